@@ -1,13 +1,16 @@
-use crate::models::{ForwardResponse, WebhookRequest};
+use crate::errors::ApiError;
+use crate::http::NetworkSettings;
+use crate::models::{Body, ForwardResponse, Headers, WebhookRequest};
 use anyhow::{Context, Result, anyhow};
+use futures_util::{Stream, StreamExt, TryStreamExt, stream};
 use reqwest::{
-    Client, Response, Url,
-    header::{AUTHORIZATION, HeaderMap, HeaderValue},
+    Client, Method, Response, StatusCode, Url,
+    header::{AUTHORIZATION, HeaderMap, HeaderValue, RETRY_AFTER},
 };
 use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 fn deserialize_map_or_default<'de, D>(
     deserializer: D,
@@ -25,6 +28,16 @@ pub struct Organization {
     pub name: String,
 }
 
+/// The account the credentials belong to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentUser {
+    pub id: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugEndpointSummary {
     pub id: String,
@@ -61,12 +74,39 @@ pub struct Pagination {
     pub total_pages: u64,
 }
 
+impl Pagination {
+    /// Whether another page follows `page`, which held `items` items. A short
+    /// page is the last one, whatever `total_pages` says.
+    fn has_more(&self, page: u32, items: usize, requested_page_size: u32) -> bool {
+        let page_size = match self.page_size {
+            0 => u64::from(requested_page_size),
+            size => size,
+        };
+        items > 0 && items as u64 >= page_size && u64::from(page) < self.total_pages
+    }
+}
+
+/// A list response that can be split into one page of items and its position.
+pub trait Paginated: DeserializeOwned {
+    type Item;
+
+    fn into_page(self) -> (Vec<Self::Item>, Pagination);
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EndpointRequestsResponse {
     pub data: Vec<DebugRequestSummary>,
     pub pagination: Pagination,
 }
 
+impl Paginated for EndpointRequestsResponse {
+    type Item = DebugRequestSummary;
+
+    fn into_page(self) -> (Vec<DebugRequestSummary>, Pagination) {
+        (self.data, self.pagination)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugRequestDetail {
     pub id: String,
@@ -147,6 +187,14 @@ pub struct EndpointRequestForwardsResponse {
     pub pagination: Pagination,
 }
 
+impl Paginated for EndpointRequestForwardsResponse {
+    type Item = DebugRequestForwardSummary;
+
+    fn into_page(self) -> (Vec<DebugRequestForwardSummary>, Pagination) {
+        (self.data, self.pagination)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugRequestForwardDetail {
     pub id: String,
@@ -259,6 +307,14 @@ pub struct AnonEventsResponse {
     pub pagination: Pagination,
 }
 
+impl Paginated for AnonEventsResponse {
+    type Item = AnonEvent;
+
+    fn into_page(self) -> (Vec<AnonEvent>, Pagination) {
+        (self.data, self.pagination)
+    }
+}
+
 // ── Shared Request models ───────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -362,6 +418,14 @@ pub struct UptimeChecksResponse {
     pub stats: Option<UptimeChecksStats>,
 }
 
+impl Paginated for UptimeChecksResponse {
+    type Item = UptimeCheck;
+
+    fn into_page(self) -> (Vec<UptimeCheck>, Pagination) {
+        (self.data, self.pagination)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenRefreshResponse {
     pub access_token: String,
@@ -371,31 +435,107 @@ pub struct TokenRefreshResponse {
 pub struct ApiClient {
     client: Client,
     base_url: Option<String>,
+    retry: RetryPolicy,
+}
+
+/// How often idempotent API calls are retried after 429, 5xx or network errors.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on every further attempt
+    pub base_delay: Duration,
+    /// Longest wait we accept, from backoff or `Retry-After`
+    pub max_delay: Duration,
 }
 
-pub fn default_base_url() -> String {
-    std::env::var("HOOKLISTENER_API_URL")
-        .unwrap_or_else(|_| "https://app.hooklistener.com".to_string())
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff before retry number `attempt` (0-based), with up to 25% jitter.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self.base_delay.saturating_mul(2u32.saturating_pow(attempt));
+        let jitter = delay.mul_f64(rand::random::<f64>() * 0.25);
+        (delay + jitter).min(self.max_delay)
+    }
+}
+
+/// Map an unsuccessful response to the matching [`ApiError`].
+fn status_error(status: StatusCode, body: &str, context: &str) -> ApiError {
+    match status.as_u16() {
+        401 => ApiError::Unauthorized,
+        403 => ApiError::Forbidden,
+        404 => ApiError::NotFound {
+            resource: resource_name(context),
+        },
+        429 => ApiError::RateLimited,
+        400 | 409 | 422 => {
+            ApiError::InvalidRequest(format!("{} failed (HTTP {}): {}", context, status, body))
+        }
+        500..=599 => ApiError::ServerError {
+            status: status.as_u16(),
+        },
+        _ => ApiError::Other(format!("{} failed (HTTP {}): {}", context, status, body)),
+    }
+}
+
+/// "get endpoint" -> "Endpoint", for not-found messages.
+fn resource_name(context: &str) -> String {
+    let noun = context.split_once(' ').map_or(context, |(_, rest)| rest);
+    let mut chars = noun.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Resource".to_string(),
+    }
+}
+
+/// `Retry-After` as a delay, from either delta-seconds or an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+        .to_std()
+        .ok()
+        .or(Some(Duration::ZERO))
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+pub fn default_base_url() -> Result<String> {
+    crate::config::api_url()
 }
 
 /// Refresh an expired CLI access token using a refresh token (no auth needed).
 pub async fn refresh_access_token(refresh_token: &str) -> Result<TokenRefreshResponse> {
-    let base_url = default_base_url();
+    let base_url = default_base_url()?;
     let url = format!("{}/api/v1/auth/refresh", base_url.trim_end_matches('/'));
     let body = serde_json::json!({ "refresh_token": refresh_token });
 
-    let client = Client::new();
-    let response = client
-        .post(&url)
-        .json(&body)
-        .send()
-        .await
-        .context("Failed to refresh access token")?;
+    let client = crate::http::client()?;
+    let response =
+        client.post(&url).json(&body).send().await.map_err(|e| {
+            ApiError::NetworkError(format!("Failed to refresh access token: {}", e))
+        })?;
 
     let status = response.status();
     let text = response.text().await.unwrap_or_default();
     if !status.is_success() {
-        return Err(anyhow!("Token refresh failed (HTTP {}): {}", status, text));
+        return Err(status_error(status, &text, "refresh access token").into());
     }
 
     serde_json::from_str(&text).context("Failed to parse refresh response")
@@ -403,34 +543,50 @@ pub async fn refresh_access_token(refresh_token: &str) -> Result<TokenRefreshRes
 
 /// Revoke a CLI refresh token server-side (best-effort, no auth needed).
 pub async fn revoke_refresh_token(refresh_token: &str) -> Result<()> {
-    let base_url = default_base_url();
+    let base_url = default_base_url()?;
     let url = format!("{}/api/v1/auth/revoke", base_url.trim_end_matches('/'));
     let body = serde_json::json!({ "refresh_token": refresh_token });
 
-    let client = Client::new();
+    let client = crate::http::client()?;
     let _ = client.post(&url).json(&body).send().await;
     Ok(())
 }
 
 impl ApiClient {
-    pub fn for_forwarding() -> Self {
-        Self {
-            client: Client::new(),
+    pub fn for_forwarding(network: &NetworkSettings) -> Result<Self> {
+        Ok(Self {
+            client: network.forwarding_client()?,
             base_url: None,
-        }
+            retry: RetryPolicy::default(),
+        })
     }
 
     /// Create a client with no authentication (for anonymous endpoints).
     pub fn unauthenticated() -> Result<Self> {
         Ok(Self {
-            client: Client::new(),
-            base_url: Some(default_base_url()),
+            client: crate::http::client()?,
+            base_url: Some(default_base_url()?),
+            retry: RetryPolicy::default(),
         })
     }
 
     pub fn with_organization(
         access_token: String,
         organization_id: Option<String>,
+    ) -> Result<Self> {
+        Self::authorized(
+            crate::http::client_builder()?,
+            default_base_url()?,
+            access_token,
+            organization_id,
+        )
+    }
+
+    fn authorized(
+        builder: reqwest::ClientBuilder,
+        base_url: String,
+        access_token: String,
+        organization_id: Option<String>,
     ) -> Result<Self> {
         let mut headers = HeaderMap::new();
         let auth = format!("Bearer {}", access_token);
@@ -446,14 +602,15 @@ impl ApiClient {
             );
         }
 
-        let client = Client::builder()
+        let client = builder
             .default_headers(headers)
             .build()
             .context("Failed to build API client")?;
 
         Ok(Self {
             client,
-            base_url: Some(default_base_url()),
+            base_url: Some(base_url),
+            retry: RetryPolicy::default(),
         })
     }
 
@@ -463,9 +620,18 @@ impl ApiClient {
         base_url: String,
         organization_id: Option<String>,
     ) -> Result<Self> {
-        let mut client = Self::with_organization(access_token, organization_id)?;
-        client.base_url = Some(base_url);
-        Ok(client)
+        Self::authorized(
+            reqwest::Client::builder(),
+            base_url,
+            access_token,
+            organization_id,
+        )
+    }
+
+    #[cfg(test)]
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
     }
 
     fn api_url(&self, path: &str) -> Result<String> {
@@ -480,29 +646,87 @@ impl ApiClient {
         ))
     }
 
-    async fn parse_json_response<T: DeserializeOwned>(
+    /// Send a request and return the successful response. Failures become
+    /// [`ApiError`]s; idempotent requests are retried per the [`RetryPolicy`].
+    async fn send(
         &self,
-        response: Response,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
         context: &str,
-    ) -> Result<T> {
-        let status = response.status();
-        let text = response.text().await.unwrap_or_default();
-        if !status.is_success() {
-            return Err(anyhow!("{} failed (HTTP {}): {}", context, status, text));
+    ) -> Result<Response> {
+        let url = self.api_url(path)?;
+        let retryable = is_idempotent(&method);
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.request(method.clone(), &url);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            let can_retry = retryable && attempt < self.retry.max_retries;
+
+            let (error, delay) = match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let delay = if status == StatusCode::TOO_MANY_REQUESTS {
+                        retry_after(&response)
+                    } else {
+                        None
+                    };
+                    let text = response.text().await.unwrap_or_default();
+                    let transient =
+                        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+                    let error = status_error(status, &text, context);
+                    if !(transient && can_retry) {
+                        return Err(error.into());
+                    }
+                    (error, delay)
+                }
+                Err(err) => {
+                    let error = ApiError::NetworkError(format!("Failed to {}: {}", context, err));
+                    if !can_retry {
+                        return Err(error.into());
+                    }
+                    (error, None)
+                }
+            };
+
+            let delay = delay.unwrap_or_else(|| self.retry.backoff(attempt));
+            if delay > self.retry.max_delay {
+                return Err(error.into());
+            }
+            tracing::debug!(
+                "{} failed ({}), retrying in {:?} (attempt {}/{})",
+                context,
+                error,
+                delay,
+                attempt + 1,
+                self.retry.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         }
+    }
 
-        serde_json::from_str(&text).with_context(|| format!("Failed to parse {} response", context))
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+        context: &str,
+    ) -> Result<T> {
+        let response = self.send(method, path, body, context).await?;
+        let text = response
+            .text()
+            .await
+            .map_err(|e| ApiError::NetworkError(format!("Failed to {}: {}", context, e)))?;
+        serde_json::from_str(&text)
+            .map_err(|e| ApiError::ParseError(format!("{} response: {}", context, e)).into())
     }
 
     async fn get_json<T: DeserializeOwned>(&self, path: &str, context: &str) -> Result<T> {
-        let url = self.api_url(path)?;
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .await
-            .with_context(|| format!("Failed to {}", context))?;
-        self.parse_json_response(response, context).await
+        self.send_json(Method::GET, path, None, context).await
     }
 
     async fn post_json<T: DeserializeOwned>(
@@ -511,15 +735,8 @@ impl ApiClient {
         body: &Value,
         context: &str,
     ) -> Result<T> {
-        let url = self.api_url(path)?;
-        let response = self
-            .client
-            .post(url)
-            .json(body)
-            .send()
+        self.send_json(Method::POST, path, Some(body), context)
             .await
-            .with_context(|| format!("Failed to {}", context))?;
-        self.parse_json_response(response, context).await
     }
 
     async fn patch_json<T: DeserializeOwned>(
@@ -528,44 +745,44 @@ impl ApiClient {
         body: &Value,
         context: &str,
     ) -> Result<T> {
-        let url = self.api_url(path)?;
-        let response = self
-            .client
-            .patch(url)
-            .json(body)
-            .send()
+        self.send_json(Method::PATCH, path, Some(body), context)
             .await
-            .with_context(|| format!("Failed to {}", context))?;
-        self.parse_json_response(response, context).await
     }
 
     async fn delete_json<T: DeserializeOwned>(&self, path: &str, context: &str) -> Result<T> {
-        let url = self.api_url(path)?;
-        let response = self
-            .client
-            .delete(url)
-            .send()
-            .await
-            .with_context(|| format!("Failed to {}", context))?;
-        self.parse_json_response(response, context).await
+        self.send_json(Method::DELETE, path, None, context).await
     }
 
     async fn delete_empty(&self, path: &str, context: &str) -> Result<()> {
-        let url = self.api_url(path)?;
-        let response = self
-            .client
-            .delete(url)
-            .send()
-            .await
-            .with_context(|| format!("Failed to {}", context))?;
-
-        let status = response.status();
-        if status.is_success() {
-            return Ok(());
-        }
+        self.send(Method::DELETE, path, None, context).await?;
+        Ok(())
+    }
 
-        let text = response.text().await.unwrap_or_default();
-        Err(anyhow!("{} failed (HTTP {}): {}", context, status, text))
+    /// Stream the items of a paginated list from `page` onwards. The next page
+    /// is only requested once the current one has been consumed, and the stream
+    /// ends after `Pagination.total_pages` or the first short or empty page.
+    pub fn paginate<'a, R: Paginated + 'a>(
+        &'a self,
+        path: String,
+        page: u32,
+        page_size: u32,
+        context: &'static str,
+    ) -> impl Stream<Item = Result<R::Item>> + 'a {
+        stream::try_unfold(Some(page.max(1)), move |next| {
+            let path = path.clone();
+            async move {
+                let Some(page) = next else {
+                    return Ok(None);
+                };
+                let separator = if path.contains('?') { '&' } else { '?' };
+                let url = format!("{path}{separator}page={page}&page_size={page_size}");
+                let (items, pagination) = self.get_json::<R>(&url, context).await?.into_page();
+                let more = pagination.has_more(page, items.len(), page_size);
+                Ok::<_, anyhow::Error>(Some((items, more.then_some(page + 1))))
+            }
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
     }
 
     pub async fn list_organizations(&self) -> Result<Vec<Organization>> {
@@ -573,6 +790,10 @@ impl ApiClient {
             .await
     }
 
+    pub async fn get_current_user(&self) -> Result<CurrentUser> {
+        self.get_json("/api/v1/me", "get current user").await
+    }
+
     pub async fn list_endpoints(&self) -> Result<Vec<DebugEndpointSummary>> {
         let response: DataResponse<Vec<DebugEndpointSummary>> =
             self.get_json("/api/v1/endpoints", "list endpoints").await?;
@@ -592,6 +813,20 @@ impl ApiClient {
         self.get_json(&path, "list endpoint requests").await
     }
 
+    pub fn stream_endpoint_requests<'a>(
+        &'a self,
+        endpoint_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<DebugRequestSummary>> + 'a {
+        self.paginate::<EndpointRequestsResponse>(
+            format!("/api/v1/endpoints/{}/requests", endpoint_id),
+            page,
+            page_size,
+            "list endpoint requests",
+        )
+    }
+
     pub async fn create_endpoint(
         &self,
         name: &str,
@@ -683,6 +918,24 @@ impl ApiClient {
         self.get_json(&path, "list endpoint request forwards").await
     }
 
+    pub fn stream_endpoint_request_forwards<'a>(
+        &'a self,
+        endpoint_id: &str,
+        request_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<DebugRequestForwardSummary>> + 'a {
+        self.paginate::<EndpointRequestForwardsResponse>(
+            format!(
+                "/api/v1/endpoints/{}/requests/{}/forwards",
+                endpoint_id, request_id
+            ),
+            page,
+            page_size,
+            "list endpoint request forwards",
+        )
+    }
+
     pub async fn get_forward(&self, forward_id: &str) -> Result<DebugRequestForwardDetail> {
         let path = format!("/api/v1/forwards/{}", forward_id);
         let response: DataResponse<DebugRequestForwardDetail> =
@@ -777,6 +1030,43 @@ impl ApiClient {
         self.get_json(&path, "list uptime checks").await
     }
 
+    pub fn stream_uptime_checks<'a>(
+        &'a self,
+        monitor_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<UptimeCheck>> + 'a {
+        self.paginate::<UptimeChecksResponse>(
+            format!("/api/v1/uptime-monitors/{}/checks", monitor_id),
+            page,
+            page_size,
+            "list uptime checks",
+        )
+    }
+
+    /// Like [`stream_uptime_checks`](Self::stream_uptime_checks), also returning
+    /// the stats sent with the first page.
+    pub async fn stream_uptime_checks_with_stats<'a>(
+        &'a self,
+        monitor_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> Result<(
+        Option<UptimeChecksStats>,
+        impl Stream<Item = Result<UptimeCheck>> + 'a,
+    )> {
+        let page = page.max(1);
+        let first = self.list_uptime_checks(monitor_id, page, page_size).await?;
+        let rest = if first.pagination.has_more(page, first.data.len(), page_size) {
+            self.stream_uptime_checks(monitor_id, page + 1, page_size)
+                .left_stream()
+        } else {
+            stream::empty().right_stream()
+        };
+        let checks = stream::iter(first.data.into_iter().map(Ok)).chain(rest);
+        Ok((first.stats, checks))
+    }
+
     // ── Anonymous Endpoint methods ────────────────────────────────────────────
 
     pub async fn create_anon_endpoint(
@@ -813,6 +1103,20 @@ impl ApiClient {
         self.get_json(&path, "list anonymous endpoint events").await
     }
 
+    pub fn stream_anon_events<'a>(
+        &'a self,
+        endpoint_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<AnonEvent>> + 'a {
+        self.paginate::<AnonEventsResponse>(
+            format!("/api/v1/anon/endpoints/{}/events", endpoint_id),
+            page,
+            page_size,
+            "list anonymous endpoint events",
+        )
+    }
+
     pub async fn get_anon_event(&self, endpoint_id: &str, event_id: &str) -> Result<AnonEvent> {
         let path = format!("/api/v1/anon/endpoints/{}/events/{}", endpoint_id, event_id);
         self.get_json(&path, "get anonymous endpoint event").await
@@ -878,6 +1182,16 @@ impl ApiClient {
         original_request: &WebhookRequest,
         target_url: &str,
     ) -> Result<ForwardResponse> {
+        if let Some(body) = original_request.body.as_ref()
+            && body.is_truncated()
+        {
+            return Err(anyhow!(
+                "Only {} of {} body bytes were kept; replaying would send an incomplete body",
+                body.len(),
+                body.original_len()
+            ));
+        }
+
         let start_time = Instant::now();
 
         // Build the forwarding request
@@ -915,17 +1229,20 @@ impl ApiClient {
         }
 
         // Add body if present (for POST, PUT, PATCH requests)
-        // Use full body if available, otherwise fall back to preview
-        let body_content = original_request
-            .body
-            .as_ref()
-            .or(original_request.body_preview.as_ref());
+        // Send the exact body bytes if available, otherwise fall back to the preview
+        let body_content = match &original_request.body {
+            Some(body) => Some(body.as_bytes().to_vec()),
+            None => original_request
+                .body_preview
+                .as_ref()
+                .map(|preview| preview.clone().into_bytes()),
+        };
         if let Some(body) = body_content
             && !body.is_empty()
             && original_request.method != "GET"
             && original_request.method != "HEAD"
         {
-            request_builder = request_builder.body(body.clone());
+            request_builder = request_builder.body(body);
         }
 
         // Execute the request
@@ -933,19 +1250,14 @@ impl ApiClient {
             Ok(response) => {
                 let status_code = response.status().as_u16();
 
-                // Extract response headers
-                let mut response_headers = HashMap::new();
-                for (key, value) in response.headers() {
-                    if let Ok(value_str) = value.to_str() {
-                        response_headers.insert(key.to_string(), value_str.to_string());
-                    }
-                }
+                // Extract response headers, keeping repeated values in order
+                let response_headers = Headers::from_header_map(response.headers());
 
-                // Get response body
-                let body = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "(Failed to read response body)".to_string());
+                // Get response body as bytes so binary content is kept intact
+                let body = match response.bytes().await {
+                    Ok(bytes) => Body::from(bytes.to_vec()),
+                    Err(_) => Body::from("(Failed to read response body)"),
+                };
 
                 let duration = start_time.elapsed();
 
@@ -965,8 +1277,8 @@ impl ApiClient {
                 Ok(ForwardResponse {
                     success: false,
                     status_code: None,
-                    headers: HashMap::new(),
-                    body: String::new(),
+                    headers: Headers::new(),
+                    body: Body::default(),
                     error_message: Some(e.to_string()),
                     target_url: target_url.to_string(),
                     duration_ms: duration.as_millis() as u64,
@@ -980,6 +1292,139 @@ impl ApiClient {
 mod tests {
     use super::*;
 
+    fn fast_client(server: &mockito::Server) -> ApiClient {
+        ApiClient::with_base_url("token".to_string(), server.url(), None)
+            .unwrap()
+            .with_retry_policy(RetryPolicy {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_secs(5),
+            })
+    }
+
+    #[tokio::test]
+    async fn test_get_retries_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("GET", "/api/v1/organizations")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/api/v1/organizations")
+            .with_status(200)
+            .with_body(r#"[{"id":"org-1","name":"Acme"}]"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let organizations = fast_client(&server).list_organizations().await.unwrap();
+        assert_eq!(organizations[0].name, "Acme");
+        failing.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_honors_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/organizations")
+            .with_status(429)
+            .with_header("retry-after", "1")
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/organizations")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let started = Instant::now();
+        fast_client(&server).list_organizations().await.unwrap();
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_limit_fails_fast() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v1/organizations")
+            .with_status(429)
+            .with_header("retry-after", "3600")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let err = fast_client(&server).list_organizations().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::RateLimited)
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_is_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/api/v1/endpoints")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let err = fast_client(&server)
+            .create_endpoint("hooks", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::ServerError { status: 502 })
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_status_codes_map_to_api_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/endpoints/missing")
+            .with_status(404)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/organizations")
+            .with_status(401)
+            .create_async()
+            .await;
+        server
+            .mock("DELETE", "/api/v1/endpoints/locked")
+            .with_status(403)
+            .create_async()
+            .await;
+        let client = fast_client(&server);
+
+        let err = client.get_endpoint("missing").await.unwrap_err();
+        let api_error = err.downcast_ref::<ApiError>().unwrap();
+        assert_eq!(api_error.to_string(), "Endpoint not found");
+        assert!(api_error.hint().is_some());
+
+        let err = client.list_organizations().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Unauthorized)
+        ));
+
+        let err = client.delete_endpoint("locked").await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Forbidden)
+        ));
+    }
+
     #[tokio::test]
     async fn test_forward_request_success() {
         let mut server = mockito::Server::new_async().await;
@@ -997,7 +1442,7 @@ mod tests {
             id: "req-1".to_string(),
             timestamp: 0,
             remote_addr: "127.0.0.1".to_string(),
-            headers: HashMap::new(),
+            headers: Headers::new(),
             content_length: 0,
             method: "POST".to_string(),
             url: "/webhook".to_string(),
@@ -1005,7 +1450,7 @@ mod tests {
             query_params: HashMap::new(),
             created_at: "2024-01-01".to_string(),
             body_preview: Some("{}".to_string()),
-            body: Some("{}".to_string()),
+            body: Some("{}".into()),
         };
 
         let target_url = format!("{}/webhook", server.url());
@@ -1015,6 +1460,42 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_forward_request_sends_exact_binary_body() {
+        let payload = vec![0x1f, 0x8b, 0x08, 0x00, 0xff, 0xfe, 0x00];
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/upload")
+            .match_body(payload.clone())
+            .with_status(200)
+            .with_body([0x89, b'P', b'N', b'G', 0x00])
+            .create_async()
+            .await;
+
+        let client =
+            ApiClient::with_base_url("test-token".to_string(), server.url(), None).unwrap();
+
+        let request = WebhookRequest {
+            id: "req-1".to_string(),
+            timestamp: 0,
+            remote_addr: "127.0.0.1".to_string(),
+            headers: Headers::new(),
+            content_length: payload.len() as i64,
+            method: "POST".to_string(),
+            url: "/upload".to_string(),
+            path: Some("/upload".to_string()),
+            query_params: HashMap::new(),
+            created_at: "2024-01-01".to_string(),
+            body_preview: None,
+            body: Some(Body::from(payload)),
+        };
+
+        let target_url = format!("{}/upload", server.url());
+        let result = client.forward_request(&request, &target_url).await.unwrap();
+        assert_eq!(result.body.as_bytes(), [0x89, b'P', b'N', b'G', 0x00]);
+        mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_forward_request_connection_refused() {
         let client = ApiClient::with_base_url(
@@ -1028,7 +1509,7 @@ mod tests {
             id: "req-1".to_string(),
             timestamp: 0,
             remote_addr: "127.0.0.1".to_string(),
-            headers: HashMap::new(),
+            headers: Headers::new(),
             content_length: 0,
             method: "POST".to_string(),
             url: "/webhook".to_string(),
@@ -1046,4 +1527,172 @@ mod tests {
         assert!(!result.success);
         assert!(result.error_message.is_some());
     }
+
+    fn requests_page(ids: &[&str], page: u64, total_pages: u64) -> String {
+        let data: Vec<_> = ids
+            .iter()
+            .map(|id| serde_json::json!({"id": id, "method": "POST"}))
+            .collect();
+        serde_json::json!({
+            "data": data,
+            "pagination": {
+                "page": page,
+                "page_size": 2,
+                "total_count": 3,
+                "total_pages": total_pages
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_paginate_walks_all_pages() {
+        use futures_util::TryStreamExt;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/api/v1/endpoints/ep-1/requests")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("page_size".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(requests_page(&["req-1", "req-2"], 1, 2))
+            .expect(1)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/api/v1/endpoints/ep-1/requests")
+            .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_status(200)
+            .with_body(requests_page(&["req-3"], 2, 2))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = fast_client(&server);
+        let requests: Vec<_> = client
+            .stream_endpoint_requests("ep-1", 1, 2)
+            .try_collect()
+            .await
+            .unwrap();
+        let ids: Vec<_> = requests.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["req-1", "req-2", "req-3"]);
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_paginate_fetches_pages_lazily() {
+        use futures_util::{StreamExt, TryStreamExt};
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/api/v1/endpoints/ep-1/requests")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_body(requests_page(&["req-1", "req-2"], 1, 2))
+            .expect(1)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/api/v1/endpoints/ep-1/requests")
+            .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = fast_client(&server);
+        let requests: Vec<_> = client
+            .stream_endpoint_requests("ep-1", 1, 2)
+            .take(2)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(requests.len(), 2);
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_after_a_short_page() {
+        use futures_util::TryStreamExt;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        // Claims more pages than there are, as a stale total would
+        let first = server
+            .mock("GET", "/api/v1/endpoints/ep-1/requests")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_body(requests_page(&["req-1"], 1, 5))
+            .expect(1)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/api/v1/endpoints/ep-1/requests")
+            .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = fast_client(&server);
+        let requests: Vec<_> = client
+            .stream_endpoint_requests("ep-1", 1, 2)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(requests.len(), 1);
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_uptime_checks_keep_first_page_stats() {
+        use futures_util::TryStreamExt;
+        use mockito::Matcher;
+
+        let page = |ids: &[&str], page: u64, stats: serde_json::Value| {
+            let data: Vec<_> = ids
+                .iter()
+                .map(|id| serde_json::json!({"id": id, "status": "up"}))
+                .collect();
+            serde_json::json!({
+                "data": data,
+                "pagination": {"page": page, "page_size": 2, "total_count": 3, "total_pages": 2},
+                "stats": stats
+            })
+            .to_string()
+        };
+        let mut server = mockito::Server::new_async().await;
+        let _first = server
+            .mock("GET", "/api/v1/uptime-monitors/mon-1/checks")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_body(page(
+                &["c1", "c2"],
+                1,
+                serde_json::json!({"uptime_percentage": 99.5}),
+            ))
+            .create_async()
+            .await;
+        let _second = server
+            .mock("GET", "/api/v1/uptime-monitors/mon-1/checks")
+            .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_status(200)
+            .with_body(page(&["c3"], 2, serde_json::Value::Null))
+            .create_async()
+            .await;
+
+        let client = fast_client(&server);
+        let (stats, checks) = client
+            .stream_uptime_checks_with_stats("mon-1", 1, 2)
+            .await
+            .unwrap();
+        let checks: Vec<_> = checks.try_collect().await.unwrap();
+        assert_eq!(checks.len(), 3);
+        assert_eq!(stats.unwrap().uptime_percentage, Some(99.5));
+    }
 }
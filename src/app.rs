@@ -1,10 +1,17 @@
 use crate::api::ApiClient;
 use crate::config::Config;
 use crate::errors::ApiError;
-use crate::models::{ForwardResponse, WebhookRequest};
+use crate::inspector::{Inspector, InspectorEntry};
+use crate::intercept::{HeldRequest, InterceptDecision, Interceptor, ManualResponse};
+use crate::mock::MockMode;
+use crate::models::{Body, ForwardResponse, Headers, WebhookRequest};
+use crate::project::RedactRules;
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use base64::Engine as _;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::borrow::Cow;
 use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 
 pub const MAX_TUNNEL_REQUESTS: usize = 500;
 pub const MAX_BODY_SIZE: usize = 256 * 1024;
@@ -15,9 +22,10 @@ pub enum AppState {
     InputForwardUrl,
     ForwardingRequest,
     ForwardResult,
-    Listening,  // State for the listen command (debug endpoints)
-    Tunneling,  // State for HTTP tunnel command
-    ExportMenu, // Export request menu (cURL/JSON)
+    Listening,    // State for the listen command (debug endpoints)
+    Tunneling,    // State for HTTP tunnel command
+    ExportMenu,   // Export request menu (cURL/JSON)
+    Intercepting, // Editing a request held by intercept rules
     Error {
         message: String,
         hint: Option<String>,
@@ -29,6 +37,8 @@ pub struct ListeningStats {
     pub total_requests: u64,
     pub successful_forwards: u64,
     pub failed_forwards: u64,
+    /// Webhooks with injected faults
+    pub faults: u64,
 }
 
 #[derive(Default, Debug)]
@@ -37,10 +47,189 @@ pub struct TunnelStats {
     pub success: u64,
     pub failed: u64,
     pub total_duration_ms: u64,
-    #[allow(dead_code)]
     pub bytes_in: u64,
-    #[allow(dead_code)]
     pub bytes_out: u64,
+    /// Requests answered by a mock rule
+    pub mocked: u64,
+    /// Requests with injected faults
+    pub faults: u64,
+}
+
+/// How a tunnel response body is relayed back through the tunnel.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum StreamStatus {
+    /// Sent as a single `tunnel_response` message
+    #[default]
+    Buffered,
+    /// Chunks are still being relayed
+    Streaming,
+    /// All chunks have been relayed
+    Streamed,
+}
+
+/// Local forwarding concurrency, reported by the tunnel's forwarding pool.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct InFlightStats {
+    pub active: usize,
+    pub queued: usize,
+    pub max: usize,
+}
+
+/// What happened to a request held by an intercept rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterceptOutcome {
+    Held,
+    Forwarded,
+    Edited,
+    Dropped,
+    Answered,
+}
+
+/// A request waiting in the intercept queue.
+#[derive(Debug, Clone)]
+pub struct HeldEntry {
+    pub request_id: String,
+    pub request: HeldRequest,
+    /// Whether a hand-written response can be sent back (tunnel only)
+    pub can_respond: bool,
+}
+
+/// Fields of the intercept editor, in focus order.
+pub const INTERCEPT_FIELDS: [&str; 7] = [
+    "Method",
+    "Path",
+    "Headers",
+    "Body",
+    "Response Status",
+    "Response Headers",
+    "Response Body",
+];
+
+/// Index of the first response field in [`INTERCEPT_FIELDS`].
+const RESPONSE_FIELDS_START: usize = 4;
+
+/// Editable text for a held request and an optional hand-written response.
+#[derive(Debug, Clone)]
+pub struct InterceptEditor {
+    pub request_id: String,
+    pub original: HeldRequest,
+    pub can_respond: bool,
+    pub focus: usize,
+    /// One entry per [`INTERCEPT_FIELDS`]; headers are `Name: value` lines
+    pub fields: Vec<String>,
+    /// Validation error from the last attempt to forward or respond
+    pub error: Option<String>,
+    pub return_to: DetailReturnTarget,
+}
+
+impl InterceptEditor {
+    pub fn new(entry: &HeldEntry, return_to: DetailReturnTarget) -> Self {
+        let request = &entry.request;
+        let path = if request.query_string.is_empty() {
+            request.path.clone()
+        } else {
+            format!("{}?{}", request.path, request.query_string)
+        };
+        let headers = request
+            .headers
+            .iter()
+            .map(|(k, v)| format!("{}: {}", k, v))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Self {
+            request_id: entry.request_id.clone(),
+            original: request.clone(),
+            can_respond: entry.can_respond,
+            focus: 0,
+            fields: vec![
+                request.method.clone(),
+                path,
+                headers,
+                request.body.clone(),
+                "200".to_string(),
+                "content-type: text/plain".to_string(),
+                String::new(),
+            ],
+            error: None,
+            return_to,
+        }
+    }
+
+    /// Number of focusable fields (response fields only when a response can be sent).
+    pub fn field_count(&self) -> usize {
+        if self.can_respond {
+            INTERCEPT_FIELDS.len()
+        } else {
+            RESPONSE_FIELDS_START
+        }
+    }
+
+    fn is_multiline(&self) -> bool {
+        matches!(self.focus, 2 | 3 | 5 | 6)
+    }
+
+    /// Build the request to forward from the edited fields.
+    pub fn to_request(&self) -> std::result::Result<HeldRequest, String> {
+        let method = self.fields[0].trim().to_uppercase();
+        if method.is_empty() || !method.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(format!("Invalid method '{}'", self.fields[0].trim()));
+        }
+
+        let target = self.fields[1].trim();
+        if !target.starts_with('/') {
+            return Err("Path must start with '/'".to_string());
+        }
+        let (path, query_string) = target.split_once('?').unwrap_or((target, ""));
+
+        Ok(HeldRequest {
+            method,
+            path: path.to_string(),
+            query_string: query_string.to_string(),
+            headers: parse_header_lines(&self.fields[2])?,
+            body: self.fields[3].clone(),
+        })
+    }
+
+    /// Build the hand-written response from the response fields.
+    pub fn to_response(&self) -> std::result::Result<ManualResponse, String> {
+        let status = self.fields[4]
+            .trim()
+            .parse::<u16>()
+            .ok()
+            .filter(|s| (100..=599).contains(s))
+            .ok_or_else(|| format!("Invalid status '{}'", self.fields[4].trim()))?;
+
+        Ok(ManualResponse {
+            status,
+            headers: parse_header_lines(&self.fields[5])?,
+            body: self.fields[6].clone(),
+        })
+    }
+}
+
+/// Parse `Name: value` lines, ignoring blank lines.
+fn parse_header_lines(text: &str) -> std::result::Result<Headers, String> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.split_once(':')
+                .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+                .filter(|(k, _)| !k.is_empty())
+                .ok_or_else(|| format!("Invalid header line '{}'", line.trim()))
+        })
+        .collect()
+}
+
+/// A WebSocket proxied through the tunnel after an upgrade request.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct WebSocketInfo {
+    /// Frames from the public client to the local target
+    pub frames_in: u64,
+    /// Frames from the local target to the public client
+    pub frames_out: u64,
+    pub open: bool,
+    pub close_code: Option<u16>,
 }
 
 #[derive(Debug, Clone)]
@@ -52,20 +241,44 @@ pub struct TunnelRequest {
     pub status: Option<u16>,
     pub completed_at: Option<std::time::Instant>,
     pub error: Option<String>,
-    pub headers: HashMap<String, String>,
-    pub body: Option<String>,
+    pub headers: Headers,
+    pub body: Option<Body>,
     pub query_string: String,
-    pub response_headers: Option<HashMap<String, String>>,
-    pub response_body: Option<String>,
+    pub response_headers: Option<Headers>,
+    pub response_body: Option<Body>,
+    /// Local URL the request was routed to
+    pub target_url: String,
+    /// Matched routing rule, `None` when the default target was used
+    pub route: Option<String>,
+    /// Request body bytes received through the tunnel
+    pub bytes_in: u64,
+    /// Response body bytes relayed back through the tunnel
+    pub bytes_out: u64,
+    pub stream_status: StreamStatus,
+    /// Set once the request was upgraded to a proxied WebSocket
+    pub websocket: Option<WebSocketInfo>,
+    /// Set when the request matched an intercept rule
+    pub intercept: Option<InterceptOutcome>,
+    /// Set when a mock rule answered instead of the local target
+    pub mocked: Option<MockMode>,
+    /// Labels of faults injected into forwarding
+    pub fault: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TunnelResponseData {
     pub status: Option<u16>,
-    pub headers: HashMap<String, String>,
-    pub body: Option<String>,
+    pub headers: Headers,
+    pub body: Option<Body>,
     pub duration_ms: Option<u64>,
     pub error: Option<String>,
+    pub target_url: Option<String>,
+    pub route: Option<String>,
+    pub bytes_out: u64,
+    pub stream_status: StreamStatus,
+    pub websocket: Option<WebSocketInfo>,
+    pub mocked: Option<MockMode>,
+    pub fault: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -74,27 +287,25 @@ pub enum DetailReturnTarget {
     Tunneling,
 }
 
-pub fn truncate_body(body: Option<String>) -> Option<String> {
-    body.map(|b| {
-        if b.len() > MAX_BODY_SIZE {
-            // Clamp to a valid UTF-8 boundary so truncation never panics.
-            let mut cutoff = MAX_BODY_SIZE.min(b.len());
-            while !b.is_char_boundary(cutoff) {
-                cutoff = cutoff.saturating_sub(1);
-            }
+pub fn truncate_body(body: Option<Body>) -> Option<Body> {
+    body.map(|b| b.truncate(MAX_BODY_SIZE))
+}
 
-            let mut truncated = b[..cutoff].to_string();
-            truncated.push_str("\n...(truncated)");
-            truncated
-        } else {
-            b
-        }
-    })
+/// Status suffix for copies of a request whose body was cut to `MAX_BODY_SIZE`.
+fn truncation_warning(request: &WebhookRequest) -> String {
+    match request.body.as_ref().filter(|body| body.is_truncated()) {
+        Some(body) => format!(
+            " Body is truncated ({} of {} bytes).",
+            body.len(),
+            body.original_len()
+        ),
+        None => String::new(),
+    }
 }
 
 const VIEWPORT_LINES: usize = 20;
 
-fn parse_query_string(query_string: &str) -> HashMap<String, String> {
+pub fn parse_query_string(query_string: &str) -> HashMap<String, String> {
     if query_string.is_empty() {
         return HashMap::new();
     }
@@ -140,6 +351,7 @@ pub struct App {
     // Listening mode state (debug endpoints)
     pub listening_requests: Vec<WebhookRequest>,
     pub listening_stats: ListeningStats,
+    pub in_flight: InFlightStats,
     pub listening_connected: bool,
     pub listening_error: Option<String>,
     pub listening_endpoint: String,
@@ -159,12 +371,29 @@ pub struct App {
     pub response_scroll_offset: usize,
     pub tunnel_local_host: String,
     pub tunnel_local_port: u16,
+    pub tunnel_local_https: bool,
+    pub tunnel_routes: Vec<String>,
     pub tunnel_org_id: Option<String>,
     pub tunnel_error: Option<String>,
     pub tunnel_requested_slug: Option<String>,
     pub tunnel_is_static: bool,
     pub tunnel_reconnect_requested: bool,
 
+    // Intercept mode: requests held before forwarding
+    pub interceptor: Option<Interceptor>,
+    pub held_requests: VecDeque<HeldEntry>,
+    pub intercept_editor: Option<InterceptEditor>,
+
+    // Local web inspector mirroring captured requests
+    pub inspector: Option<Arc<Inspector>>,
+    pub inspector_url: Option<String>,
+
+    // When the connection last refreshed an expired access token
+    pub token_refreshed_at: Option<chrono::DateTime<chrono::Local>>,
+
+    // Project redaction rules applied to captured traffic
+    pub redact: RedactRules,
+
     // Status messages (auto-expire)
     pub tunnel_status_message: Option<(String, std::time::Instant)>,
     pub status_message: Option<(String, std::time::Instant)>,
@@ -195,6 +424,7 @@ impl App {
             loading_frame: 0,
             listening_requests: Vec::new(),
             listening_stats: ListeningStats::default(),
+            in_flight: InFlightStats::default(),
             listening_connected: false,
             listening_error: None,
             listening_endpoint: String::new(),
@@ -212,11 +442,20 @@ impl App {
             response_scroll_offset: 0,
             tunnel_local_host: String::from("localhost"),
             tunnel_local_port: 3000,
+            tunnel_local_https: false,
+            tunnel_routes: Vec::new(),
             tunnel_org_id: None,
             tunnel_error: None,
             tunnel_requested_slug: None,
             tunnel_is_static: false,
             tunnel_reconnect_requested: false,
+            interceptor: None,
+            held_requests: VecDeque::new(),
+            intercept_editor: None,
+            inspector: None,
+            inspector_url: None,
+            token_refreshed_at: None,
+            redact: RedactRules::default(),
             tunnel_status_message: None,
             status_message: None,
             search_active: false,
@@ -235,18 +474,22 @@ impl App {
         }
     }
 
-    /// Return the body text of the currently selected request (full body or preview).
-    fn selected_body_text(&self) -> Option<&str> {
-        self.selected_request
-            .as_ref()
-            .and_then(|r| r.body.as_deref().or(r.body_preview.as_deref()))
+    /// Return the body text of the currently selected request (full body or preview),
+    /// as a hex dump for binary bodies.
+    fn selected_body_text(&self) -> Option<Cow<'_, str>> {
+        let request = self.selected_request.as_ref()?;
+        match &request.body {
+            Some(body) => Some(body.display()),
+            None => request.body_preview.as_deref().map(Cow::Borrowed),
+        }
     }
 
     /// Return the response body text from the selected tunnel response.
-    fn response_body_text(&self) -> Option<&str> {
+    fn response_body_text(&self) -> Option<Cow<'_, str>> {
         self.selected_tunnel_response
             .as_ref()
-            .and_then(|r| r.body.as_deref())
+            .and_then(|r| r.body.as_ref())
+            .map(|body| body.display())
     }
 
     /// Maximum scroll offset for response headers in the Response tab.
@@ -259,6 +502,145 @@ impl App {
             .unwrap_or(0)
     }
 
+    /// Show a status message in the view the user returns to.
+    fn set_mode_status(&mut self, target: &DetailReturnTarget, message: String) {
+        let message = Some((message, std::time::Instant::now()));
+        match target {
+            DetailReturnTarget::Tunneling => self.tunnel_status_message = message,
+            DetailReturnTarget::Listening => self.status_message = message,
+        }
+    }
+
+    /// Send the latest state of a captured request to the inspector, if one is running.
+    pub fn publish_to_inspector(&self, request_id: &str) {
+        let Some(inspector) = &self.inspector else {
+            return;
+        };
+        if let Some(request) = self
+            .tunnel_requests
+            .iter()
+            .rev()
+            .find(|r| r.request_id == request_id)
+        {
+            inspector.publish(InspectorEntry::from_tunnel(request));
+        } else if let Some(request) = self
+            .listening_requests
+            .iter()
+            .rev()
+            .find(|r| r.id == request_id)
+        {
+            inspector.publish(InspectorEntry::from_webhook(
+                request,
+                &self.listening_target,
+            ));
+        }
+    }
+
+    /// Pause or resume holding new requests that match the intercept rules.
+    fn toggle_intercept(&mut self, target: DetailReturnTarget) {
+        let Some(interceptor) = &self.interceptor else {
+            self.set_mode_status(&target, "No intercept rules (use --intercept)".into());
+            return;
+        };
+        let enabled = !interceptor.is_enabled();
+        interceptor.set_enabled(enabled);
+        let message = if enabled {
+            "Intercept mode on".to_string()
+        } else {
+            format!(
+                "Intercept mode paused ({} still held)",
+                self.held_requests.len()
+            )
+        };
+        self.set_mode_status(&target, message);
+    }
+
+    /// Open the editor for the oldest held request, if any.
+    fn open_intercept_editor(&mut self, target: DetailReturnTarget) {
+        match self.held_requests.front() {
+            Some(entry) => {
+                self.intercept_editor = Some(InterceptEditor::new(entry, target));
+                self.state = AppState::Intercepting;
+            }
+            None => {
+                self.intercept_editor = None;
+                self.state = match target {
+                    DetailReturnTarget::Tunneling => AppState::Tunneling,
+                    DetailReturnTarget::Listening => AppState::Listening,
+                };
+            }
+        }
+    }
+
+    /// Turn the editor contents into a decision and release the held request.
+    fn resolve_intercept(&mut self, key: KeyCode) {
+        let Some(editor) = self.intercept_editor.as_mut() else {
+            return;
+        };
+
+        let result = match key {
+            KeyCode::Char('s') => editor.to_request().map(|request| {
+                let outcome = if request == editor.original {
+                    InterceptOutcome::Forwarded
+                } else {
+                    InterceptOutcome::Edited
+                };
+                (InterceptDecision::Forward(request), outcome)
+            }),
+            KeyCode::Char('d') => Ok((InterceptDecision::Drop, InterceptOutcome::Dropped)),
+            KeyCode::Char('r') if editor.can_respond => editor.to_response().map(|response| {
+                (
+                    InterceptDecision::Respond(response),
+                    InterceptOutcome::Answered,
+                )
+            }),
+            _ => return,
+        };
+        let (decision, outcome) = match result {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                editor.error = Some(e);
+                return;
+            }
+        };
+
+        let request_id = editor.request_id.clone();
+        let target = editor.return_to.clone();
+        let edited = match &decision {
+            InterceptDecision::Forward(request) => Some(request.clone()),
+            _ => None,
+        };
+
+        let delivered = self
+            .interceptor
+            .as_ref()
+            .is_some_and(|interceptor| interceptor.resolve(&request_id, decision));
+        self.held_requests
+            .retain(|entry| entry.request_id != request_id);
+
+        if let Some(req) = self
+            .tunnel_requests
+            .iter_mut()
+            .find(|r| r.request_id == request_id)
+        {
+            req.intercept = Some(outcome);
+            if let Some(edited) = edited {
+                req.method = edited.method;
+                req.path = edited.path;
+                req.query_string = edited.query_string;
+            }
+        }
+
+        let message = match (delivered, outcome) {
+            (false, _) => "Request is no longer waiting (connection was reset)",
+            (true, InterceptOutcome::Dropped) => "Request dropped",
+            (true, InterceptOutcome::Answered) => "Response sent",
+            (true, _) => "Request released",
+        };
+        self.set_mode_status(&target, message.to_string());
+        self.open_intercept_editor(target);
+    }
+
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
         if key.kind != KeyEventKind::Press {
             return Ok(());
@@ -292,7 +674,7 @@ impl App {
                             && self.is_valid_url(&self.forward_url_input)
                             && self.selected_request.is_some() =>
                     {
-                        self.state = AppState::ForwardingRequest;
+                        self.start_replay();
                     }
                     KeyCode::Char('e') if self.selected_request.is_some() => {
                         self.state = AppState::ExportMenu;
@@ -338,7 +720,7 @@ impl App {
                         }
                         2 => {
                             if let Some(body) = self.selected_body_text() {
-                                let max = max_body_scroll(body);
+                                let max = max_body_scroll(&body);
                                 if self.body_scroll_offset < max {
                                     self.body_scroll_offset += 1;
                                 }
@@ -349,7 +731,7 @@ impl App {
                             if self.response_headers_scroll_offset < headers_max {
                                 self.response_headers_scroll_offset += 1;
                             } else if let Some(body) = self.response_body_text() {
-                                let body_max = max_body_scroll(body);
+                                let body_max = max_body_scroll(&body);
                                 if self.response_scroll_offset < body_max {
                                     self.response_scroll_offset += 1;
                                 }
@@ -385,7 +767,7 @@ impl App {
                         }
                         2 => {
                             if let Some(body) = self.selected_body_text() {
-                                let max = max_body_scroll(body);
+                                let max = max_body_scroll(&body);
                                 self.body_scroll_offset = (self.body_scroll_offset + 10).min(max);
                             }
                         }
@@ -400,7 +782,7 @@ impl App {
                             if remaining > 0
                                 && let Some(body) = self.response_body_text()
                             {
-                                let body_max = max_body_scroll(body);
+                                let body_max = max_body_scroll(&body);
                                 self.response_scroll_offset =
                                     (self.response_scroll_offset + remaining).min(body_max);
                             }
@@ -425,14 +807,14 @@ impl App {
                         }
                         2 => {
                             if let Some(body) = self.selected_body_text() {
-                                self.body_scroll_offset = max_body_scroll(body);
+                                self.body_scroll_offset = max_body_scroll(&body);
                             }
                         }
                         3 => {
                             self.response_headers_scroll_offset =
                                 self.max_response_headers_scroll();
                             if let Some(body) = self.response_body_text() {
-                                self.response_scroll_offset = max_body_scroll(body);
+                                self.response_scroll_offset = max_body_scroll(&body);
                             } else {
                                 self.response_scroll_offset = 0;
                             }
@@ -468,6 +850,18 @@ impl App {
                         KeyCode::Char('q') | KeyCode::Esc => {
                             self.should_quit = true;
                         }
+                        KeyCode::Char('i') => {
+                            self.open_intercept_editor(DetailReturnTarget::Listening);
+                        }
+                        KeyCode::Char('p') => {
+                            self.toggle_intercept(DetailReturnTarget::Listening);
+                        }
+                        KeyCode::Char('r') => {
+                            self.tunnel_reconnect_requested = true;
+                            self.listening_connected = false;
+                            self.listening_error =
+                                Some("Manual reconnect requested...".to_string());
+                        }
                         KeyCode::Char('/') => {
                             self.search_active = true;
                             self.search_query.clear();
@@ -551,14 +945,18 @@ impl App {
                             content_length: tunnel_req
                                 .body
                                 .as_ref()
-                                .map(|b| b.len() as i64)
+                                .map(|b| b.original_len() as i64)
                                 .unwrap_or(0),
                             method: tunnel_req.method.clone(),
                             url: tunnel_req.path.clone(),
                             path: Some(tunnel_req.path.clone()),
                             query_params: parse_query_string(&tunnel_req.query_string),
                             created_at: chrono::Utc::now().to_rfc3339(),
-                            body_preview: tunnel_req.body.clone(),
+                            body_preview: tunnel_req
+                                .body
+                                .as_ref()
+                                .and_then(|b| b.as_text())
+                                .map(str::to_string),
                             body: tunnel_req.body.clone(),
                         };
                         self.selected_request = Some(webhook_req);
@@ -573,6 +971,13 @@ impl App {
                             body: tunnel_req.response_body.clone(),
                             duration_ms,
                             error: tunnel_req.error.clone(),
+                            target_url: Some(tunnel_req.target_url.clone()),
+                            route: tunnel_req.route.clone(),
+                            bytes_out: tunnel_req.bytes_out,
+                            stream_status: tunnel_req.stream_status,
+                            websocket: tunnel_req.websocket.clone(),
+                            mocked: tunnel_req.mocked,
+                            fault: tunnel_req.fault.clone(),
                         });
 
                         self.current_tab = 0;
@@ -603,6 +1008,12 @@ impl App {
                         }
                     }
                 }
+                KeyCode::Char('i') => {
+                    self.open_intercept_editor(DetailReturnTarget::Tunneling);
+                }
+                KeyCode::Char('p') => {
+                    self.toggle_intercept(DetailReturnTarget::Tunneling);
+                }
                 KeyCode::Char('r') => {
                     self.tunnel_reconnect_requested = true;
                     self.tunnel_connected = false;
@@ -615,6 +1026,49 @@ impl App {
                 }
                 _ => {}
             },
+            AppState::Intercepting => {
+                let Some(editor) = self.intercept_editor.as_mut() else {
+                    self.state = AppState::Listening;
+                    return Ok(());
+                };
+                let count = editor.field_count();
+                match key.code {
+                    KeyCode::Char('s' | 'd' | 'r')
+                        if key.modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        self.resolve_intercept(key.code);
+                    }
+                    KeyCode::Esc => {
+                        // Leave the request held; `i` reopens it
+                        let target = editor.return_to.clone();
+                        self.intercept_editor = None;
+                        self.state = match target {
+                            DetailReturnTarget::Tunneling => AppState::Tunneling,
+                            DetailReturnTarget::Listening => AppState::Listening,
+                        };
+                    }
+                    KeyCode::Tab => {
+                        editor.focus = (editor.focus + 1) % count;
+                    }
+                    KeyCode::BackTab => {
+                        editor.focus = (editor.focus + count - 1) % count;
+                    }
+                    KeyCode::Enter if editor.is_multiline() => {
+                        editor.fields[editor.focus].push('\n');
+                    }
+                    KeyCode::Enter => {
+                        editor.focus = (editor.focus + 1) % count;
+                    }
+                    KeyCode::Backspace => {
+                        editor.fields[editor.focus].pop();
+                    }
+                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        editor.fields[editor.focus].push(c);
+                        editor.error = None;
+                    }
+                    _ => {}
+                }
+            }
             AppState::ExportMenu => match key.code {
                 KeyCode::Char('1') | KeyCode::Char('c') => {
                     if let Some(request) = &self.selected_request {
@@ -622,7 +1076,10 @@ impl App {
                         match arboard::Clipboard::new().and_then(|mut cb| cb.set_text(&curl)) {
                             Ok(_) => {
                                 self.status_message = Some((
-                                    "cURL command copied to clipboard!".into(),
+                                    format!(
+                                        "cURL command copied to clipboard!{}",
+                                        truncation_warning(request)
+                                    ),
                                     std::time::Instant::now(),
                                 ));
                             }
@@ -645,7 +1102,10 @@ impl App {
                                 {
                                     Ok(_) => {
                                         self.status_message = Some((
-                                            "JSON copied to clipboard!".into(),
+                                            format!(
+                                                "JSON copied to clipboard!{}",
+                                                truncation_warning(request)
+                                            ),
                                             std::time::Instant::now(),
                                         ));
                                     }
@@ -677,7 +1137,7 @@ impl App {
                     if !self.forward_url_input.is_empty()
                         && self.is_valid_url(&self.forward_url_input) =>
                 {
-                    self.state = AppState::ForwardingRequest;
+                    self.start_replay();
                 }
                 KeyCode::Char(c) => {
                     self.forward_url_input.push(c);
@@ -711,18 +1171,43 @@ impl App {
         Ok(())
     }
 
+    /// Replay the selected request, unless only part of its body was kept.
+    fn start_replay(&mut self) {
+        match self
+            .selected_request
+            .as_ref()
+            .and_then(|r| r.body.as_ref())
+            .filter(|body| body.is_truncated())
+        {
+            Some(body) => {
+                self.status_message = Some((
+                    format!(
+                        "Cannot replay: only {} of {} body bytes were kept",
+                        body.len(),
+                        body.original_len()
+                    ),
+                    std::time::Instant::now(),
+                ));
+                self.state = AppState::ShowRequestDetail;
+            }
+            None => self.state = AppState::ForwardingRequest,
+        }
+    }
+
     pub fn is_valid_url(&self, url: &str) -> bool {
         url.starts_with("http://") || url.starts_with("https://")
     }
 
     pub async fn forward_request(&mut self) -> Result<()> {
         if let Some(request) = &self.selected_request {
-            let client = ApiClient::for_forwarding();
+            let result = async {
+                ApiClient::for_forwarding(crate::http::settings()?)?
+                    .forward_request(request, &self.forward_url_input)
+                    .await
+            }
+            .await;
 
-            match client
-                .forward_request(request, &self.forward_url_input)
-                .await
-            {
+            match result {
                 Ok(response) => {
                     self.forward_result = Some(response);
                     self.state = AppState::ForwardResult;
@@ -761,12 +1246,20 @@ impl App {
 
         let has_body_method = matches!(request.method.as_str(), "POST" | "PUT" | "PATCH");
         if has_body_method {
-            let body = request
-                .body
-                .as_ref()
-                .or(request.body_preview.as_ref())
-                .cloned()
-                .unwrap_or_default();
+            // Binary bodies are piped in from base64 so the exact bytes are sent
+            if let Some(body) = &request.body
+                && body.is_binary()
+            {
+                let encoded = base64::engine::general_purpose::STANDARD.encode(body.as_bytes());
+                parts[0] = format!("echo '{}' | base64 -d | {}", encoded, parts[0]);
+                parts.push("  --data-binary @-".to_string());
+                return parts.join(" \\\n");
+            }
+
+            let body = match &request.body {
+                Some(body) => body.as_text().unwrap_or_default(),
+                None => request.body_preview.as_deref().unwrap_or_default(),
+            };
             if !body.is_empty() {
                 let escaped_body = body.replace('\'', "'\\''");
                 parts.push(format!("  -d '{}'", escaped_body));
@@ -940,7 +1433,7 @@ mod tests {
             id: "r1".to_string(),
             timestamp: 0,
             remote_addr: "".to_string(),
-            headers: std::collections::HashMap::new(),
+            headers: Headers::new(),
             content_length: 0,
             method: "GET".to_string(),
             url: "/".to_string(),
@@ -982,6 +1475,16 @@ mod tests {
         assert!(app.tunnel_error.is_some());
     }
 
+    #[test]
+    fn test_r_from_listening_requests_reconnect() {
+        let mut app = make_app_with_state(AppState::Listening);
+        app.listening_connected = true;
+        app.handle_key_event(key_event(KeyCode::Char('r'))).unwrap();
+        assert!(app.take_tunnel_reconnect_request());
+        assert!(!app.listening_connected);
+        assert!(app.listening_error.is_some());
+    }
+
     #[test]
     fn test_q_from_error_quits() {
         let mut app = make_app_with_state(AppState::Error {
@@ -1019,14 +1522,20 @@ mod tests {
             status: Some(200),
             completed_at: Some(std::time::Instant::now()),
             error: None,
-            headers: HashMap::new(),
-            body: Some("{\"test\":true}".to_string()),
+            headers: Headers::new(),
+            body: Some("{\"test\":true}".into()),
             query_string: String::new(),
-            response_headers: Some(HashMap::from([(
-                "content-type".to_string(),
-                "application/json".to_string(),
-            )])),
-            response_body: Some("{\"ok\":true}".to_string()),
+            response_headers: Some([("content-type", "application/json")].into_iter().collect()),
+            response_body: Some("{\"ok\":true}".into()),
+            target_url: "http://localhost:8080/webhook".to_string(),
+            route: Some("/webhook → http://localhost:8080".to_string()),
+            bytes_in: 13,
+            bytes_out: 11,
+            stream_status: StreamStatus::Streamed,
+            websocket: None,
+            intercept: None,
+            mocked: None,
+            fault: None,
         });
         app.tunnel_selected_index = 0;
         app.handle_key_event(key_event(KeyCode::Enter)).unwrap();
@@ -1037,6 +1546,13 @@ mod tests {
         let resp = app.selected_tunnel_response.unwrap();
         assert_eq!(resp.status, Some(200));
         assert!(resp.body.is_some());
+        assert_eq!(
+            resp.target_url.as_deref(),
+            Some("http://localhost:8080/webhook")
+        );
+        assert!(resp.route.is_some());
+        assert_eq!(resp.bytes_out, 11);
+        assert_eq!(resp.stream_status, StreamStatus::Streamed);
     }
 
     #[test]
@@ -1050,11 +1566,20 @@ mod tests {
             status: Some(200),
             completed_at: Some(std::time::Instant::now()),
             error: None,
-            headers: HashMap::new(),
+            headers: Headers::new(),
             body: None,
             query_string: "q=hello%20world&plus=a+b".to_string(),
-            response_headers: Some(HashMap::new()),
+            response_headers: Some(Headers::new()),
             response_body: None,
+            target_url: "http://localhost:3000/search".to_string(),
+            route: None,
+            bytes_in: 0,
+            bytes_out: 0,
+            stream_status: StreamStatus::Buffered,
+            websocket: None,
+            intercept: None,
+            mocked: None,
+            fault: None,
         });
         app.tunnel_selected_index = 0;
         app.handle_key_event(key_event(KeyCode::Enter)).unwrap();
@@ -1075,10 +1600,17 @@ mod tests {
         app.selected_request = Some(make_request("GET", "/"));
         app.selected_tunnel_response = Some(TunnelResponseData {
             status: Some(200),
-            headers: HashMap::new(),
+            headers: Headers::new(),
             body: None,
             duration_ms: Some(42),
             error: None,
+            target_url: None,
+            route: None,
+            bytes_out: 0,
+            stream_status: StreamStatus::Buffered,
+            websocket: None,
+            mocked: None,
+            fault: None,
         });
         assert_eq!(app.current_tab, 0);
         // Cycle through 4 tabs: 0 -> 1 -> 2 -> 3 -> 0
@@ -1108,9 +1640,16 @@ mod tests {
         app.selected_tunnel_response = Some(TunnelResponseData {
             status: Some(200),
             headers,
-            body: Some(body),
+            body: Some(body.into()),
             duration_ms: Some(42),
             error: None,
+            target_url: None,
+            route: None,
+            bytes_out: 0,
+            stream_status: StreamStatus::Buffered,
+            websocket: None,
+            mocked: None,
+            fault: None,
         });
         app.current_tab = 3;
 
@@ -1128,30 +1667,43 @@ mod tests {
 
     #[test]
     fn test_truncate_body_under_limit() {
-        let small = Some("hello".to_string());
-        assert_eq!(truncate_body(small), Some("hello".to_string()));
+        let small = Some(Body::from("hello"));
+        assert_eq!(truncate_body(small), Some(Body::from("hello")));
     }
 
     #[test]
     fn test_truncate_body_over_limit() {
-        let large = Some("x".repeat(MAX_BODY_SIZE + 100));
+        let large = Some(Body::from("x".repeat(MAX_BODY_SIZE + 100)));
         let result = truncate_body(large).unwrap();
-        assert!(result.len() < MAX_BODY_SIZE + 100);
-        assert!(result.ends_with("\n...(truncated)"));
+        assert_eq!(result.len(), MAX_BODY_SIZE);
+        assert_eq!(result.original_len(), MAX_BODY_SIZE + 100);
+        assert!(result.as_bytes().iter().all(|&b| b == b'x'));
+        assert!(result.display().ends_with("\n...(truncated)"));
     }
 
     #[test]
     fn test_truncate_body_over_limit_utf8_boundary() {
         // 3-byte codepoint makes MAX_BODY_SIZE likely land mid-character.
-        let large = Some("€".repeat((MAX_BODY_SIZE / 3) + 100));
+        let large = Some(Body::from("€".repeat((MAX_BODY_SIZE / 3) + 100)));
         let result = truncate_body(large).unwrap();
+        let prefix = result.as_text().unwrap();
 
-        assert!(result.ends_with("\n...(truncated)"));
-        let prefix = result.strip_suffix("\n...(truncated)").unwrap();
+        assert!(result.is_truncated());
         assert!(prefix.len() <= MAX_BODY_SIZE);
         assert!(prefix.is_char_boundary(prefix.len()));
     }
 
+    #[test]
+    fn test_truncate_body_keeps_binary_prefix() {
+        let large = Some(Body::from(vec![0xff; MAX_BODY_SIZE + 100]));
+        let result = truncate_body(large).unwrap();
+        assert!(result.is_binary());
+        assert_eq!(
+            result.as_bytes()[..MAX_BODY_SIZE],
+            vec![0xff; MAX_BODY_SIZE]
+        );
+    }
+
     #[test]
     fn test_truncate_body_none() {
         assert_eq!(truncate_body(None), None);
@@ -1163,7 +1715,7 @@ mod tests {
             id: uuid::Uuid::new_v4().to_string(),
             timestamp: 0,
             remote_addr: "127.0.0.1".to_string(),
-            headers: std::collections::HashMap::new(),
+            headers: Headers::new(),
             content_length: 0,
             method: method.to_string(),
             url: url.to_string(),
@@ -1182,7 +1734,7 @@ mod tests {
     ) -> WebhookRequest {
         let mut r = make_request(method, url);
         for (k, v) in headers {
-            r.headers.insert(k.to_string(), v.to_string());
+            r.headers.push(k, v);
         }
         r
     }
@@ -1220,6 +1772,19 @@ mod tests {
         assert!(matches!(app.state, AppState::ForwardingRequest));
     }
 
+    #[test]
+    fn test_replay_refuses_truncated_body() {
+        let mut app = make_app_with_state(AppState::ShowRequestDetail);
+        let mut request = make_request("POST", "/webhook");
+        request.body = truncate_body(Some(Body::from("x".repeat(MAX_BODY_SIZE + 1))));
+        app.selected_request = Some(request);
+        app.forward_url_input = "http://localhost:3000".to_string();
+        app.handle_key_event(key_event(KeyCode::Char('r'))).unwrap();
+        assert!(matches!(app.state, AppState::ShowRequestDetail));
+        let (message, _) = app.status_message.as_ref().unwrap();
+        assert!(message.starts_with("Cannot replay"));
+    }
+
     #[test]
     fn test_replay_without_url_stays_in_detail() {
         let mut app = make_app_with_state(AppState::ShowRequestDetail);
@@ -1281,7 +1846,7 @@ mod tests {
             vec![("content-type", "application/json")],
         );
         let mut request = request;
-        request.body = Some(r#"{"key":"value"}"#.to_string());
+        request.body = Some(r#"{"key":"value"}"#.into());
 
         let curl = App::generate_curl(&request);
         assert!(curl.contains("curl -X POST"));
@@ -1323,6 +1888,38 @@ mod tests {
         assert_eq!(parsed["url"], "/webhook");
     }
 
+    #[test]
+    fn test_json_export_round_trips_repeated_headers() {
+        let request = make_request_with_headers(
+            "POST",
+            "/webhook",
+            vec![("Via", "1.1 a"), ("X-Id", "1"), ("Via", "1.1 b")],
+        );
+        let json = App::generate_json_export(&request).unwrap();
+        let parsed: WebhookRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed.headers.get_all("via").collect::<Vec<_>>(),
+            ["1.1 a", "1.1 b"]
+        );
+
+        let curl = App::generate_curl(&request);
+        assert!(curl.contains("-H 'Via: 1.1 a'") && curl.contains("-H 'Via: 1.1 b'"));
+    }
+
+    #[test]
+    fn test_binary_body_exports_exact_bytes() {
+        let mut request = make_request("POST", "https://example.com/upload");
+        request.body = Some(Body::from(vec![0x00, 0x9f, 0x92, 0x96, b'\'']));
+
+        let curl = App::generate_curl(&request);
+        assert!(curl.starts_with("echo 'AJ+Slic=' | base64 -d | curl -X POST"));
+        assert!(curl.ends_with("--data-binary @-"));
+
+        let json = App::generate_json_export(&request).unwrap();
+        let parsed: WebhookRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.body, request.body);
+    }
+
     // === Search / filter tests ===
 
     #[test]
@@ -1382,4 +1979,116 @@ mod tests {
         app.tick();
         assert!(app.status_message.is_none());
     }
+
+    fn held_entry(request_id: &str, can_respond: bool) -> HeldEntry {
+        HeldEntry {
+            request_id: request_id.to_string(),
+            request: HeldRequest {
+                method: "POST".to_string(),
+                path: "/hooks".to_string(),
+                query_string: "a=1".to_string(),
+                headers: [("content-type", "application/json")].into_iter().collect(),
+                body: "{}".to_string(),
+            },
+            can_respond,
+        }
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent {
+            modifiers: KeyModifiers::CONTROL,
+            ..key_event(KeyCode::Char(c))
+        }
+    }
+
+    #[test]
+    fn test_intercept_editor_builds_edited_request() {
+        let mut editor =
+            InterceptEditor::new(&held_entry("r1", true), DetailReturnTarget::Tunneling);
+        assert_eq!(editor.fields[1], "/hooks?a=1");
+        assert_eq!(editor.to_request().unwrap(), held_entry("r1", true).request);
+
+        editor.fields[0] = "put".to_string();
+        editor.fields[1] = "/other".to_string();
+        editor.fields[2] = "x-test: yes\n\n".to_string();
+        let request = editor.to_request().unwrap();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.path, "/other");
+        assert_eq!(request.query_string, "");
+        assert_eq!(request.headers, [("x-test", "yes")].into_iter().collect());
+
+        editor.fields[2] = "not a header".to_string();
+        assert!(editor.to_request().is_err());
+
+        editor.fields[4] = "99".to_string();
+        assert!(editor.to_response().is_err());
+        editor.fields[4] = "202".to_string();
+        editor.fields[6] = "ok".to_string();
+        let response = editor.to_response().unwrap();
+        assert_eq!(response.status, 202);
+        assert_eq!(response.body, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_intercept_forward_edited_request_from_tunneling() {
+        let interceptor = Interceptor::new(Vec::new());
+        let rx = interceptor.hold("r1");
+        let mut app = make_app_with_state(AppState::Tunneling);
+        app.interceptor = Some(interceptor);
+        app.held_requests.push_back(held_entry("r1", true));
+
+        app.handle_key_event(key_event(KeyCode::Char('i'))).unwrap();
+        assert!(matches!(app.state, AppState::Intercepting));
+
+        // Tab to the path field and append a segment
+        app.handle_key_event(key_event(KeyCode::Tab)).unwrap();
+        app.handle_key_event(key_event(KeyCode::Char('x'))).unwrap();
+        app.handle_key_event(ctrl('s')).unwrap();
+
+        let InterceptDecision::Forward(request) = rx.await.unwrap() else {
+            panic!("expected the request to be forwarded");
+        };
+        assert_eq!(request.query_string, "a=1x");
+        assert!(app.held_requests.is_empty());
+        assert!(app.intercept_editor.is_none());
+        assert!(matches!(app.state, AppState::Tunneling));
+    }
+
+    #[tokio::test]
+    async fn test_intercept_drop_opens_next_held_request() {
+        let interceptor = Interceptor::new(Vec::new());
+        let first = interceptor.hold("r1");
+        let _second = interceptor.hold("r2");
+        let mut app = make_app_with_state(AppState::Listening);
+        app.interceptor = Some(interceptor);
+        app.held_requests.push_back(held_entry("r1", false));
+        app.held_requests.push_back(held_entry("r2", false));
+
+        app.handle_key_event(key_event(KeyCode::Char('i'))).unwrap();
+        app.handle_key_event(ctrl('d')).unwrap();
+        assert_eq!(first.await.unwrap(), InterceptDecision::Drop);
+        assert!(matches!(app.state, AppState::Intercepting));
+        assert_eq!(app.intercept_editor.as_ref().unwrap().request_id, "r2");
+
+        // Listen mode cannot answer requests directly
+        app.handle_key_event(ctrl('r')).unwrap();
+        assert_eq!(app.held_requests.len(), 1);
+
+        app.handle_key_event(key_event(KeyCode::Esc)).unwrap();
+        assert!(matches!(app.state, AppState::Listening));
+        assert_eq!(app.held_requests.len(), 1);
+    }
+
+    #[test]
+    fn test_p_toggles_intercept_mode() {
+        let mut app = make_app_with_state(AppState::Tunneling);
+        app.interceptor = Some(Interceptor::new(vec![
+            crate::intercept::InterceptRule::parse("*").unwrap(),
+        ]));
+
+        app.handle_key_event(key_event(KeyCode::Char('p'))).unwrap();
+        assert!(!app.interceptor.as_ref().unwrap().is_enabled());
+        app.handle_key_event(key_event(KeyCode::Char('p'))).unwrap();
+        assert!(app.interceptor.as_ref().unwrap().is_enabled());
+    }
 }
@@ -2,11 +2,22 @@ use anyhow::{Result, anyhow};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Polling interval when the server does not send one (RFC 8628 section 3.2).
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 5;
+/// Added to the interval on every `slow_down` response (RFC 8628 section 3.5).
+const SLOW_DOWN_INCREMENT_SECS: u64 = 5;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeviceCodeResponse {
     pub device_code: String,
     pub user_code: String,
     pub expires_in: u64,
+    #[serde(default)]
+    pub verification_uri: Option<String>,
+    #[serde(default)]
+    pub verification_uri_complete: Option<String>,
+    #[serde(default)]
+    pub interval: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,16 +43,22 @@ pub struct DeviceCodeFlow {
     device_code: Option<String>,
     user_code: Option<String>,
     expires_at: Option<DateTime<Utc>>,
+    verification_uri: Option<String>,
+    verification_uri_complete: Option<String>,
+    interval: std::time::Duration,
 }
 
 impl DeviceCodeFlow {
-    pub fn new(base_url: String) -> Self {
+    pub fn new(client: reqwest::Client, base_url: String) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client,
             base_url,
             device_code: None,
             user_code: None,
             expires_at: None,
+            verification_uri: None,
+            verification_uri_complete: None,
+            interval: std::time::Duration::from_secs(DEFAULT_POLL_INTERVAL_SECS),
         }
     }
 
@@ -67,11 +84,19 @@ impl DeviceCodeFlow {
         self.device_code = Some(device_response.device_code.clone());
         self.user_code = Some(device_response.user_code.clone());
         self.expires_at = Some(Utc::now() + Duration::seconds(device_response.expires_in as i64));
+        self.verification_uri = device_response.verification_uri;
+        self.verification_uri_complete = device_response.verification_uri_complete;
+        if let Some(interval) = device_response.interval.filter(|secs| *secs > 0) {
+            self.interval = std::time::Duration::from_secs(interval);
+        }
 
         Ok(device_response.user_code)
     }
 
-    pub async fn poll_for_authorization(&self) -> Result<Option<TokenResponse>> {
+    /// Ask once whether the user approved the code. `Ok(None)` means keep
+    /// polling after [`interval`](Self::interval); a `slow_down` answer also
+    /// lengthens that interval.
+    pub async fn poll_for_authorization(&mut self) -> Result<Option<TokenResponse>> {
         let device_code = self
             .device_code
             .as_ref()
@@ -83,34 +108,53 @@ impl DeviceCodeFlow {
         );
 
         let response = self.client.get(&url).send().await?;
+        let status = response.status();
 
-        match response.status().as_u16() {
-            200 => {
+        match status.as_u16() {
+            // RFC 8628 sends pending and error states as 400; older servers use 200.
+            200 | 400 => {
                 let text = response.text().await?;
 
-                if let Ok(token_response) = serde_json::from_str::<TokenResponse>(&text) {
-                    if token_response.access_token.is_empty() {
-                        // No access_token means it parsed a pending response
-                        Err(anyhow!("Unexpected response format"))
-                    } else {
-                        Ok(Some(token_response))
-                    }
-                } else if let Ok(pending_response) = serde_json::from_str::<PendingResponse>(&text)
+                if let Ok(token_response) = serde_json::from_str::<TokenResponse>(&text)
+                    && !token_response.access_token.is_empty()
                 {
-                    if pending_response.error == "authorization_pending" {
-                        Ok(None)
-                    } else {
-                        Err(anyhow!("Authorization error: {}", pending_response.error))
-                    }
-                } else {
-                    Err(anyhow!("Unexpected response format"))
+                    return Ok(Some(token_response));
+                }
+                match serde_json::from_str::<PendingResponse>(&text) {
+                    Ok(pending) => match pending.error.as_str() {
+                        "authorization_pending" => Ok(None),
+                        "slow_down" => {
+                            self.interval +=
+                                std::time::Duration::from_secs(SLOW_DOWN_INCREMENT_SECS);
+                            Ok(None)
+                        }
+                        "access_denied" => Err(anyhow!("Authorization was denied")),
+                        "expired_token" => Err(anyhow!("Device code expired")),
+                        other => Err(anyhow!("Authorization error: {}", other)),
+                    },
+                    Err(_) if status.as_u16() == 400 => Err(anyhow!("Polling failed: {}", status)),
+                    Err(_) => Err(anyhow!("Unexpected response format")),
                 }
             }
             404 => Err(anyhow!("Device code not found or expired")),
-            _ => Err(anyhow!("Polling failed: {}", response.status())),
+            _ => Err(anyhow!("Polling failed: {}", status)),
         }
     }
 
+    /// How long to wait between polls.
+    pub fn interval(&self) -> std::time::Duration {
+        self.interval
+    }
+
+    /// Where the user approves the code, preferring the server's links over
+    /// `fallback`. The second value is a link with the code filled in, if any.
+    pub fn verification_urls(&self, fallback: String) -> (String, Option<String>) {
+        (
+            self.verification_uri.clone().unwrap_or(fallback),
+            self.verification_uri_complete.clone(),
+        )
+    }
+
     pub fn format_user_code(&self) -> Option<String> {
         self.user_code.as_ref().map(|code| {
             if code.len() == 8 {
@@ -133,6 +177,19 @@ impl DeviceCodeFlow {
     }
 }
 
+/// Render `text` as a QR code of half-height blocks for the terminal.
+pub fn render_qr(text: &str) -> Result<String> {
+    use qrcode::render::unicode::Dense1x2;
+    let code = qrcode::QrCode::new(text.as_bytes())
+        .map_err(|e| anyhow!("Failed to build QR code: {}", e))?;
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .quiet_zone(true)
+        .build())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,7 +205,7 @@ mod tests {
             .create_async()
             .await;
 
-        let mut flow = DeviceCodeFlow::new(server.url());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
         let user_code = flow.initiate_device_flow().await.unwrap();
         assert_eq!(user_code, "ABCD1234");
         assert_eq!(flow.device_code.as_deref(), Some("dev123"));
@@ -165,7 +222,7 @@ mod tests {
             .create_async()
             .await;
 
-        let mut flow = DeviceCodeFlow::new(server.url());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
         let result = flow.initiate_device_flow().await;
         assert!(result.is_err());
         mock.assert_async().await;
@@ -182,13 +239,83 @@ mod tests {
             .create_async()
             .await;
 
-        let mut flow = DeviceCodeFlow::new(server.url());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
         flow.device_code = Some("dev123".to_string());
         let result = flow.poll_for_authorization().await.unwrap();
         assert!(result.is_none());
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn test_initiate_device_flow_reads_interval_and_links() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/api/v1/device")
+            .with_status(200)
+            .with_body(
+                r#"{"device_code":"dev123","user_code":"ABCD1234","expires_in":600,"interval":7,
+                "verification_uri":"https://example.com/device",
+                "verification_uri_complete":"https://example.com/device?code=ABCD1234"}"#,
+            )
+            .create_async()
+            .await;
+
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
+        flow.initiate_device_flow().await.unwrap();
+        assert_eq!(flow.interval(), std::time::Duration::from_secs(7));
+        let (url, complete) = flow.verification_urls("https://fallback".to_string());
+        assert_eq!(url, "https://example.com/device");
+        assert_eq!(
+            complete.as_deref(),
+            Some("https://example.com/device?code=ABCD1234")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_slow_down_increases_interval() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/device?device_code=dev123")
+            .with_status(400)
+            .with_body(r#"{"error":"slow_down"}"#)
+            .create_async()
+            .await;
+
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
+        flow.device_code = Some("dev123".to_string());
+        assert!(flow.poll_for_authorization().await.unwrap().is_none());
+        assert_eq!(flow.interval(), std::time::Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_poll_rfc_error_responses() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/device?device_code=pending")
+            .with_status(400)
+            .with_body(r#"{"error":"authorization_pending"}"#)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/device?device_code=denied")
+            .with_status(400)
+            .with_body(r#"{"error":"access_denied"}"#)
+            .create_async()
+            .await;
+
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
+        flow.device_code = Some("pending".to_string());
+        assert!(flow.poll_for_authorization().await.unwrap().is_none());
+        assert_eq!(
+            flow.interval(),
+            std::time::Duration::from_secs(DEFAULT_POLL_INTERVAL_SECS)
+        );
+
+        flow.device_code = Some("denied".to_string());
+        let err = flow.poll_for_authorization().await.unwrap_err();
+        assert!(err.to_string().contains("denied"));
+    }
+
     #[tokio::test]
     async fn test_poll_for_authorization_success() {
         let mut server = mockito::Server::new_async().await;
@@ -200,7 +327,7 @@ mod tests {
             .create_async()
             .await;
 
-        let mut flow = DeviceCodeFlow::new(server.url());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
         flow.device_code = Some("dev123".to_string());
         let result = flow.poll_for_authorization().await.unwrap();
         let token_response = result.unwrap();
@@ -219,7 +346,7 @@ mod tests {
             .create_async()
             .await;
 
-        let mut flow = DeviceCodeFlow::new(server.url());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
         flow.device_code = Some("dev123".to_string());
         let result = flow.poll_for_authorization().await;
         assert!(result.is_err());
@@ -230,35 +357,47 @@ mod tests {
     #[tokio::test]
     async fn test_poll_without_device_code_errors() {
         let server = mockito::Server::new_async().await;
-        let flow = DeviceCodeFlow::new(server.url());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), server.url());
         let result = flow.poll_for_authorization().await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No device code"));
     }
 
+    #[test]
+    fn test_render_qr() {
+        let qr = render_qr("https://example.com/device?code=ABCD1234").unwrap();
+        let lines: Vec<_> = qr.lines().collect();
+        assert!(lines.len() > 10);
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.chars().count() == lines[0].chars().count())
+        );
+    }
+
     #[test]
     fn test_format_user_code_8_chars() {
-        let mut flow = DeviceCodeFlow::new("http://localhost".to_string());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), "http://localhost".to_string());
         flow.user_code = Some("ABCD1234".to_string());
         assert_eq!(flow.format_user_code(), Some("ABCD-1234".to_string()));
     }
 
     #[test]
     fn test_format_user_code_other_length() {
-        let mut flow = DeviceCodeFlow::new("http://localhost".to_string());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), "http://localhost".to_string());
         flow.user_code = Some("ABC".to_string());
         assert_eq!(flow.format_user_code(), Some("ABC".to_string()));
     }
 
     #[test]
     fn test_format_user_code_none() {
-        let flow = DeviceCodeFlow::new("http://localhost".to_string());
+        let flow = DeviceCodeFlow::new(reqwest::Client::new(), "http://localhost".to_string());
         assert_eq!(flow.format_user_code(), None);
     }
 
     #[test]
     fn test_time_remaining_future() {
-        let mut flow = DeviceCodeFlow::new("http://localhost".to_string());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), "http://localhost".to_string());
         flow.expires_at = Some(Utc::now() + Duration::minutes(5));
         let remaining = flow.time_remaining().unwrap();
         assert!(remaining > Duration::zero());
@@ -266,7 +405,7 @@ mod tests {
 
     #[test]
     fn test_time_remaining_past() {
-        let mut flow = DeviceCodeFlow::new("http://localhost".to_string());
+        let mut flow = DeviceCodeFlow::new(reqwest::Client::new(), "http://localhost".to_string());
         flow.expires_at = Some(Utc::now() - Duration::minutes(5));
         let remaining = flow.time_remaining().unwrap();
         assert_eq!(remaining, Duration::zero());
@@ -274,7 +413,7 @@ mod tests {
 
     #[test]
     fn test_time_remaining_not_set() {
-        let flow = DeviceCodeFlow::new("http://localhost".to_string());
+        let flow = DeviceCodeFlow::new(reqwest::Client::new(), "http://localhost".to_string());
         assert!(flow.time_remaining().is_none());
     }
 }
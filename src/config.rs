@@ -1,38 +1,412 @@
-use anyhow::Result;
+use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
-#[derive(Debug, Default, Serialize, Deserialize)]
-pub struct Config {
+use crate::credentials::{self, CredentialMap, Credentials, Passphrase};
+use crate::errors::ConfigError;
+
+/// Name of the profile stored in the top-level fields of the config file.
+pub const DEFAULT_PROFILE: &str = "default";
+pub const DEFAULT_API_URL: &str = "https://app.hooklistener.com";
+pub const DEFAULT_WS_URL: &str = "wss://api.hooklistener.com";
+/// Environment variable holding an API token, used instead of stored credentials.
+pub const TOKEN_ENV_VAR: &str = "HOOKLISTENER_TOKEN";
+
+/// Profile chosen with `--profile` or `HOOKLISTENER_PROFILE` for this process.
+static SELECTED_PROFILE: OnceLock<String> = OnceLock::new();
+/// Warn about a loosely permissioned credentials file once per process.
+static PERMISSION_WARNING_SHOWN: AtomicBool = AtomicBool::new(false);
+/// Service URLs of the loaded profile, read once on first use.
+static PROFILE_URLS: OnceLock<(Option<String>, Option<String>)> = OnceLock::new();
+
+/// Use `name` instead of the config file's active profile for this process.
+pub fn select_profile(name: String) {
+    let _ = SELECTED_PROFILE.set(name);
+}
+
+/// API base URL: `HOOKLISTENER_API_URL`, then the profile, then production.
+pub fn api_url() -> Result<String> {
+    if let Ok(url) = std::env::var("HOOKLISTENER_API_URL") {
+        return Ok(url);
+    }
+    Ok(profile_urls()?
+        .0
+        .clone()
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string()))
+}
+
+/// WebSocket base URL: `HOOKLISTENER_WS_URL`, then the profile, then production.
+pub fn ws_url() -> Result<String> {
+    if let Ok(url) = std::env::var("HOOKLISTENER_WS_URL") {
+        return Ok(url);
+    }
+    let (profile_api_url, profile_ws_url) = profile_urls()?;
+    Ok(profile_ws_url_or_default(
+        profile_api_url.as_deref(),
+        profile_ws_url.as_deref(),
+    ))
+}
+
+/// A profile's WebSocket URL. A profile with only an API URL is served from
+/// that host, so its WebSocket URL is derived from it rather than production.
+pub fn profile_ws_url_or_default(api_url: Option<&str>, ws_url: Option<&str>) -> String {
+    match (ws_url, api_url) {
+        (Some(ws_url), _) => ws_url.to_string(),
+        (None, Some(api_url)) => api_url
+            .trim_end_matches('/')
+            .replacen("https://", "wss://", 1)
+            .replacen("http://", "ws://", 1),
+        (None, None) => DEFAULT_WS_URL.to_string(),
+    }
+}
+
+/// API token from `HOOKLISTENER_TOKEN`, if set and non-empty.
+pub fn env_token() -> Option<String> {
+    std::env::var(TOKEN_ENV_VAR)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// Where the credential used for API calls comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialSource {
+    /// `HOOKLISTENER_TOKEN`
+    Environment,
+    /// Long-lived token saved by `login --with-token`
+    ApiToken,
+    /// Access and refresh tokens from the device flow
+    Session,
+}
+
+impl CredentialSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            CredentialSource::Environment => "HOOKLISTENER_TOKEN environment variable",
+            CredentialSource::ApiToken => "API token (login --with-token)",
+            CredentialSource::Session => "device login session",
+        }
+    }
+}
+
+/// A config that fails to load is an error rather than a fall back to
+/// production, which would send the profile's tokens to the wrong host.
+fn profile_urls() -> Result<&'static (Option<String>, Option<String>)> {
+    if let Some(urls) = PROFILE_URLS.get() {
+        return Ok(urls);
+    }
+    let config = Config::load().context("Failed to load the profile's service URLs")?;
+    Ok(PROFILE_URLS.get_or_init(|| (config.api_url, config.ws_url)))
+}
+
+/// Lock file next to the config, guarding every write to the config directory.
+const LOCK_FILE_NAME: &str = "config.lock";
+
+/// Exclusive advisory lock shared by all `hooklistener` processes, released on drop.
+/// Config and credentials files are replaced by rename, so readers never need it.
+#[derive(Debug)]
+pub struct ConfigLock {
+    _file: fs::File,
+    config_path: PathBuf,
+}
+
+impl ConfigLock {
+    /// Block until the lock for the default config location is free.
+    pub fn acquire() -> Result<Self> {
+        Self::acquire_for(&Config::config_path()?)
+    }
+
+    pub fn acquire_for(config_path: &Path) -> Result<Self> {
+        let lock_path = config_path.with_file_name(LOCK_FILE_NAME);
+        if let Some(parent) = lock_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|err| io_error(err.into(), &lock_path))
+            .with_context(|| format!("Failed to open {}", lock_path.display()))?;
+        file.lock()
+            .with_context(|| format!("Failed to lock {}", lock_path.display()))?;
+        Ok(Self {
+            _file: file,
+            config_path: config_path.to_path_buf(),
+        })
+    }
+}
+
+/// Turn a permission failure on `path` anywhere in `err` into
+/// [`ConfigError::PermissionDenied`], so it gets the permissions hint.
+fn io_error(err: anyhow::Error, path: &Path) -> anyhow::Error {
+    let denied = err.chain().any(|cause| {
+        cause
+            .downcast_ref::<std::io::Error>()
+            .is_some_and(|e| e.kind() == std::io::ErrorKind::PermissionDenied)
+    });
+    if denied {
+        ConfigError::PermissionDenied(path.display().to_string()).into()
+    } else {
+        err
+    }
+}
+
+/// Replace `path` with `content` via a temp file and rename, so readers never
+/// see a partial file. New files get `mode` on Unix.
+pub fn write_atomic(path: &Path, content: &[u8], mode: u32) -> Result<()> {
+    let dir = path
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid path {}", path.display()))?;
+    fs::create_dir_all(dir)?;
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow!("Invalid path {}", path.display()))?;
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let tmp = dir.join(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(mode);
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+    let result = (|| -> Result<()> {
+        let mut file = options.open(&tmp)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result.with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// A named account on a Hooklistener instance.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ws_url: Option<String>,
+    // Tokens are kept in the credentials file; older configs stored them here
+    #[serde(default, skip_serializing)]
+    pub api_token: Option<String>,
+    #[serde(default, skip_serializing)]
     pub access_token: Option<String>,
+    #[serde(default, skip_serializing)]
     pub token_expires_at: Option<DateTime<Utc>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing)]
     pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub refresh_token_expires_at: Option<DateTime<Utc>>,
     #[serde(default)]
+    pub selected_organization_id: Option<String>,
+}
+
+/// Which stored credentials a profile would use. Unlike
+/// [`Config::credential_source`], this ignores `HOOKLISTENER_TOKEN`.
+fn stored_credential_source(api_token: bool, session: bool) -> Option<CredentialSource> {
+    if api_token {
+        Some(CredentialSource::ApiToken)
+    } else if session {
+        Some(CredentialSource::Session)
+    } else {
+        None
+    }
+}
+
+impl Profile {
+    /// Which stored credentials this profile would use, if any.
+    pub fn credential_source(&self) -> Option<CredentialSource> {
+        stored_credential_source(
+            self.api_token.is_some(),
+            self.access_token.is_some() || self.refresh_token.is_some(),
+        )
+    }
+
+    fn credentials(&self) -> Credentials {
+        Credentials {
+            api_token: self.api_token.clone(),
+            access_token: self.access_token.clone(),
+            token_expires_at: self.token_expires_at,
+            refresh_token: self.refresh_token.clone(),
+            refresh_token_expires_at: self.refresh_token_expires_at,
+        }
+    }
+
+    fn set_credentials(&mut self, credentials: Credentials) {
+        self.api_token = credentials.api_token;
+        self.access_token = credentials.access_token;
+        self.token_expires_at = credentials.token_expires_at;
+        self.refresh_token = credentials.refresh_token;
+        self.refresh_token_expires_at = credentials.refresh_token_expires_at;
+    }
+}
+
+/// Outbound network settings, shared by all profiles. The standard proxy
+/// environment variables take precedence over `proxy` and `no_proxy`.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Proxy URL for API and WebSocket traffic, e.g. `http://proxy.corp:8080`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// Comma-separated hosts that bypass the proxy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+    /// Extra PEM CA bundle to trust, e.g. a corporate root CA
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_timeout_secs: Option<u64>,
+}
+
+impl NetworkConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// CLI configuration. The token, org and URL fields belong to the loaded
+/// profile; on disk they hold the `default` profile and the others live
+/// under `profiles`. Tokens are saved to the credentials file instead.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default, skip_serializing)]
+    pub access_token: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub token_expires_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing)]
+    pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing)]
     pub refresh_token_expires_at: Option<DateTime<Utc>>,
     pub selected_organization_id: Option<String>,
+    /// Long-lived API token from `login --with-token`; never refreshed
+    #[serde(default, skip_serializing)]
+    pub api_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ws_url: Option<String>,
     #[serde(default)]
     pub last_update_check: Option<DateTime<Utc>>,
     #[serde(default)]
     pub latest_known_version: Option<String>,
+    /// Profile used when `--profile` is not given; `None` means `default`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default, skip_serializing_if = "NetworkConfig::is_default")]
+    pub network: NetworkConfig,
+    /// Non-default profile whose fields were swapped into the top level on load
+    #[serde(skip)]
+    pub(crate) loaded_profile: Option<String>,
+    /// Whether the credentials file is passphrase-encrypted
+    #[serde(skip)]
+    pub(crate) encrypt_credentials: bool,
 }
 
 impl Config {
+    /// Load the config for the selected profile.
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
         Self::load_from(&config_path)
     }
 
     pub fn load_from(path: &Path) -> Result<Self> {
-        if path.exists() {
-            let content = fs::read_to_string(path)?;
-            let config: Config = serde_json::from_str(&content)?;
-            Ok(config)
+        Self::load_profile_from(path, SELECTED_PROFILE.get().map(String::as_str), None)
+    }
+
+    /// Load the config for the selected profile while holding `lock`, to
+    /// read-modify-write it without racing other processes.
+    pub fn load_locked(lock: &ConfigLock) -> Result<Self> {
+        Self::load_profile_from(
+            &lock.config_path,
+            SELECTED_PROFILE.get().map(String::as_str),
+            Some(lock),
+        )
+    }
+
+    /// Load the whole file without switching profiles, for managing profiles.
+    pub fn load_all() -> Result<Self> {
+        Self::read_from(&Self::config_path()?, None)
+    }
+
+    fn load_profile_from(
+        path: &Path,
+        profile: Option<&str>,
+        lock: Option<&ConfigLock>,
+    ) -> Result<Self> {
+        let mut config = Self::read_from(path, lock)?;
+        let name = profile
+            .map(str::to_string)
+            .or_else(|| config.active_profile.clone());
+        if let Some(name) = name.filter(|name| name != DEFAULT_PROFILE) {
+            let profile = config.profiles.remove(&name).ok_or_else(|| {
+                anyhow!(
+                    "Profile '{}' not found. Create it with `hooklistener profile add {}`.",
+                    name,
+                    name
+                )
+            })?;
+            let default = config.replace_profile(profile);
+            config.profiles.insert(DEFAULT_PROFILE.to_string(), default);
+            config.loaded_profile = Some(name);
+        }
+        Ok(config)
+    }
+
+    fn read_from(path: &Path, lock: Option<&ConfigLock>) -> Result<Self> {
+        let mut config = if path.exists() {
+            let content = fs::read_to_string(path).map_err(|err| io_error(err.into(), path))?;
+            serde_json::from_str(&content)
+                .map_err(|err| ConfigError::ParseError(format!("{}: {}", path.display(), err)))?
         } else {
-            Ok(Config::default())
+            Config::default()
+        };
+
+        // Tokens in config.json predate the credentials file; move them over
+        let legacy = !config.credential_map().is_empty();
+
+        let credentials_path = credentials::credentials_path(path);
+        if let Some(stored) = credentials::load(&credentials_path, credentials::passphrase)? {
+            config.encrypt_credentials = stored.encrypted;
+            config.apply_credentials(stored.profiles);
+        }
+        if let Some(problem) = credentials::permission_problem(&credentials_path)
+            && !PERMISSION_WARNING_SHOWN.swap(true, Ordering::Relaxed)
+        {
+            eprintln!("⚠️  Warning: {}", problem);
+        }
+
+        if legacy {
+            match lock {
+                Some(_) => config.write_to(path)?,
+                None => config.save_to(path)?,
+            }
         }
+        Ok(config)
     }
 
     pub fn save(&self) -> Result<()> {
@@ -41,23 +415,193 @@ impl Config {
     }
 
     pub fn save_to(&self, path: &Path) -> Result<()> {
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
+        let _lock = ConfigLock::acquire_for(path)?;
+        self.write_to(path)
+    }
+
+    /// Save while holding `lock`, e.g. after [`load_locked`](Self::load_locked).
+    pub fn save_locked(&self, lock: &ConfigLock) -> Result<()> {
+        self.write_to(&lock.config_path)
+    }
+
+    /// Write the credentials file and then the config, each atomically.
+    /// The caller must hold the config lock.
+    fn write_to(&self, path: &Path) -> Result<()> {
+        self.write_files(path).map_err(|err| io_error(err, path))
+    }
+
+    fn write_files(&self, path: &Path) -> Result<()> {
+        let file = self.to_file();
+        let passphrase = if file.encrypt_credentials {
+            Some(credentials::passphrase()?)
+        } else {
+            None
+        };
+        credentials::save(
+            &credentials::credentials_path(path),
+            &file.credential_map(),
+            passphrase.as_ref(),
+        )?;
+
+        let content = serde_json::to_string_pretty(&file)?;
+        write_atomic(path, content.as_bytes(), 0o644)
+    }
+
+    /// Tokens of every profile. Call on the file layout (`default` at the top level).
+    fn credential_map(&self) -> CredentialMap {
+        let default = Credentials {
+            api_token: self.api_token.clone(),
+            access_token: self.access_token.clone(),
+            token_expires_at: self.token_expires_at,
+            refresh_token: self.refresh_token.clone(),
+            refresh_token_expires_at: self.refresh_token_expires_at,
+        };
+        std::iter::once((DEFAULT_PROFILE.to_string(), default))
+            .chain(
+                self.profiles
+                    .iter()
+                    .map(|(name, profile)| (name.clone(), profile.credentials())),
+            )
+            .filter(|(_, credentials)| !credentials.is_empty())
+            .collect()
+    }
+
+    fn apply_credentials(&mut self, map: CredentialMap) {
+        for (name, credentials) in map {
+            if name == DEFAULT_PROFILE {
+                self.api_token = credentials.api_token;
+                self.access_token = credentials.access_token;
+                self.token_expires_at = credentials.token_expires_at;
+                self.refresh_token = credentials.refresh_token;
+                self.refresh_token_expires_at = credentials.refresh_token_expires_at;
+            } else if let Some(profile) = self.profiles.get_mut(&name) {
+                profile.set_credentials(credentials);
+            }
+        }
+    }
+
+    pub fn credentials_encrypted(&self) -> bool {
+        self.encrypt_credentials
+    }
+
+    /// Encrypt the credentials file with `passphrase` on the next save, or
+    /// store it in plain JSON again with `None`.
+    pub fn set_credentials_passphrase(&mut self, passphrase: Option<Passphrase>) {
+        self.encrypt_credentials = passphrase.is_some();
+        if let Some(passphrase) = passphrase {
+            credentials::set_passphrase(passphrase);
+        }
+    }
+
+    /// Undo the profile swap done on load so `default` is back at the top level.
+    fn to_file(&self) -> Self {
+        let mut file = self.clone();
+        if let Some(name) = file.loaded_profile.take() {
+            let default = file.profiles.remove(DEFAULT_PROFILE).unwrap_or_default();
+            let profile = file.replace_profile(default);
+            file.profiles.insert(name, profile);
+        }
+        file
+    }
+
+    /// Put `profile` in the top-level fields, returning what was there.
+    fn replace_profile(&mut self, profile: Profile) -> Profile {
+        Profile {
+            api_url: std::mem::replace(&mut self.api_url, profile.api_url),
+            ws_url: std::mem::replace(&mut self.ws_url, profile.ws_url),
+            api_token: std::mem::replace(&mut self.api_token, profile.api_token),
+            access_token: std::mem::replace(&mut self.access_token, profile.access_token),
+            token_expires_at: std::mem::replace(
+                &mut self.token_expires_at,
+                profile.token_expires_at,
+            ),
+            refresh_token: std::mem::replace(&mut self.refresh_token, profile.refresh_token),
+            refresh_token_expires_at: std::mem::replace(
+                &mut self.refresh_token_expires_at,
+                profile.refresh_token_expires_at,
+            ),
+            selected_organization_id: std::mem::replace(
+                &mut self.selected_organization_id,
+                profile.selected_organization_id,
+            ),
+        }
+    }
+
+    /// Name of the profile this config was loaded for.
+    pub fn profile_name(&self) -> &str {
+        self.loaded_profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Every profile by name, `default` first. Call on a config from `load_all`.
+    pub fn list_profiles(&self) -> Vec<(String, Profile)> {
+        let default = Profile {
+            api_url: self.api_url.clone(),
+            ws_url: self.ws_url.clone(),
+            api_token: self.api_token.clone(),
+            access_token: self.access_token.clone(),
+            token_expires_at: self.token_expires_at,
+            refresh_token: self.refresh_token.clone(),
+            refresh_token_expires_at: self.refresh_token_expires_at,
+            selected_organization_id: self.selected_organization_id.clone(),
+        };
+        std::iter::once((DEFAULT_PROFILE.to_string(), default))
+            .chain(
+                self.profiles
+                    .iter()
+                    .map(|(name, profile)| (name.clone(), profile.clone())),
+            )
+            .collect()
+    }
+
+    pub fn active_profile_name(&self) -> &str {
+        self.active_profile.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    fn has_profile(&self, name: &str) -> bool {
+        name == DEFAULT_PROFILE || self.profiles.contains_key(name)
+    }
+
+    pub fn add_profile(&mut self, name: &str, profile: Profile) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(anyhow!("Profile name must not be empty"));
         }
+        if self.has_profile(name) {
+            return Err(anyhow!("Profile '{}' already exists", name));
+        }
+        self.profiles.insert(name.to_string(), profile);
+        Ok(())
+    }
 
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(path, content)?;
+    pub fn use_profile(&mut self, name: &str) -> Result<()> {
+        if !self.has_profile(name) {
+            return Err(anyhow!("Profile '{}' not found", name));
+        }
+        self.active_profile = (name != DEFAULT_PROFILE).then(|| name.to_string());
+        Ok(())
+    }
 
+    /// Remove a profile; the active profile falls back to `default`.
+    pub fn remove_profile(&mut self, name: &str) -> Result<()> {
+        if name == DEFAULT_PROFILE {
+            return Err(anyhow!("The default profile cannot be removed"));
+        }
+        if self.profiles.remove(name).is_none() {
+            return Err(anyhow!("Profile '{}' not found", name));
+        }
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
         Ok(())
     }
 
     pub fn config_path() -> Result<PathBuf> {
-        let home =
-            dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Could not find config directory"))?;
+        let home = dirs::config_dir().ok_or(ConfigError::NoConfigDir)?;
 
         Ok(home.join("hooklistener").join("config.json"))
     }
 
+    /// Store a login session, replacing any saved API token so the session is
+    /// what API calls use.
     pub fn set_tokens(
         &mut self,
         access_token: String,
@@ -65,6 +609,7 @@ impl Config {
         refresh_token: Option<String>,
         refresh_expires_at: Option<DateTime<Utc>>,
     ) {
+        self.api_token = None;
         self.access_token = Some(access_token);
         self.token_expires_at = Some(expires_at);
         self.refresh_token = refresh_token;
@@ -87,7 +632,41 @@ impl Config {
         }
     }
 
+    /// Credential that API calls use: `HOOKLISTENER_TOKEN`, then a saved API
+    /// token, then the login session (which may need refreshing).
+    pub fn credential_source(&self) -> Option<CredentialSource> {
+        self.credential_source_with(env_token().is_some())
+    }
+
+    fn credential_source_with(&self, env_token: bool) -> Option<CredentialSource> {
+        if env_token {
+            Some(CredentialSource::Environment)
+        } else {
+            stored_credential_source(
+                self.api_token.is_some(),
+                self.access_token.is_some() || self.refresh_token.is_some(),
+            )
+        }
+    }
+
+    /// The token API calls would send, from the same source as
+    /// [`credential_source`](Self::credential_source).
+    pub fn active_token(&self) -> Option<String> {
+        match self.credential_source()? {
+            CredentialSource::Environment => env_token(),
+            CredentialSource::ApiToken => self.api_token.clone(),
+            CredentialSource::Session => self.access_token.clone(),
+        }
+    }
+
+    /// Replace any login session with a long-lived API token.
+    pub fn set_api_token(&mut self, token: String) {
+        self.clear_token();
+        self.api_token = Some(token);
+    }
+
     pub fn clear_token(&mut self) {
+        self.api_token = None;
         self.access_token = None;
         self.token_expires_at = None;
         self.refresh_token = None;
@@ -115,6 +694,26 @@ mod tests {
         dir.path().join("config.json")
     }
 
+    #[test]
+    fn test_profile_ws_url_derives_from_api_url() {
+        assert_eq!(
+            profile_ws_url_or_default(Some("https://hooks.staging.test/"), None),
+            "wss://hooks.staging.test"
+        );
+        assert_eq!(
+            profile_ws_url_or_default(Some("http://localhost:4000"), None),
+            "ws://localhost:4000"
+        );
+        assert_eq!(
+            profile_ws_url_or_default(
+                Some("https://hooks.staging.test"),
+                Some("wss://ws.staging.test")
+            ),
+            "wss://ws.staging.test"
+        );
+        assert_eq!(profile_ws_url_or_default(None, None), DEFAULT_WS_URL);
+    }
+
     #[test]
     fn test_no_token_is_invalid() {
         let config = Config::default();
@@ -153,6 +752,7 @@ mod tests {
     #[test]
     fn test_set_tokens() {
         let mut config = Config::default();
+        config.set_api_token("hl_old_api_token".to_string());
         let expires = Utc::now() + Duration::hours(1);
         let refresh_expires = Utc::now() + Duration::days(30);
         config.set_tokens(
@@ -165,6 +765,11 @@ mod tests {
         assert_eq!(config.token_expires_at, Some(expires));
         assert_eq!(config.refresh_token.as_deref(), Some("my_refresh"));
         assert_eq!(config.refresh_token_expires_at, Some(refresh_expires));
+        assert_eq!(config.api_token, None);
+        assert_eq!(
+            config.credential_source_with(false),
+            Some(CredentialSource::Session)
+        );
     }
 
     #[test]
@@ -206,6 +811,57 @@ mod tests {
         assert_eq!(config.selected_organization_id.as_deref(), Some("org-42"));
     }
 
+    #[test]
+    fn test_credential_source_precedence() {
+        let mut config = Config {
+            access_token: Some("session".to_string()),
+            token_expires_at: Some(Utc::now() + Duration::hours(1)),
+            refresh_token: Some("ref".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(
+            config.credential_source_with(false),
+            Some(CredentialSource::Session)
+        );
+
+        config.set_api_token("hl_api_token".to_string());
+        assert!(config.access_token.is_none());
+        assert!(config.refresh_token.is_none());
+        assert_eq!(
+            config.credential_source_with(false),
+            Some(CredentialSource::ApiToken)
+        );
+        assert_eq!(
+            config.credential_source_with(true),
+            Some(CredentialSource::Environment)
+        );
+
+        config.clear_token();
+        assert_eq!(config.credential_source_with(false), None);
+    }
+
+    #[test]
+    fn test_profile_credential_source_counts_every_stored_token() {
+        let api_token = Profile {
+            api_token: Some("hl_api_token".to_string()),
+            ..Profile::default()
+        };
+        assert_eq!(
+            api_token.credential_source(),
+            Some(CredentialSource::ApiToken)
+        );
+
+        let refresh_only = Profile {
+            refresh_token: Some("ref".to_string()),
+            ..Profile::default()
+        };
+        assert_eq!(
+            refresh_only.credential_source(),
+            Some(CredentialSource::Session)
+        );
+        assert_eq!(Profile::default().credential_source(), None);
+    }
+
     #[test]
     fn test_save_load_roundtrip() {
         let dir = TempDir::new().unwrap();
@@ -237,6 +893,176 @@ mod tests {
         assert!(config.selected_organization_id.is_none());
     }
 
+    #[test]
+    fn test_load_named_profile_and_save_back() {
+        let dir = TempDir::new().unwrap();
+        let path = config_path_in(&dir);
+        fs::write(
+            &path,
+            r#"{
+                "access_token": "prod_token",
+                "token_expires_at": null,
+                "selected_organization_id": "org-prod",
+                "active_profile": "staging",
+                "profiles": {
+                    "staging": {
+                        "api_url": "https://hooks.staging.test",
+                        "ws_url": "wss://ws.staging.test",
+                        "access_token": "staging_token"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mut config = Config::load_profile_from(&path, None, None).unwrap();
+        assert_eq!(config.profile_name(), "staging");
+        assert_eq!(config.access_token.as_deref(), Some("staging_token"));
+        assert_eq!(
+            config.api_url.as_deref(),
+            Some("https://hooks.staging.test")
+        );
+        assert!(config.selected_organization_id.is_none());
+
+        config.selected_organization_id = Some("org-staging".to_string());
+        config.save_to(&path).unwrap();
+
+        let all = Config::read_from(&path, None).unwrap();
+        assert_eq!(all.access_token.as_deref(), Some("prod_token"));
+        assert_eq!(all.selected_organization_id.as_deref(), Some("org-prod"));
+        assert!(all.api_url.is_none());
+        assert_eq!(
+            all.profiles["staging"].selected_organization_id.as_deref(),
+            Some("org-staging")
+        );
+        assert!(!all.profiles.contains_key(DEFAULT_PROFILE));
+
+        let prod = Config::load_profile_from(&path, Some(DEFAULT_PROFILE), None).unwrap();
+        assert_eq!(prod.profile_name(), DEFAULT_PROFILE);
+        assert_eq!(prod.access_token.as_deref(), Some("prod_token"));
+
+        let err = Config::load_profile_from(&path, Some("missing"), None).unwrap_err();
+        assert!(err.to_string().contains("Profile 'missing' not found"));
+    }
+
+    #[test]
+    fn test_manage_profiles() {
+        let mut config = Config {
+            access_token: Some("prod_token".to_string()),
+            ..Config::default()
+        };
+        config
+            .add_profile(
+                "staging",
+                Profile {
+                    api_url: Some("https://hooks.staging.test".to_string()),
+                    ..Profile::default()
+                },
+            )
+            .unwrap();
+        assert!(config.add_profile("staging", Profile::default()).is_err());
+        assert!(
+            config
+                .add_profile(DEFAULT_PROFILE, Profile::default())
+                .is_err()
+        );
+
+        let names: Vec<String> = config
+            .list_profiles()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["default", "staging"]);
+        assert_eq!(
+            config.list_profiles()[0].1.access_token.as_deref(),
+            Some("prod_token")
+        );
+
+        config.use_profile("staging").unwrap();
+        assert_eq!(config.active_profile_name(), "staging");
+        assert!(config.use_profile("missing").is_err());
+
+        assert!(config.remove_profile(DEFAULT_PROFILE).is_err());
+        config.remove_profile("staging").unwrap();
+        assert_eq!(config.active_profile_name(), DEFAULT_PROFILE);
+        assert!(config.remove_profile("staging").is_err());
+    }
+
+    #[test]
+    fn test_load_moves_tokens_to_credentials_file() {
+        let dir = TempDir::new().unwrap();
+        let path = config_path_in(&dir);
+        fs::write(
+            &path,
+            r#"{
+                "access_token": "legacy_token",
+                "token_expires_at": null,
+                "refresh_token": "legacy_refresh",
+                "selected_organization_id": "org-1"
+            }"#,
+        )
+        .unwrap();
+
+        let config = Config::load_from(&path).unwrap();
+        assert_eq!(config.access_token.as_deref(), Some("legacy_token"));
+        assert_eq!(config.refresh_token.as_deref(), Some("legacy_refresh"));
+
+        let on_disk = fs::read_to_string(&path).unwrap();
+        assert!(!on_disk.contains("legacy_token"));
+        assert!(on_disk.contains("org-1"));
+
+        let credentials_path = credentials::credentials_path(&path);
+        let stored = fs::read_to_string(&credentials_path).unwrap();
+        assert!(stored.contains("legacy_token"));
+        assert!(credentials::permission_problem(&credentials_path).is_none());
+
+        let reloaded = Config::load_from(&path).unwrap();
+        assert_eq!(reloaded.access_token.as_deref(), Some("legacy_token"));
+    }
+
+    #[test]
+    fn test_config_lock_is_exclusive() {
+        let dir = TempDir::new().unwrap();
+        let path = config_path_in(&dir);
+
+        let lock = ConfigLock::acquire_for(&path).unwrap();
+        let other = fs::File::open(dir.path().join(LOCK_FILE_NAME)).unwrap();
+        assert!(other.try_lock().is_err());
+
+        drop(lock);
+        assert!(other.try_lock().is_ok());
+    }
+
+    #[test]
+    fn test_concurrent_saves_leave_valid_files() {
+        let dir = TempDir::new().unwrap();
+        let path = config_path_in(&dir);
+
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let mut config = Config::default();
+                    config.set_tokens(format!("token_{}", i), Utc::now(), None, None);
+                    config.save_to(&path).unwrap();
+                    Config::read_from(&path, None).unwrap();
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let loaded = Config::load_from(&path).unwrap();
+        assert!(loaded.access_token.unwrap().starts_with("token_"));
+        let mut names: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["config.json", "config.lock", "credentials.json"]);
+    }
+
     #[test]
     fn test_load_corrupted_json_returns_error() {
         let dir = TempDir::new().unwrap();
@@ -244,7 +1070,10 @@ mod tests {
 
         fs::write(&path, "not valid json {{{").unwrap();
 
-        let result = Config::load_from(&path);
-        assert!(result.is_err());
+        let err = Config::load_from(&path).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ConfigError>(),
+            Some(ConfigError::ParseError(_))
+        ));
     }
 }
@@ -0,0 +1,305 @@
+use anyhow::{Context, Result, anyhow};
+use argon2::Argon2;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use chacha20poly1305::{
+    ChaCha20Poly1305, Key, KeyInit, Nonce,
+    aead::{Aead, Payload},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Tokens live here, next to `config.json`, readable only by the owner.
+pub const CREDENTIALS_FILE_NAME: &str = "credentials.json";
+/// Passphrase for an encrypted credentials file, used instead of prompting.
+pub const PASSPHRASE_ENV_VAR: &str = "HOOKLISTENER_PASSPHRASE";
+
+const KDF: &str = "argon2id";
+
+/// Passphrase entered once per process and reused for every load and save.
+static PASSPHRASE: OnceLock<Passphrase> = OnceLock::new();
+
+/// Tokens for one profile.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Credentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_expires_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token_expires_at: Option<DateTime<Utc>>,
+}
+
+impl Credentials {
+    pub fn is_empty(&self) -> bool {
+        *self == Credentials::default()
+    }
+}
+
+/// Credentials for every profile, keyed by profile name.
+pub type CredentialMap = BTreeMap<String, Credentials>;
+
+#[derive(Clone)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Self {
+        Self(passphrase)
+    }
+}
+
+impl std::fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Passphrase(..)")
+    }
+}
+
+/// On-disk layout: either plain `profiles` or an `encrypted` blob holding them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StoreFile {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    profiles: CredentialMap,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encrypted: Option<Sealed>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Sealed {
+    kdf: String,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Credentials read from disk, and whether they were encrypted.
+#[derive(Debug, Default)]
+pub struct LoadedCredentials {
+    pub profiles: CredentialMap,
+    pub encrypted: bool,
+}
+
+/// The credentials file that sits next to `config_path`.
+pub fn credentials_path(config_path: &Path) -> PathBuf {
+    config_path.with_file_name(CREDENTIALS_FILE_NAME)
+}
+
+/// Read the credentials file, asking `passphrase` for the key only if it is encrypted.
+pub fn load(
+    path: &Path,
+    passphrase: impl FnOnce() -> Result<Passphrase>,
+) -> Result<Option<LoadedCredentials>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read credentials file {}", path.display()))?;
+    let file: StoreFile = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse credentials file {}", path.display()))?;
+
+    match file.encrypted {
+        Some(sealed) => Ok(Some(LoadedCredentials {
+            profiles: open(&sealed, &passphrase()?)?,
+            encrypted: true,
+        })),
+        None => Ok(Some(LoadedCredentials {
+            profiles: file.profiles,
+            encrypted: false,
+        })),
+    }
+}
+
+/// Write the credentials file atomically with owner-only permissions,
+/// encrypting it when a passphrase is given.
+pub fn save(path: &Path, profiles: &CredentialMap, passphrase: Option<&Passphrase>) -> Result<()> {
+    let file = match passphrase {
+        Some(passphrase) => StoreFile {
+            profiles: CredentialMap::new(),
+            encrypted: Some(seal(profiles, passphrase)?),
+        },
+        None => StoreFile {
+            profiles: profiles.clone(),
+            encrypted: None,
+        },
+    };
+    crate::config::write_atomic(path, serde_json::to_string_pretty(&file)?.as_bytes(), 0o600)
+}
+
+/// Describe why `path` is too open, if it is readable by the group or others.
+pub fn permission_problem(path: &Path) -> Option<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path).ok()?.permissions().mode() & 0o777;
+        (mode & 0o077 != 0).then(|| {
+            format!(
+                "{} is accessible by other users (mode {:o}). Run `chmod 600 {}`.",
+                path.display(),
+                mode,
+                path.display()
+            )
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// The passphrase for this process: `HOOKLISTENER_PASSPHRASE`, else a prompt.
+pub fn passphrase() -> Result<Passphrase> {
+    if let Some(passphrase) = PASSPHRASE.get() {
+        return Ok(passphrase.clone());
+    }
+    let passphrase = match std::env::var(PASSPHRASE_ENV_VAR) {
+        Ok(value) => value,
+        Err(_) if std::io::stdin().is_terminal() => {
+            rpassword::prompt_password("Credentials passphrase: ")?
+        }
+        Err(_) => {
+            return Err(anyhow!(
+                "Credentials are encrypted. Set {} or run the command in a terminal.",
+                PASSPHRASE_ENV_VAR
+            ));
+        }
+    };
+    Ok(PASSPHRASE.get_or_init(|| Passphrase(passphrase)).clone())
+}
+
+/// Use `passphrase` for the rest of the process, e.g. after choosing a new one.
+pub fn set_passphrase(passphrase: Passphrase) {
+    let _ = PASSPHRASE.set(passphrase);
+}
+
+fn derive_key(passphrase: &Passphrase, salt: &[u8]) -> Result<Key> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.0.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Failed to derive key: {}", e))?;
+    Ok(Key::from(key))
+}
+
+fn seal(profiles: &CredentialMap, passphrase: &Passphrase) -> Result<Sealed> {
+    let salt: [u8; 16] = rand::random();
+    let nonce: [u8; 12] = rand::random();
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+    let plaintext = serde_json::to_vec(profiles)?;
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &plaintext,
+                aad: KDF.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow!("Failed to encrypt credentials"))?;
+    Ok(Sealed {
+        kdf: KDF.to_string(),
+        salt: STANDARD.encode(salt),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    })
+}
+
+fn open(sealed: &Sealed, passphrase: &Passphrase) -> Result<CredentialMap> {
+    if sealed.kdf != KDF {
+        return Err(anyhow!(
+            "Unsupported credentials encryption '{}'",
+            sealed.kdf
+        ));
+    }
+    let salt = STANDARD.decode(&sealed.salt)?;
+    let nonce = STANDARD.decode(&sealed.nonce)?;
+    let ciphertext = STANDARD.decode(&sealed.ciphertext)?;
+    if nonce.len() != 12 {
+        return Err(anyhow!("Corrupted credentials file: bad nonce"));
+    }
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+    let plaintext = cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &ciphertext,
+                aad: KDF.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow!("Wrong passphrase for the credentials file"))?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample() -> CredentialMap {
+        let mut profiles = CredentialMap::new();
+        profiles.insert(
+            "default".to_string(),
+            Credentials {
+                access_token: Some("secret_token".to_string()),
+                refresh_token: Some("secret_refresh".to_string()),
+                ..Credentials::default()
+            },
+        );
+        profiles
+    }
+
+    fn no_passphrase() -> Result<Passphrase> {
+        panic!("plain credentials must not ask for a passphrase")
+    }
+
+    #[test]
+    fn test_plain_roundtrip_is_owner_only() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CREDENTIALS_FILE_NAME);
+
+        save(&path, &sample(), None).unwrap();
+        let loaded = load(&path, no_passphrase).unwrap().unwrap();
+        assert_eq!(loaded.profiles, sample());
+        assert!(!loaded.encrypted);
+        assert!(permission_problem(&path).is_none());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+            assert!(permission_problem(&path).unwrap().contains("mode 644"));
+        }
+    }
+
+    #[test]
+    fn test_encrypted_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CREDENTIALS_FILE_NAME);
+        let passphrase = Passphrase::new("correct horse".to_string());
+
+        save(&path, &sample(), Some(&passphrase)).unwrap();
+        let raw = fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("secret_token"));
+
+        let loaded = load(&path, || Ok(passphrase.clone())).unwrap().unwrap();
+        assert_eq!(loaded.profiles, sample());
+        assert!(loaded.encrypted);
+
+        let err = load(&path, || Ok(Passphrase::new("wrong".to_string()))).unwrap_err();
+        assert!(err.to_string().contains("Wrong passphrase"));
+    }
+
+    #[test]
+    fn test_missing_file_loads_nothing() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(CREDENTIALS_FILE_NAME);
+        assert!(load(&path, no_passphrase).unwrap().is_none());
+    }
+}
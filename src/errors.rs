@@ -1,6 +1,85 @@
+use serde::Serialize;
 use thiserror::Error;
 
-#[allow(dead_code)]
+/// Class of a failed command. Each class has its own process exit code, which
+/// scripts rely on, so existing codes must never change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    General,
+    Validation,
+    Auth,
+    NotFound,
+    Network,
+    RateLimited,
+    TargetUnreachable,
+}
+
+impl ErrorKind {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ErrorKind::General => 1,
+            // Same as clap's exit code for invalid arguments
+            ErrorKind::Validation => 2,
+            ErrorKind::Auth => 3,
+            ErrorKind::NotFound => 4,
+            ErrorKind::Network => 5,
+            ErrorKind::RateLimited => 6,
+            ErrorKind::TargetUnreachable => 7,
+        }
+    }
+
+    /// Classify `err` by the first typed error in its chain.
+    pub fn of(err: &anyhow::Error) -> Self {
+        err.chain()
+            .find_map(|cause| {
+                if let Some(e) = cause.downcast_ref::<CliError>() {
+                    return Some(e.kind());
+                }
+                if let Some(e) = cause.downcast_ref::<ApiError>() {
+                    return Some(e.kind());
+                }
+                if let Some(e) = cause.downcast_ref::<TunnelError>() {
+                    return Some(e.kind());
+                }
+                if let Some(e) = cause.downcast_ref::<ConfigError>() {
+                    return Some(e.kind());
+                }
+                if let Some(e) = cause.downcast_ref::<UpdateError>() {
+                    return Some(e.kind());
+                }
+                None
+            })
+            .unwrap_or(ErrorKind::General)
+    }
+}
+
+/// Failures detected locally, before or without talking to the API.
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("{0}")]
+    NotAuthenticated(String),
+
+    #[error("{0}")]
+    InvalidInput(String),
+}
+
+impl CliError {
+    pub fn hint(&self) -> Option<&str> {
+        match self {
+            CliError::NotAuthenticated(_) => Some("Run `hooklistener login` to authenticate."),
+            CliError::InvalidInput(_) => None,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            CliError::NotAuthenticated(_) => ErrorKind::Auth,
+            CliError::InvalidInput(_) => ErrorKind::Validation,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ApiError {
     #[error("Unauthorized: your token is invalid or expired")]
@@ -24,6 +103,9 @@ pub enum ApiError {
     #[error("Failed to parse response: {0}")]
     ParseError(String),
 
+    #[error("{0}")]
+    InvalidRequest(String),
+
     #[error("{0}")]
     Other(String),
 }
@@ -42,13 +124,25 @@ impl ApiError {
             ApiError::RateLimited => Some("Wait a moment and try again."),
             ApiError::NetworkError(_) => Some("Check your internet connection and try again."),
             ApiError::ParseError(_) => None,
+            ApiError::InvalidRequest(_) => Some("Check the values passed to the command."),
             ApiError::Other(_) => None,
         }
     }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ApiError::Unauthorized | ApiError::Forbidden => ErrorKind::Auth,
+            ApiError::NotFound { .. } => ErrorKind::NotFound,
+            ApiError::ServerError { .. } | ApiError::NetworkError(_) => ErrorKind::Network,
+            ApiError::RateLimited => ErrorKind::RateLimited,
+            ApiError::InvalidRequest(_) => ErrorKind::Validation,
+            ApiError::ParseError(_) | ApiError::Other(_) => ErrorKind::General,
+        }
+    }
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum TunnelError {
     #[error("Authentication failed: token is invalid or expired")]
     AuthenticationFailed,
@@ -68,6 +162,10 @@ pub enum TunnelError {
     #[error("Connection timed out")]
     Timeout,
 
+    /// `target` is the scheme, host and port of the local target
+    #[error("Local target {target} is not reachable")]
+    TargetUnreachable { target: String },
+
     #[error("{0}")]
     Other(String),
 }
@@ -91,10 +189,25 @@ impl TunnelError {
                 "The channel could not be joined. Verify the endpoint exists and you have access.",
             ),
             TunnelError::Timeout => Some("The server did not respond in time. Try again shortly."),
+            TunnelError::TargetUnreachable { .. } => {
+                Some("Start your local server first, or check --target/--port.")
+            }
             TunnelError::Other(_) => None,
         }
     }
 
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            TunnelError::AuthenticationFailed => ErrorKind::Auth,
+            TunnelError::EndpointNotFound { .. } => ErrorKind::NotFound,
+            TunnelError::ConnectionRefused(_)
+            | TunnelError::WebSocketError(_)
+            | TunnelError::Timeout => ErrorKind::Network,
+            TunnelError::TargetUnreachable { .. } => ErrorKind::TargetUnreachable,
+            TunnelError::JoinFailed { .. } | TunnelError::Other(_) => ErrorKind::General,
+        }
+    }
+
     #[allow(dead_code)]
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -104,12 +217,12 @@ impl TunnelError {
             TunnelError::ConnectionRefused(_)
             | TunnelError::WebSocketError(_)
             | TunnelError::Timeout
+            | TunnelError::TargetUnreachable { .. }
             | TunnelError::Other(_) => true,
         }
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("Could not find config directory")]
@@ -134,6 +247,12 @@ impl ConfigError {
             }
         }
     }
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ConfigError::ParseError(_) => ErrorKind::Validation,
+            ConfigError::NoConfigDir | ConfigError::PermissionDenied(_) => ErrorKind::General,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -154,4 +273,52 @@ impl UpdateError {
             }
         }
     }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            UpdateError::CheckFailed(_) => ErrorKind::Network,
+            UpdateError::UpdateFailed(_) => ErrorKind::General,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_kind_follows_the_chain() {
+        let err = anyhow::Error::new(ApiError::NotFound {
+            resource: "Endpoint".to_string(),
+        })
+        .context("Could not load the endpoint");
+        assert_eq!(ErrorKind::of(&err), ErrorKind::NotFound);
+        assert_eq!(ErrorKind::of(&err).exit_code(), 4);
+
+        let err: anyhow::Error = CliError::NotAuthenticated("Not logged in".to_string()).into();
+        assert_eq!(ErrorKind::of(&err).exit_code(), 3);
+
+        let err = anyhow::Error::new(ConfigError::ParseError("bad".to_string()))
+            .context("Failed to load network settings");
+        assert_eq!(ErrorKind::of(&err), ErrorKind::Validation);
+
+        let err = anyhow::anyhow!("something else");
+        assert_eq!(ErrorKind::of(&err).exit_code(), 1);
+    }
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let kinds = [
+            ErrorKind::General,
+            ErrorKind::Validation,
+            ErrorKind::Auth,
+            ErrorKind::NotFound,
+            ErrorKind::Network,
+            ErrorKind::RateLimited,
+            ErrorKind::TargetUnreachable,
+        ];
+        let mut codes: Vec<_> = kinds.iter().map(|kind| kind.exit_code()).collect();
+        codes.dedup();
+        assert_eq!(codes, [1, 2, 3, 4, 5, 6, 7]);
+    }
 }
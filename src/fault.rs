@@ -0,0 +1,285 @@
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+use crate::models::Headers;
+use crate::rules::RequestMatcher;
+
+/// A fault injected into local forwarding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fault {
+    /// Delay the request before forwarding it
+    Latency(Duration),
+    /// Answer with this status instead of forwarding
+    Status(u16),
+    /// Fail as if the local target reset the connection
+    Reset,
+    /// Fail as if the local target never answered
+    Timeout,
+}
+
+impl Fault {
+    /// Short label for the TUI and logs.
+    pub fn describe(&self) -> String {
+        match self {
+            Fault::Latency(delay) => format!("+{}ms", delay.as_millis()),
+            Fault::Status(status) => format!("HTTP {}", status),
+            Fault::Reset => "reset".to_string(),
+            Fault::Timeout => "timeout".to_string(),
+        }
+    }
+}
+
+/// A fault applied to a percentage of requests, optionally scoped by method, path or header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaultRule {
+    pub fault: Fault,
+    /// Share of matching requests affected, from 0 to 100
+    pub percent: f64,
+    /// Requests the fault applies to; all of them by default
+    pub scope: RequestMatcher,
+}
+
+impl FaultRule {
+    /// Parse a `--fault` value.
+    ///
+    /// Syntax: `KIND[,percent=N][,method=METHOD][,path=GLOB][,header=NAME[:VALUE]]`
+    /// where KIND is `latency=DURATION`, `error[=STATUS]`, `reset` or `timeout`, for
+    /// example `latency=500ms,percent=20` or `error=503,path=/api/*`.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut parts = spec.split(',');
+        let kind = parts.next().unwrap_or_default().trim();
+        let (name, value) = match kind.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (kind, None),
+        };
+
+        let fault = match (name, value) {
+            ("latency", Some(value)) => Fault::Latency(
+                parse_duration(value)
+                    .ok_or_else(|| anyhow!("Invalid fault '{}': bad latency '{}'", spec, value))?,
+            ),
+            ("error", None) => Fault::Status(500),
+            ("error", Some(value)) => Fault::Status(
+                value
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|status| (100..=599).contains(status))
+                    .ok_or_else(|| anyhow!("Invalid fault '{}': bad status '{}'", spec, value))?,
+            ),
+            ("reset", None) => Fault::Reset,
+            ("timeout", None) => Fault::Timeout,
+            _ => {
+                return Err(anyhow!(
+                    "Invalid fault '{}': use latency=DURATION, error[=STATUS], reset or timeout",
+                    spec
+                ));
+            }
+        };
+
+        let mut rule = FaultRule {
+            fault,
+            percent: 100.0,
+            scope: RequestMatcher::default(),
+        };
+
+        for option in parts {
+            let option = option.trim();
+            if let Some(percent) = option.strip_prefix("percent=") {
+                rule.percent = percent
+                    .trim_end_matches('%')
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|p| (0.0..=100.0).contains(p))
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Invalid fault '{}': percent must be between 0 and 100",
+                            spec
+                        )
+                    })?;
+            } else if let Some(method) = option.strip_prefix("method=") {
+                rule.scope.method = Some(method.to_uppercase());
+            } else if let Some(path) = option.strip_prefix("path=") {
+                rule.scope.path = path.to_string();
+                if !rule.scope.has_valid_path() {
+                    return Err(anyhow!(
+                        "Invalid fault '{}': path must start with '/' or '*'",
+                        spec
+                    ));
+                }
+            } else if let Some(header) = option.strip_prefix("header=") {
+                rule.scope.header = Some(header.to_string());
+            } else if !option.is_empty() {
+                return Err(anyhow!(
+                    "Invalid fault '{}': unknown option '{}'",
+                    spec,
+                    option
+                ));
+            }
+        }
+
+        Ok(rule)
+    }
+}
+
+/// Parse `500ms`, `2s`, `1.5s` or a bare number of milliseconds.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (number, scale) = if let Some(ms) = value.strip_suffix("ms") {
+        (ms, 1.0)
+    } else if let Some(secs) = value.strip_suffix('s') {
+        (secs, 1000.0)
+    } else {
+        (value, 1.0)
+    };
+    let millis = number.trim().parse::<f64>().ok().filter(|n| *n >= 0.0)? * scale;
+    Some(Duration::from_millis(millis as u64))
+}
+
+/// Faults chosen for a single request.
+#[derive(Debug, Default, PartialEq)]
+pub struct FaultPlan {
+    /// Total latency to add before forwarding (or failing)
+    pub delay: Duration,
+    /// Failure replacing the forward, if any
+    pub failure: Option<Fault>,
+    /// Labels of every injected fault
+    pub labels: Vec<String>,
+}
+
+impl FaultPlan {
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+/// Ordered fault rules; each rule rolls independently and the first failure wins.
+#[derive(Debug, Clone, Default)]
+pub struct FaultInjector {
+    rules: Vec<FaultRule>,
+}
+
+impl FaultInjector {
+    pub fn new(rules: Vec<FaultRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Pick faults for a request using a random roll per rule.
+    pub fn plan(&self, method: &str, path: &str, headers: &Headers) -> FaultPlan {
+        if self.rules.is_empty() {
+            return FaultPlan::default();
+        }
+        self.plan_with(method, path, headers, || rand::random::<f64>() * 100.0)
+    }
+
+    /// Pick faults with the given roll source (values from 0 to 100).
+    fn plan_with(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &Headers,
+        mut roll: impl FnMut() -> f64,
+    ) -> FaultPlan {
+        let mut plan = FaultPlan::default();
+
+        for rule in self
+            .rules
+            .iter()
+            .filter(|rule| rule.scope.matches(method, path, headers))
+        {
+            if roll() >= rule.percent {
+                continue;
+            }
+            match rule.fault {
+                Fault::Latency(delay) => plan.delay += delay,
+                failure if plan.failure.is_none() => plan.failure = Some(failure),
+                _ => continue,
+            }
+            plan.labels.push(rule.fault.describe());
+        }
+
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_faults() {
+        let latency = FaultRule::parse("latency=1.5s,percent=25,path=/api/*").unwrap();
+        assert_eq!(latency.fault, Fault::Latency(Duration::from_millis(1500)));
+        assert_eq!(latency.percent, 25.0);
+        assert_eq!(latency.scope.path, "/api/*");
+
+        let scoped = FaultRule::parse("reset,method=post,header=x-source:stripe").unwrap();
+        assert_eq!(scoped.scope.method.as_deref(), Some("POST"));
+        assert_eq!(scoped.scope.header.as_deref(), Some("x-source:stripe"));
+        assert_eq!(scoped.scope.path, "*");
+
+        assert_eq!(
+            FaultRule::parse("latency=200").unwrap().fault,
+            Fault::Latency(Duration::from_millis(200))
+        );
+        assert_eq!(FaultRule::parse("error").unwrap().fault, Fault::Status(500));
+        assert_eq!(
+            FaultRule::parse("error=503,percent=10%").unwrap().fault,
+            Fault::Status(503)
+        );
+        assert_eq!(FaultRule::parse("reset").unwrap().fault, Fault::Reset);
+        assert_eq!(FaultRule::parse("timeout").unwrap().percent, 100.0);
+
+        assert!(FaultRule::parse("latency").is_err());
+        assert!(FaultRule::parse("error=99").is_err());
+        assert!(FaultRule::parse("reset,percent=150").is_err());
+        assert!(FaultRule::parse("reset,path=api").is_err());
+        assert!(FaultRule::parse("explode").is_err());
+    }
+
+    #[test]
+    fn test_plan_combines_latency_with_first_failure() {
+        let injector = FaultInjector::new(vec![
+            FaultRule::parse("latency=100ms").unwrap(),
+            FaultRule::parse("latency=50ms,path=/slow/*").unwrap(),
+            FaultRule::parse("error=503,percent=50").unwrap(),
+            FaultRule::parse("reset").unwrap(),
+        ]);
+
+        let headers = Headers::new();
+
+        let plan = injector.plan_with("POST", "/slow/1", &headers, || 10.0);
+        assert_eq!(plan.delay, Duration::from_millis(150));
+        assert_eq!(plan.failure, Some(Fault::Status(503)));
+        assert_eq!(plan.labels, vec!["+100ms", "+50ms", "HTTP 503"]);
+
+        // A roll above 50 skips the error rule, so the reset applies
+        let plan = injector.plan_with("POST", "/other", &headers, || 75.0);
+        assert_eq!(plan.delay, Duration::from_millis(100));
+        assert_eq!(plan.failure, Some(Fault::Reset));
+    }
+
+    #[test]
+    fn test_scope_limits_faults_to_matching_requests() {
+        let injector = FaultInjector::new(vec![
+            FaultRule::parse("error=503,method=post,header=x-source").unwrap(),
+        ]);
+        let stripe: Headers = [("X-Source", "stripe")].into_iter().collect();
+
+        let plan = injector.plan_with("POST", "/", &stripe, || 0.0);
+        assert_eq!(plan.failure, Some(Fault::Status(503)));
+        assert!(injector.plan_with("GET", "/", &stripe, || 0.0).is_empty());
+        assert!(
+            injector
+                .plan_with("POST", "/", &Headers::new(), || 0.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_empty_injector_plans_nothing() {
+        assert!(
+            FaultInjector::default()
+                .plan("GET", "/", &Headers::new())
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,604 @@
+use anyhow::{Result, anyhow};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::info;
+
+use crate::errors::TunnelError;
+use crate::mock::MockMode;
+use crate::project::RedactRules;
+use crate::tunnel::TunnelEvent;
+
+/// Prints tunnel events for `--no-tui` runs, as log lines or NDJSON.
+pub struct EventPrinter {
+    json: bool,
+    /// `METHOD /path` of requests still in progress, for text output
+    labels: HashMap<String, String>,
+}
+
+impl EventPrinter {
+    pub fn new(json: bool) -> Self {
+        Self {
+            json,
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Render an event as one output line, or `None` if it is not shown.
+    pub fn format(&mut self, event: &TunnelEvent) -> Option<String> {
+        let timestamp = chrono::Utc::now();
+        if self.json {
+            let mut value = event_json(event);
+            value["timestamp"] = json!(timestamp.to_rfc3339());
+            return Some(value.to_string());
+        }
+        let message = self.event_text(event)?;
+        Some(format!(
+            "{} {}",
+            timestamp.with_timezone(&chrono::Local).format("%H:%M:%S"),
+            message
+        ))
+    }
+
+    fn label(&self, request_id: &str) -> String {
+        self.labels
+            .get(request_id)
+            .cloned()
+            .unwrap_or_else(|| request_id.to_string())
+    }
+
+    fn finish(&mut self, request_id: &str) -> String {
+        self.labels
+            .remove(request_id)
+            .unwrap_or_else(|| request_id.to_string())
+    }
+
+    fn event_text(&mut self, event: &TunnelEvent) -> Option<String> {
+        Some(match event {
+            TunnelEvent::Connecting => "Connecting...".to_string(),
+            TunnelEvent::Connected => "Connected".to_string(),
+            TunnelEvent::TunnelEstablished {
+                subdomain,
+                is_static,
+                ..
+            } => format!(
+                "Tunnel established: https://{} ({})",
+                subdomain,
+                if *is_static { "static" } else { "ephemeral" }
+            ),
+            TunnelEvent::ConnectionError(error) => format!("Connection error: {}", error),
+            TunnelEvent::Disconnected => "Disconnected".to_string(),
+            TunnelEvent::RequestReceived {
+                request_id,
+                method,
+                path,
+                target,
+                ..
+            } => {
+                let label = format!("{} {}", method, path);
+                let line = format!("→ {} → {}", label, target);
+                self.labels.insert(request_id.clone(), label);
+                line
+            }
+            TunnelEvent::RequestForwarded {
+                request_id,
+                status,
+                duration_ms,
+                ..
+            } => format!(
+                "✓ {} {} ({}ms)",
+                self.finish(request_id),
+                status,
+                duration_ms
+            ),
+            TunnelEvent::RequestFailed {
+                request_id, error, ..
+            } => {
+                format!("✗ {} failed: {}", self.finish(request_id), error)
+            }
+            TunnelEvent::FaultInjected { request_id, faults } => {
+                format!("⚡ {} fault injected: {}", self.label(request_id), faults)
+            }
+            TunnelEvent::RequestMocked {
+                request_id,
+                status,
+                duration_ms,
+                ..
+            } => format!(
+                "◆ {} {} mocked ({}ms)",
+                self.finish(request_id),
+                status,
+                duration_ms
+            ),
+            TunnelEvent::ResponseStreamStarted {
+                request_id, status, ..
+            } => format!("⇣ {} {} streaming", self.label(request_id), status),
+            TunnelEvent::ResponseStreamEnded {
+                request_id,
+                duration_ms,
+                error,
+                ..
+            } => match error {
+                Some(error) => format!("✗ {} stream failed: {}", self.finish(request_id), error),
+                None => format!(
+                    "✓ {} stream ended ({}ms)",
+                    self.finish(request_id),
+                    duration_ms
+                ),
+            },
+            TunnelEvent::WebSocketOpened { request_id, .. } => {
+                format!("⇄ {} WebSocket opened", self.label(request_id))
+            }
+            TunnelEvent::WebSocketClosed {
+                request_id, code, ..
+            } => match code {
+                Some(code) => format!("⇄ {} WebSocket closed ({})", self.finish(request_id), code),
+                None => format!("⇄ {} WebSocket closed", self.finish(request_id)),
+            },
+            TunnelEvent::RequestIntercepted { request_id, .. } => {
+                format!("⏸ {} held", self.label(request_id))
+            }
+            TunnelEvent::WebhookReceived(request) => format!(
+                "→ {} {}",
+                request.method,
+                request.path.as_deref().unwrap_or(&request.url)
+            ),
+            TunnelEvent::ForwardSuccess => "✓ Forwarded".to_string(),
+            TunnelEvent::ForwardError {
+                unreachable_target: None,
+            } => "✗ Forward failed".to_string(),
+            TunnelEvent::ForwardError {
+                unreachable_target: Some(target),
+            } => format!("✗ Forward failed: {} is not reachable", target),
+            TunnelEvent::Reconnecting {
+                attempt,
+                max_attempts,
+                next_retry_in_secs,
+            } => format!(
+                "Reconnecting (attempt {}/{})... next retry in {}s",
+                attempt, max_attempts, next_retry_in_secs
+            ),
+            TunnelEvent::ReconnectFailed { reason, .. } => format!("Connection lost: {}", reason),
+            TunnelEvent::TokenRefreshed => "Access token refreshed".to_string(),
+            // Progress counters are only useful in the TUI and NDJSON output
+            TunnelEvent::BytesTransferred { .. }
+            | TunnelEvent::WebSocketFrames { .. }
+            | TunnelEvent::InFlight { .. } => return None,
+        })
+    }
+}
+
+/// The NDJSON object for an event, tagged with its snake_case name in `event`.
+fn event_json(event: &TunnelEvent) -> Value {
+    match event {
+        TunnelEvent::Connecting => json!({ "event": "connecting" }),
+        TunnelEvent::Connected => json!({ "event": "connected" }),
+        TunnelEvent::TunnelEstablished {
+            subdomain,
+            tunnel_id,
+            is_static,
+        } => json!({
+            "event": "tunnel_established",
+            "subdomain": subdomain,
+            "url": format!("https://{}", subdomain),
+            "tunnel_id": tunnel_id,
+            "is_static": is_static,
+        }),
+        TunnelEvent::ConnectionError(error) => {
+            json!({ "event": "connection_error", "error": error })
+        }
+        TunnelEvent::Disconnected => json!({ "event": "disconnected" }),
+        TunnelEvent::RequestReceived {
+            request_id,
+            method,
+            path,
+            headers,
+            body,
+            query_string,
+            target,
+            route,
+        } => json!({
+            "event": "request_received",
+            "request_id": request_id,
+            "method": method,
+            "path": path,
+            "query_string": query_string,
+            "headers": headers,
+            "body": body,
+            "target": target,
+            "route": route,
+        }),
+        TunnelEvent::RequestForwarded {
+            request_id,
+            status,
+            duration_ms,
+            response_headers,
+            response_body,
+        } => json!({
+            "event": "request_forwarded",
+            "request_id": request_id,
+            "status": status,
+            "duration_ms": duration_ms,
+            "response_headers": response_headers,
+            "response_body": response_body,
+        }),
+        TunnelEvent::RequestFailed {
+            request_id,
+            error,
+            unreachable_target,
+        } => json!({
+            "event": "request_failed",
+            "request_id": request_id,
+            "error": error,
+            "unreachable_target": unreachable_target,
+        }),
+        TunnelEvent::FaultInjected { request_id, faults } => json!({
+            "event": "fault_injected",
+            "request_id": request_id,
+            "faults": faults,
+        }),
+        TunnelEvent::RequestMocked {
+            request_id,
+            status,
+            duration_ms,
+            response_headers,
+            response_body,
+            mode,
+        } => json!({
+            "event": "request_mocked",
+            "request_id": request_id,
+            "status": status,
+            "duration_ms": duration_ms,
+            "response_headers": response_headers,
+            "response_body": response_body,
+            "mode": match mode {
+                MockMode::Always => "always",
+                MockMode::Fallback => "fallback",
+            },
+        }),
+        TunnelEvent::BytesTransferred {
+            request_id,
+            bytes_in,
+            bytes_out,
+        } => json!({
+            "event": "bytes_transferred",
+            "request_id": request_id,
+            "bytes_in": bytes_in,
+            "bytes_out": bytes_out,
+        }),
+        TunnelEvent::ResponseStreamStarted {
+            request_id,
+            status,
+            response_headers,
+        } => json!({
+            "event": "response_stream_started",
+            "request_id": request_id,
+            "status": status,
+            "response_headers": response_headers,
+        }),
+        TunnelEvent::ResponseStreamEnded {
+            request_id,
+            duration_ms,
+            response_body,
+            error,
+        } => json!({
+            "event": "response_stream_ended",
+            "request_id": request_id,
+            "duration_ms": duration_ms,
+            "response_body": response_body,
+            "error": error,
+        }),
+        TunnelEvent::WebSocketOpened {
+            request_id,
+            response_headers,
+        } => json!({
+            "event": "websocket_opened",
+            "request_id": request_id,
+            "response_headers": response_headers,
+        }),
+        TunnelEvent::WebSocketFrames {
+            request_id,
+            frames_in,
+            frames_out,
+        } => json!({
+            "event": "websocket_frames",
+            "request_id": request_id,
+            "frames_in": frames_in,
+            "frames_out": frames_out,
+        }),
+        TunnelEvent::WebSocketClosed {
+            request_id,
+            code,
+            reason,
+        } => json!({
+            "event": "websocket_closed",
+            "request_id": request_id,
+            "code": code,
+            "reason": reason,
+        }),
+        TunnelEvent::RequestIntercepted { request_id, .. } => json!({
+            "event": "request_intercepted",
+            "request_id": request_id,
+        }),
+        TunnelEvent::WebhookReceived(request) => json!({
+            "event": "webhook_received",
+            "request": request,
+        }),
+        TunnelEvent::ForwardSuccess => json!({ "event": "forward_success" }),
+        TunnelEvent::ForwardError { unreachable_target } => json!({
+            "event": "forward_error",
+            "unreachable_target": unreachable_target,
+        }),
+        TunnelEvent::Reconnecting {
+            attempt,
+            max_attempts,
+            next_retry_in_secs,
+        } => json!({
+            "event": "reconnecting",
+            "attempt": attempt,
+            "max_attempts": max_attempts,
+            "next_retry_in_secs": next_retry_in_secs,
+        }),
+        TunnelEvent::ReconnectFailed { reason, .. } => {
+            json!({ "event": "reconnect_failed", "reason": reason })
+        }
+        TunnelEvent::TokenRefreshed => json!({ "event": "token_refreshed" }),
+        TunnelEvent::InFlight {
+            active,
+            queued,
+            max,
+        } => json!({
+            "event": "in_flight",
+            "active": active,
+            "queued": queued,
+            "max": max,
+        }),
+    }
+}
+
+/// Print events, redacted by the project rules, until Ctrl+C or SIGTERM. Returns an error when the connection
+/// is given up for good, so the process exits non-zero. With `fail_on_unreachable`, a request that finds
+/// nothing listening on the local target also ends the session.
+pub async fn run(
+    mut events: mpsc::Receiver<TunnelEvent>,
+    json: bool,
+    redact: RedactRules,
+    fail_on_unreachable: bool,
+) -> Result<()> {
+    let mut printer = EventPrinter::new(json);
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            event = events.recv() => {
+                let Some(mut event) = event else {
+                    return Err(anyhow!("Connection closed unexpectedly"));
+                };
+                event.redact(&redact);
+                if let Some(line) = printer.format(&event) {
+                    println!("{}", line);
+                }
+                match event {
+                    TunnelEvent::ReconnectFailed { reason, error } => {
+                        return Err(match error {
+                            Some(error) => anyhow::Error::new(error).context("Connection lost"),
+                            None => anyhow!("Connection lost: {}", reason),
+                        });
+                    }
+                    TunnelEvent::RequestFailed {
+                        unreachable_target: Some(target),
+                        ..
+                    }
+                    | TunnelEvent::ForwardError {
+                        unreachable_target: Some(target),
+                    } if fail_on_unreachable => {
+                        return Err(TunnelError::TargetUnreachable { target }.into());
+                    }
+                    _ => {}
+                }
+            }
+            _ = &mut shutdown => {
+                info!("Shutting down headless session");
+                return Ok(());
+            }
+        }
+    }
+}
+
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+            }
+            Err(_) => {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorKind;
+
+    fn received() -> TunnelEvent {
+        TunnelEvent::RequestReceived {
+            request_id: "req-1".to_string(),
+            method: "POST".to_string(),
+            path: "/hook".to_string(),
+            headers: [("content-type", "text/plain")].into_iter().collect(),
+            body: Some("hi".into()),
+            query_string: String::new(),
+            target: "http://localhost:3000/hook".to_string(),
+            route: None,
+        }
+    }
+
+    fn forwarded() -> TunnelEvent {
+        TunnelEvent::RequestForwarded {
+            request_id: "req-1".to_string(),
+            status: 200,
+            duration_ms: 12,
+            response_headers: Default::default(),
+            response_body: None,
+        }
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let mut printer = EventPrinter::new(true);
+        let line = printer.format(&received()).unwrap();
+        assert!(!line.contains('\n'));
+        let value: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["event"], "request_received");
+        assert_eq!(value["request_id"], "req-1");
+        assert_eq!(value["headers"]["content-type"], "text/plain");
+        assert_eq!(value["body"], "hi");
+        assert!(value["timestamp"].is_string());
+
+        let failed = printer
+            .format(&TunnelEvent::ReconnectFailed {
+                reason: "Unauthorized".to_string(),
+                error: None,
+            })
+            .unwrap();
+        let value: Value = serde_json::from_str(&failed).unwrap();
+        assert_eq!(value["event"], "reconnect_failed");
+        assert_eq!(value["reason"], "Unauthorized");
+    }
+
+    #[test]
+    fn test_text_lines_name_the_request() {
+        let mut printer = EventPrinter::new(false);
+        let line = printer.format(&received()).unwrap();
+        assert!(line.ends_with("→ POST /hook → http://localhost:3000/hook"));
+        let line = printer.format(&forwarded()).unwrap();
+        assert!(line.ends_with("✓ POST /hook 200 (12ms)"));
+        assert!(printer.labels.is_empty());
+
+        let in_flight = TunnelEvent::InFlight {
+            active: 1,
+            queued: 0,
+            max: 8,
+        };
+        assert!(printer.format(&in_flight).is_none());
+    }
+
+    #[test]
+    fn test_json_lines_hide_redacted_webhook_values() {
+        let rules = RedactRules {
+            headers: vec!["authorization".to_string()],
+            body_fields: vec!["password".to_string()],
+        };
+        let body = r#"{"user":"a","password":"hunter2"}"#;
+        let mut event = TunnelEvent::WebhookReceived(Box::new(crate::models::WebhookRequest {
+            id: "req-1".to_string(),
+            timestamp: 0,
+            remote_addr: "Tunnel".to_string(),
+            headers: [("Authorization", "Bearer hunter2")].into_iter().collect(),
+            content_length: body.len() as i64,
+            method: "POST".to_string(),
+            url: "/hook".to_string(),
+            path: Some("/hook".to_string()),
+            query_params: HashMap::new(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            body_preview: Some(body.to_string()),
+            body: Some(body.into()),
+        }));
+        event.redact(&rules);
+
+        let line = EventPrinter::new(true).format(&event).unwrap();
+        assert!(!line.contains("hunter2"), "secret leaked: {line}");
+        let value: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["request"]["headers"]["Authorization"], "[REDACTED]");
+        assert!(
+            value["request"]["body_preview"]
+                .as_str()
+                .unwrap()
+                .contains("[REDACTED]")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_fails_when_reconnect_gives_up() {
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(TunnelEvent::Connected).await.unwrap();
+        tx.send(TunnelEvent::ReconnectFailed {
+            reason: "Maximum reconnection attempts exceeded".to_string(),
+            error: None,
+        })
+        .await
+        .unwrap();
+        let err = run(rx, true, RedactRules::default(), false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Maximum reconnection attempts"));
+        assert_eq!(ErrorKind::of(&err), ErrorKind::General);
+    }
+
+    #[tokio::test]
+    async fn test_run_exits_with_the_typed_connect_error() {
+        let cases = [
+            (TunnelError::AuthenticationFailed, ErrorKind::Auth),
+            (
+                TunnelError::EndpointNotFound {
+                    slug: "missing".to_string(),
+                },
+                ErrorKind::NotFound,
+            ),
+        ];
+        for (error, kind) in cases {
+            let (tx, rx) = mpsc::channel(4);
+            tx.send(TunnelEvent::ReconnectFailed {
+                reason: error.to_string(),
+                error: Some(error),
+            })
+            .await
+            .unwrap();
+            let err = run(rx, true, RedactRules::default(), false)
+                .await
+                .unwrap_err();
+            assert_eq!(ErrorKind::of(&err), kind);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refused_forward_ends_the_session_only_when_asked() {
+        let refused = || TunnelEvent::RequestFailed {
+            request_id: "req-1".to_string(),
+            error: "Local target http://localhost:3000 is not reachable".to_string(),
+            unreachable_target: Some("http://localhost:3000".to_string()),
+        };
+
+        // The session keeps running; it only ends here because the sender is dropped
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(refused()).await.unwrap();
+        drop(tx);
+        let err = run(rx, true, RedactRules::default(), false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("closed unexpectedly"));
+
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(refused()).await.unwrap();
+        let err = run(rx, true, RedactRules::default(), true)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            crate::errors::ErrorKind::of(&err),
+            crate::errors::ErrorKind::TargetUnreachable
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use anyhow::{Context, Result, anyhow};
+use bytes::Bytes;
+use futures_util::stream;
+use http_body_util::{BodyExt, Full, StreamBody, combinators::BoxBody};
+use hyper::body::{Frame, Incoming};
+use hyper::header::{CACHE_CONTROL, CONTENT_TYPE, HOST};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tracing::{debug, info};
+
+use crate::api::ApiClient;
+use crate::app::{MAX_TUNNEL_REQUESTS, TunnelRequest, parse_query_string};
+use crate::http::NetworkSettings;
+use crate::models::{Body, Headers, WebhookRequest};
+
+/// Default address for `--inspect` without a value.
+pub const DEFAULT_INSPECT_ADDR: &str = "127.0.0.1:4040";
+
+/// Header the inspector page sends with replays; browsers cannot add it cross-origin
+/// without a CORS preflight, which the inspector never approves.
+const REPLAY_HEADER: &str = "x-hooklistener-inspector";
+
+const INDEX_HTML: &str = include_str!("inspector.html");
+
+/// A captured request as shown in the inspector.
+#[derive(Debug, Clone, Serialize)]
+pub struct InspectorEntry {
+    pub id: String,
+    /// `tunnel` or `listen`
+    pub source: &'static str,
+    pub method: String,
+    pub path: String,
+    pub query_string: String,
+    pub received_at: String,
+    pub headers: Headers,
+    pub body: Option<Body>,
+    /// Size of the request body as received, set when `body` was truncated
+    pub body_original_len: Option<usize>,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+    pub duration_ms: Option<u64>,
+    /// Local URL (without query) the request was forwarded to; replays go here
+    pub target_url: String,
+    pub response_headers: Option<Headers>,
+    pub response_body: Option<Body>,
+}
+
+impl InspectorEntry {
+    pub fn from_tunnel(request: &TunnelRequest) -> Self {
+        let elapsed = chrono::Duration::from_std(request.received_at.elapsed()).unwrap_or_default();
+        let target_url = request
+            .target_url
+            .split_once('?')
+            .map_or(request.target_url.as_str(), |(url, _)| url);
+        Self {
+            id: request.request_id.clone(),
+            source: "tunnel",
+            method: request.method.clone(),
+            path: request.path.clone(),
+            query_string: request.query_string.clone(),
+            received_at: (chrono::Utc::now() - elapsed).to_rfc3339(),
+            headers: request.headers.clone(),
+            body: request.body.clone(),
+            body_original_len: truncated_len(request.body.as_ref()),
+            status: request.status,
+            error: request.error.clone(),
+            duration_ms: request
+                .completed_at
+                .map(|done| done.duration_since(request.received_at).as_millis() as u64),
+            target_url: target_url.to_string(),
+            response_headers: request.response_headers.clone(),
+            response_body: request.response_body.clone(),
+        }
+    }
+
+    pub fn from_webhook(request: &WebhookRequest, target: &str) -> Self {
+        let path = request.path.clone().unwrap_or_else(|| request.url.clone());
+        let mut query: Vec<String> = request
+            .query_params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        query.sort();
+        Self {
+            id: request.id.clone(),
+            source: "listen",
+            method: request.method.clone(),
+            target_url: format!("{}{}", target, path),
+            path,
+            query_string: query.join("&"),
+            received_at: request.created_at.clone(),
+            headers: request.headers.clone(),
+            body: request.body.clone(),
+            body_original_len: truncated_len(request.body.as_ref()),
+            status: None,
+            error: None,
+            duration_ms: None,
+            response_headers: None,
+            response_body: None,
+        }
+    }
+
+    /// The request to send again on replay.
+    fn to_webhook_request(&self) -> WebhookRequest {
+        WebhookRequest {
+            id: self.id.clone(),
+            timestamp: chrono::Utc::now().timestamp(),
+            remote_addr: "Inspector".to_string(),
+            headers: self.headers.clone(),
+            content_length: self.body.as_ref().map_or(0, |b| b.len() as i64),
+            method: self.method.clone(),
+            url: self.path.clone(),
+            path: Some(self.path.clone()),
+            query_params: parse_query_string(&self.query_string),
+            created_at: self.received_at.clone(),
+            body_preview: None,
+            body: self.body.clone(),
+        }
+    }
+}
+
+fn truncated_len(body: Option<&Body>) -> Option<usize> {
+    body.filter(|body| body.is_truncated())
+        .map(Body::original_len)
+}
+
+/// Captured traffic shared with the local inspector web UI.
+pub struct Inspector {
+    entries: Mutex<VecDeque<InspectorEntry>>,
+    updates: broadcast::Sender<InspectorEntry>,
+    /// Settings for replays; `None` uses the process-wide ones
+    network: Option<NetworkSettings>,
+}
+
+impl Inspector {
+    pub fn new() -> Arc<Self> {
+        Self::build(None)
+    }
+
+    #[cfg(test)]
+    pub fn with_network(network: NetworkSettings) -> Arc<Self> {
+        Self::build(Some(network))
+    }
+
+    fn build(network: Option<NetworkSettings>) -> Arc<Self> {
+        let (updates, _) = broadcast::channel(256);
+        Arc::new(Self {
+            entries: Mutex::new(VecDeque::new()),
+            updates,
+            network,
+        })
+    }
+
+    /// Add or replace an entry and notify live subscribers.
+    pub fn publish(&self, entry: InspectorEntry) {
+        // Only copy the entry for live pages when one is open
+        let update = (self.updates.receiver_count() > 0).then(|| entry.clone());
+        {
+            let mut entries = self.entries.lock().unwrap();
+            match entries.iter_mut().find(|e| e.id == entry.id) {
+                Some(existing) => *existing = entry,
+                None => {
+                    entries.push_back(entry);
+                    if entries.len() > MAX_TUNNEL_REQUESTS {
+                        entries.pop_front();
+                    }
+                }
+            }
+        }
+        if let Some(update) = update {
+            // The page may have closed since the check
+            let _ = self.updates.send(update);
+        }
+    }
+
+    fn list(&self) -> Vec<InspectorEntry> {
+        self.entries.lock().unwrap().iter().rev().cloned().collect()
+    }
+
+    fn get(&self, id: &str) -> Option<InspectorEntry> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|e| e.id == id)
+            .cloned()
+    }
+
+    /// Bind the inspector to a loopback address and serve it in the background.
+    pub async fn start(self: &Arc<Self>, addr: SocketAddr) -> Result<SocketAddr> {
+        if !addr.ip().is_loopback() {
+            return Err(anyhow!(
+                "Inspector address {} must be a loopback address such as 127.0.0.1",
+                addr
+            ));
+        }
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed to start inspector on {}", addr))?;
+        let local_addr = listener.local_addr()?;
+        info!(address = %local_addr, "Inspector listening");
+
+        let inspector = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    continue;
+                };
+                let inspector = Arc::clone(&inspector);
+                tokio::spawn(async move {
+                    let service = service_fn(move |req| {
+                        let inspector = Arc::clone(&inspector);
+                        async move { Ok::<_, Infallible>(inspector.handle(req).await) }
+                    });
+                    if let Err(e) = http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await
+                    {
+                        debug!("Inspector connection error: {}", e);
+                    }
+                });
+            }
+        });
+
+        Ok(local_addr)
+    }
+
+    async fn handle(&self, req: Request<Incoming>) -> Response<BoxBody<Bytes, Infallible>> {
+        // Only answer requests addressed to localhost, so other sites cannot reach
+        // the inspector through DNS rebinding
+        if !is_local_host(req.headers().get(HOST).and_then(|v| v.to_str().ok())) {
+            return text_response(StatusCode::FORBIDDEN, "Forbidden host");
+        }
+
+        let path = req.uri().path().to_string();
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (req.method(), segments.as_slice()) {
+            (&Method::GET, [""]) => Response::builder()
+                .header(CONTENT_TYPE, "text/html; charset=utf-8")
+                .body(full(INDEX_HTML))
+                .unwrap(),
+            (&Method::GET, ["api", "requests"]) => json_response(StatusCode::OK, &self.list()),
+            (&Method::GET, ["api", "requests", id]) => match self.get(id) {
+                Some(entry) => json_response(StatusCode::OK, &entry),
+                None => text_response(StatusCode::NOT_FOUND, "Request not found"),
+            },
+            (&Method::POST, ["api", "requests", id, "replay"]) => {
+                if !req.headers().contains_key(REPLAY_HEADER) {
+                    return text_response(StatusCode::FORBIDDEN, "Missing inspector header");
+                }
+                match self.get(id) {
+                    Some(entry) => self.replay(&entry).await,
+                    None => text_response(StatusCode::NOT_FOUND, "Request not found"),
+                }
+            }
+            (&Method::GET, ["api", "events"]) => self.events(),
+            _ => text_response(StatusCode::NOT_FOUND, "Not found"),
+        }
+    }
+
+    /// Send a captured request to its local target again.
+    async fn replay(&self, entry: &InspectorEntry) -> Response<BoxBody<Bytes, Infallible>> {
+        if let Some(original_len) = entry.body_original_len {
+            let kept = entry.body.as_ref().map_or(0, Body::len);
+            return text_response(
+                StatusCode::CONFLICT,
+                &format!(
+                    "Cannot replay: only {} of {} body bytes were kept",
+                    kept, original_len
+                ),
+            );
+        }
+        info!(request_id = %entry.id, target = %entry.target_url, "Replaying from inspector");
+        let result = async {
+            ApiClient::for_forwarding(crate::http::settings_or(self.network.as_ref())?)?
+                .forward_request(&entry.to_webhook_request(), &entry.target_url)
+                .await
+        }
+        .await;
+        match result {
+            Ok(response) => json_response(StatusCode::OK, &response),
+            Err(e) => text_response(StatusCode::BAD_GATEWAY, &format!("Replay failed: {}", e)),
+        }
+    }
+
+    /// Server-sent events: one `data:` message with the entry JSON per update.
+    fn events(&self) -> Response<BoxBody<Bytes, Infallible>> {
+        let updates = stream::unfold(self.updates.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(entry) => {
+                        let json = serde_json::to_string(&entry).unwrap_or_default();
+                        let frame = Frame::data(Bytes::from(format!("data: {}\n\n", json)));
+                        return Some((Ok(frame), rx));
+                    }
+                    // A slow page missed some updates; it still gets the next ones
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+
+        Response::builder()
+            .header(CONTENT_TYPE, "text/event-stream")
+            .header(CACHE_CONTROL, "no-cache")
+            .body(StreamBody::new(updates).boxed())
+            .unwrap()
+    }
+}
+
+/// Whether a `Host` header names this machine (`localhost`, `127.0.0.1` or `[::1]`).
+fn is_local_host(host: Option<&str>) -> bool {
+    let Some(host) = host else {
+        return false;
+    };
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+fn full(body: impl Into<Bytes>) -> BoxBody<Bytes, Infallible> {
+    Full::new(body.into()).boxed()
+}
+
+fn text_response(status: StatusCode, message: &str) -> Response<BoxBody<Bytes, Infallible>> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "text/plain; charset=utf-8")
+        .body(full(message.to_string()))
+        .unwrap()
+}
+
+fn json_response(
+    status: StatusCode,
+    value: &impl Serialize,
+) -> Response<BoxBody<Bytes, Infallible>> {
+    match serde_json::to_vec(value) {
+        Ok(json) => Response::builder()
+            .status(status)
+            .header(CONTENT_TYPE, "application/json")
+            .body(full(json))
+            .unwrap(),
+        Err(e) => text_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str) -> InspectorEntry {
+        let mut request = WebhookRequest {
+            id: id.to_string(),
+            timestamp: 0,
+            remote_addr: "Tunnel".to_string(),
+            headers: [("content-type", "text/plain")].into_iter().collect(),
+            content_length: 2,
+            method: "POST".to_string(),
+            url: "/hook".to_string(),
+            path: Some("/hook".to_string()),
+            query_params: Default::default(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            body_preview: None,
+            body: Some("hi".into()),
+        };
+        request
+            .query_params
+            .insert("a".to_string(), "1".to_string());
+        InspectorEntry::from_webhook(&request, "http://localhost:3000")
+    }
+
+    #[test]
+    fn test_is_local_host() {
+        assert!(is_local_host(Some("127.0.0.1:4040")));
+        assert!(is_local_host(Some("localhost:4040")));
+        assert!(is_local_host(Some("[::1]:4040")));
+        assert!(!is_local_host(Some("evil.example.com:4040")));
+        assert!(!is_local_host(None));
+    }
+
+    #[test]
+    fn test_publish_replaces_entries_by_id() {
+        let inspector = Inspector::new();
+        inspector.publish(entry("a"));
+        inspector.publish(entry("b"));
+        let mut updated = entry("a");
+        updated.status = Some(201);
+        inspector.publish(updated);
+
+        let list = inspector.list();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].id, "b");
+        assert_eq!(inspector.get("a").unwrap().status, Some(201));
+        assert_eq!(list[1].target_url, "http://localhost:3000/hook");
+        assert_eq!(list[1].query_string, "a=1");
+    }
+
+    #[tokio::test]
+    async fn test_serves_requests_and_replays() {
+        let mut local = mockito::Server::new_async().await;
+        let mock = local
+            .mock("POST", "/hook")
+            .match_query(mockito::Matcher::UrlEncoded("a".into(), "1".into()))
+            .match_body("hi")
+            .with_status(202)
+            .create_async()
+            .await;
+
+        let inspector = Inspector::with_network(NetworkSettings::default());
+        let mut captured = entry("req-1");
+        captured.target_url = format!("{}/hook", local.url());
+        inspector.publish(captured);
+        let addr = inspector
+            .start("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let base = format!("http://{}", addr);
+        let client = reqwest::Client::new();
+
+        let list: serde_json::Value = client
+            .get(format!("{}/api/requests", base))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(list[0]["id"], "req-1");
+        assert_eq!(list[0]["body"], "hi");
+
+        let replay_url = format!("{}/api/requests/req-1/replay", base);
+        let refused = client.post(&replay_url).send().await.unwrap();
+        assert_eq!(refused.status(), 403);
+
+        let replayed: serde_json::Value = client
+            .post(&replay_url)
+            .header(REPLAY_HEADER, "1")
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(replayed["status_code"], 202);
+        mock.assert_async().await;
+
+        let mut truncated = entry("req-2");
+        truncated.body = Some(Body::from("0123456789").truncate(4));
+        truncated.body_original_len = truncated_len(truncated.body.as_ref());
+        assert_eq!(truncated.body_original_len, Some(10));
+        inspector.publish(truncated);
+        let refused = client
+            .post(format!("{}/api/requests/req-2/replay", base))
+            .header(REPLAY_HEADER, "1")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(refused.status(), 409);
+        assert_eq!(
+            refused.text().await.unwrap(),
+            "Cannot replay: only 4 of 10 body bytes were kept"
+        );
+
+        assert!(inspector.start("0.0.0.0:0".parse().unwrap()).await.is_err());
+    }
+}
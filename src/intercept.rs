@@ -0,0 +1,292 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+use crate::models::Headers;
+use crate::project::REDACTED;
+use crate::rules::RequestMatcher;
+
+/// Rule selecting which incoming requests are held for editing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterceptRule {
+    pub matcher: RequestMatcher,
+}
+
+impl InterceptRule {
+    /// Parse an `--intercept` value.
+    ///
+    /// Syntax: `[METHOD ]PATH_GLOB[,header=NAME[:VALUE]]`, for example `*`,
+    /// `POST /webhooks/*` or `/api/*,header=x-source:stripe`.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut parts = spec.split(',');
+        let matcher = parts.next().unwrap_or_default().trim();
+
+        let (method, path) = match matcher.split_once(char::is_whitespace) {
+            Some((method, path)) => (Some(method.trim().to_uppercase()), path.trim()),
+            None if matcher.is_empty() => (None, "*"),
+            None if matcher.starts_with('/') || matcher.starts_with('*') => (None, matcher),
+            None => (Some(matcher.to_uppercase()), "*"),
+        };
+
+        let mut matcher = RequestMatcher {
+            method,
+            path: path.to_string(),
+            header: None,
+        };
+
+        for option in parts {
+            let option = option.trim();
+            if let Some(header) = option.strip_prefix("header=") {
+                matcher.header = Some(header.to_string());
+            } else if !option.is_empty() {
+                return Err(anyhow!(
+                    "Invalid intercept rule '{}': unknown option '{}'",
+                    spec,
+                    option
+                ));
+            }
+        }
+
+        if !matcher.has_valid_path() {
+            return Err(anyhow!(
+                "Invalid intercept rule '{}': path must start with '/' or '*'",
+                spec
+            ));
+        }
+
+        Ok(Self { matcher })
+    }
+}
+
+/// Editable copy of a held request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeldRequest {
+    pub method: String,
+    pub path: String,
+    pub query_string: String,
+    pub headers: Headers,
+    pub body: String,
+}
+
+impl HeldRequest {
+    /// Put back header values and JSON body fields that redaction hid from the
+    /// TUI, so forwarding an edited copy never sends `[REDACTED]` to the target.
+    pub fn restore_redacted(&mut self, original: &HeldRequest) {
+        if self.headers.iter().any(|(_, value)| value == REDACTED) {
+            let mut seen: HashMap<String, usize> = HashMap::new();
+            self.headers = self
+                .headers
+                .iter()
+                .map(|(name, value)| {
+                    let index = seen.entry(name.to_ascii_lowercase()).or_default();
+                    let restored = original.headers.get_all(name).nth(*index);
+                    *index += 1;
+                    match restored {
+                        Some(restored) if value == REDACTED => (name.clone(), restored.to_string()),
+                        _ => (name.clone(), value.clone()),
+                    }
+                })
+                .collect();
+        }
+
+        if !self.body.contains(REDACTED) {
+            return;
+        }
+        let (Ok(mut edited), Ok(source)) = (
+            serde_json::from_str::<serde_json::Value>(&self.body),
+            serde_json::from_str::<serde_json::Value>(&original.body),
+        ) else {
+            return;
+        };
+        restore_value(&mut edited, &source);
+        if edited == source {
+            // Unchanged apart from redaction: keep the original formatting
+            self.body = original.body.clone();
+        } else if let Ok(body) = serde_json::to_string(&edited) {
+            self.body = body;
+        }
+    }
+}
+
+fn restore_value(edited: &mut serde_json::Value, original: &serde_json::Value) {
+    use serde_json::Value;
+    match (edited, original) {
+        (Value::Object(map), Value::Object(source)) => {
+            for (key, field) in map.iter_mut() {
+                if let Some(source) = source.get(key) {
+                    if field.as_str() == Some(REDACTED) {
+                        *field = source.clone();
+                    } else {
+                        restore_value(field, source);
+                    }
+                }
+            }
+        }
+        (Value::Array(items), Value::Array(source)) => {
+            for (item, source) in items.iter_mut().zip(source) {
+                restore_value(item, source);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Hand-written response sent back instead of forwarding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManualResponse {
+    pub status: u16,
+    pub headers: Headers,
+    pub body: String,
+}
+
+/// What to do with a held request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterceptDecision {
+    /// Forward the (possibly edited) request to the local target
+    Forward(HeldRequest),
+    /// Never forward the request
+    Drop,
+    /// Answer the request directly (tunnel only)
+    Respond(ManualResponse),
+}
+
+/// Shared intercept state: the forwarding side holds requests, the TUI resolves them.
+#[derive(Clone, Default)]
+pub struct Interceptor {
+    rules: Arc<Vec<InterceptRule>>,
+    enabled: Arc<AtomicBool>,
+    pending: Arc<Mutex<HashMap<String, oneshot::Sender<InterceptDecision>>>>,
+}
+
+impl Interceptor {
+    /// Create an interceptor that is active whenever any rules are given.
+    pub fn new(rules: Vec<InterceptRule>) -> Self {
+        Self {
+            enabled: Arc::new(AtomicBool::new(!rules.is_empty())),
+            rules: Arc::new(rules),
+            pending: Arc::default(),
+        }
+    }
+
+    pub fn has_rules(&self) -> bool {
+        !self.rules.is_empty()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume interception; requests already held stay held.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Whether a new request should be held.
+    pub fn should_hold(&self, method: &str, path: &str, headers: &Headers) -> bool {
+        self.is_enabled()
+            && self
+                .rules
+                .iter()
+                .any(|rule| rule.matcher.matches(method, path, headers))
+    }
+
+    /// Register a held request; the receiver completes when the TUI decides.
+    pub fn hold(&self, request_id: &str) -> oneshot::Receiver<InterceptDecision> {
+        let (tx, rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .unwrap()
+            .insert(request_id.to_string(), tx);
+        rx
+    }
+
+    /// Deliver a decision for a held request. Returns false if it is no longer waiting.
+    pub fn resolve(&self, request_id: &str, decision: InterceptDecision) -> bool {
+        let Some(tx) = self.pending.lock().unwrap().remove(request_id) else {
+            return false;
+        };
+        tx.send(decision).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rules() {
+        let all = InterceptRule::parse("*").unwrap().matcher;
+        assert_eq!(all, RequestMatcher::default());
+
+        let post = InterceptRule::parse("post /webhooks/*").unwrap().matcher;
+        assert_eq!(post.method.as_deref(), Some("POST"));
+        assert_eq!(post.path, "/webhooks/*");
+
+        let method_only = InterceptRule::parse("DELETE").unwrap().matcher;
+        assert_eq!(method_only.method.as_deref(), Some("DELETE"));
+        assert_eq!(method_only.path, "*");
+
+        let header = InterceptRule::parse("/api/*,header=x-source:stripe")
+            .unwrap()
+            .matcher;
+        assert_eq!(header.header.as_deref(), Some("x-source:stripe"));
+
+        assert!(InterceptRule::parse("/api/*,bogus").is_err());
+        assert!(InterceptRule::parse("GET api").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_hold_and_resolve() {
+        let interceptor = Interceptor::new(vec![InterceptRule::parse("*").unwrap()]);
+        assert!(interceptor.should_hold("GET", "/", &Headers::new()));
+
+        let rx = interceptor.hold("req-1");
+        assert!(interceptor.resolve("req-1", InterceptDecision::Drop));
+        assert_eq!(rx.await.unwrap(), InterceptDecision::Drop);
+        assert!(!interceptor.resolve("req-1", InterceptDecision::Drop));
+
+        interceptor.set_enabled(false);
+        assert!(!interceptor.should_hold("GET", "/", &Headers::new()));
+    }
+
+    #[test]
+    fn test_interceptor_without_rules_is_disabled() {
+        let interceptor = Interceptor::new(Vec::new());
+        assert!(!interceptor.has_rules());
+        assert!(!interceptor.should_hold("GET", "/", &Headers::new()));
+    }
+
+    #[test]
+    fn test_restore_redacted_keeps_edits_and_hidden_values() {
+        let original = HeldRequest {
+            method: "POST".to_string(),
+            path: "/hook".to_string(),
+            query_string: String::new(),
+            headers: [("Authorization", "Bearer secret"), ("Accept", "*/*")]
+                .into_iter()
+                .collect(),
+            body: "{\n  \"user\": \"a\",\n  \"password\": \"secret\"\n}".to_string(),
+        };
+
+        let mut unchanged = HeldRequest {
+            headers: [("Authorization", REDACTED), ("Accept", "*/*")]
+                .into_iter()
+                .collect(),
+            body: format!(r#"{{"user":"a","password":"{REDACTED}"}}"#),
+            ..original.clone()
+        };
+        unchanged.restore_redacted(&original);
+        assert_eq!(unchanged, original);
+
+        let mut edited = HeldRequest {
+            headers: [("Authorization", REDACTED)].into_iter().collect(),
+            body: format!(r#"{{"user":"b","password":"{REDACTED}"}}"#),
+            ..original.clone()
+        };
+        edited.restore_redacted(&original);
+        assert_eq!(edited.headers.get("authorization"), Some("Bearer secret"));
+        assert_eq!(edited.body, r#"{"user":"b","password":"secret"}"#);
+    }
+}
@@ -0,0 +1,235 @@
+use anyhow::{Context, Result, anyhow};
+use std::fs;
+use std::net::ToSocketAddrs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// TLS settings for talking to HTTPS local targets.
+#[derive(Debug, Clone, Default)]
+pub struct LocalTlsOptions {
+    /// Extra PEM CA bundle to trust (e.g. a local dev CA)
+    pub ca_file: Option<PathBuf>,
+    /// Accept any certificate, for self-signed development certs
+    pub insecure: bool,
+    /// PEM client certificate for mTLS
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for the client certificate (may be omitted if bundled in `client_cert`)
+    pub client_key: Option<PathBuf>,
+    /// Server name to send in the TLS handshake instead of the target host
+    pub sni: Option<String>,
+}
+
+impl LocalTlsOptions {
+    /// Build the HTTP client used for local forwarding.
+    ///
+    /// `default_target` is the tunnel's primary target; an SNI override is pinned to its address.
+    /// Only connecting is bounded here; request timeouts are applied per request.
+    pub fn build_client(
+        &self,
+        default_target: &str,
+        connect_timeout: Duration,
+    ) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder().connect_timeout(connect_timeout);
+
+        if let Some(ca_file) = &self.ca_file {
+            let pem = fs::read(ca_file)
+                .with_context(|| format!("Failed to read CA file {}", ca_file.display()))?;
+            let certs = reqwest::Certificate::from_pem_bundle(&pem)
+                .with_context(|| format!("Invalid CA file {}", ca_file.display()))?;
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        if self.insecure {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        if let Some(cert_path) = &self.client_cert {
+            let mut pem = fs::read(cert_path).with_context(|| {
+                format!("Failed to read client certificate {}", cert_path.display())
+            })?;
+            if let Some(key_path) = &self.client_key {
+                let key = fs::read(key_path)
+                    .with_context(|| format!("Failed to read client key {}", key_path.display()))?;
+                pem.push(b'\n');
+                pem.extend_from_slice(&key);
+            }
+            let identity = reqwest::Identity::from_pem(&pem)
+                .context("Invalid client certificate or key (expected PEM)")?;
+            builder = builder.identity(identity);
+        } else if self.client_key.is_some() {
+            return Err(anyhow!("--client-key requires --client-cert"));
+        }
+
+        if let Some(sni) = &self.sni {
+            let url = reqwest::Url::parse(default_target)
+                .with_context(|| format!("Invalid target URL {}", default_target))?;
+            let host = url
+                .host_str()
+                .ok_or_else(|| anyhow!("Target URL {} has no host", default_target))?;
+            let port = url.port_or_known_default().unwrap_or(443);
+            let addr = (host, port)
+                .to_socket_addrs()
+                .with_context(|| format!("Failed to resolve {}:{}", host, port))?
+                .next()
+                .ok_or_else(|| anyhow!("No address found for {}:{}", host, port))?;
+            builder = builder.resolve(sni, addr);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Build the TLS connector for proxied `wss://` WebSockets, or `None` when the
+    /// platform defaults apply. SNI is handled by the caller connecting to the original address.
+    pub fn ws_connector(&self) -> Result<Option<tokio_tungstenite::Connector>> {
+        if self.ca_file.is_none() && !self.insecure && self.client_cert.is_none() {
+            return Ok(None);
+        }
+
+        let mut builder = native_tls::TlsConnector::builder();
+
+        if let Some(ca_file) = &self.ca_file {
+            let pem = fs::read(ca_file)
+                .with_context(|| format!("Failed to read CA file {}", ca_file.display()))?;
+            let certs = native_tls::Certificate::stack_from_pem(&pem)
+                .with_context(|| format!("Invalid CA file {}", ca_file.display()))?;
+            for cert in certs {
+                builder.add_root_certificate(cert);
+            }
+        }
+
+        if self.insecure {
+            builder.danger_accept_invalid_certs(true);
+        }
+
+        if let Some(cert_path) = &self.client_cert {
+            let cert = fs::read(cert_path).with_context(|| {
+                format!("Failed to read client certificate {}", cert_path.display())
+            })?;
+            // Without --client-key the key is expected in the certificate file
+            let key = match &self.client_key {
+                Some(key_path) => fs::read(key_path)
+                    .with_context(|| format!("Failed to read client key {}", key_path.display()))?,
+                None => cert.clone(),
+            };
+            let identity = native_tls::Identity::from_pkcs8(&cert, &key)
+                .context("Invalid client certificate or key (expected PEM with a PKCS#8 key)")?;
+            builder.identity(identity);
+        }
+
+        let connector = builder.build().context("Failed to build TLS connector")?;
+        Ok(Some(tokio_tungstenite::Connector::NativeTls(connector)))
+    }
+
+    /// Rewrite a forwarding URL so the TLS handshake uses the SNI override.
+    ///
+    /// Only URLs pointing at `default_target` are rewritten; the host is pinned to the
+    /// original address by [`build_client`](Self::build_client).
+    pub fn apply_sni(&self, url: &str, default_target: &str) -> String {
+        let Some(sni) = &self.sni else {
+            return url.to_string();
+        };
+        let (Ok(mut parsed), Ok(target)) = (
+            reqwest::Url::parse(url),
+            reqwest::Url::parse(default_target),
+        ) else {
+            return url.to_string();
+        };
+        if parsed.scheme() != "https"
+            || parsed.host_str() != target.host_str()
+            || parsed.port_or_known_default() != target.port_or_known_default()
+        {
+            return url.to_string();
+        }
+        if parsed.set_host(Some(sni)).is_err() {
+            return url.to_string();
+        }
+        parsed.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_sni_without_override_is_noop() {
+        let tls = LocalTlsOptions::default();
+        assert_eq!(
+            tls.apply_sni("https://localhost:8443/a", "https://localhost:8443"),
+            "https://localhost:8443/a"
+        );
+    }
+
+    #[test]
+    fn test_apply_sni_rewrites_default_target_only() {
+        let tls = LocalTlsOptions {
+            sni: Some("app.test".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            tls.apply_sni("https://localhost:8443/a?b=1", "https://localhost:8443"),
+            "https://app.test:8443/a?b=1"
+        );
+        assert_eq!(
+            tls.apply_sni("https://localhost:9443/a", "https://localhost:8443"),
+            "https://localhost:9443/a"
+        );
+        assert_eq!(
+            tls.apply_sni("http://localhost:8443/a", "https://localhost:8443"),
+            "http://localhost:8443/a"
+        );
+    }
+
+    #[test]
+    fn test_build_client_rejects_key_without_cert() {
+        let tls = LocalTlsOptions {
+            client_key: Some(PathBuf::from("key.pem")),
+            ..Default::default()
+        };
+        assert!(
+            tls.build_client("https://localhost:8443", Duration::from_secs(30))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_build_client_reports_missing_ca_file() {
+        let tls = LocalTlsOptions {
+            ca_file: Some(PathBuf::from("/nonexistent/ca.pem")),
+            ..Default::default()
+        };
+        let err = tls
+            .build_client("https://localhost:8443", Duration::from_secs(30))
+            .unwrap_err();
+        assert!(err.to_string().contains("CA file"));
+    }
+
+    #[test]
+    fn test_ws_connector_defaults_to_platform_tls() {
+        let tls = LocalTlsOptions {
+            sni: Some("app.test".to_string()),
+            ..Default::default()
+        };
+        assert!(tls.ws_connector().unwrap().is_none());
+
+        let insecure = LocalTlsOptions {
+            insecure: true,
+            ..Default::default()
+        };
+        assert!(insecure.ws_connector().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_build_client_insecure() {
+        let tls = LocalTlsOptions {
+            insecure: true,
+            ..Default::default()
+        };
+        assert!(
+            tls.build_client("https://localhost:8443", Duration::from_secs(30))
+                .is_ok()
+        );
+    }
+}
@@ -2,21 +2,33 @@ mod api;
 mod app;
 mod auth;
 mod config;
+mod credentials;
 mod errors;
+mod fault;
+mod headless;
+mod http;
+mod inspector;
+mod intercept;
+mod local_tls;
 mod logger;
+mod mock;
 mod models;
+mod project;
+mod routing;
+mod rules;
+mod sessions;
 mod syntax;
 mod tunnel;
 mod ui;
 mod updater;
 
-use anyhow::{Result, anyhow};
-use chrono::{Duration as ChronoDuration, Utc};
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use comfy_table::{ContentArrangement, Table, presets::UTF8_FULL_CONDENSED};
 use crossterm::{
     cursor::{MoveToColumn, Show},
-    event::{self, Event, KeyEventKind},
+    event::{self, Event, KeyCode, KeyEventKind},
     execute,
     style::Stylize,
     terminal::{
@@ -25,11 +37,13 @@ use crossterm::{
     },
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
+use std::net::SocketAddr;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::{sync::mpsc, time::sleep};
-use tracing::error;
+use tracing::{error, warn};
 
 use api::ApiClient;
 use app::{App, AppState};
@@ -48,6 +62,10 @@ struct Cli {
     #[arg(long, global = true)]
     json: bool,
 
+    /// Profile to use instead of the active one (also HOOKLISTENER_PROFILE)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
     /// Log level (trace, debug, info, warn, error)
     #[arg(long, default_value = "info", value_parser = validate_log_level)]
     log_level: String,
@@ -68,20 +86,66 @@ enum Commands {
         /// Start a new authentication even if a valid token already exists
         #[arg(long)]
         force: bool,
+        /// Read a long-lived API token from stdin instead of using the browser (for CI)
+        #[arg(long, conflicts_with = "force")]
+        with_token: bool,
+        /// Do not open the verification page in a browser; show a QR code instead
+        #[arg(long, conflicts_with = "with_token")]
+        no_browser: bool,
+        /// Give up waiting for approval after this many seconds
+        #[arg(long, value_name = "SECONDS", conflicts_with = "with_token")]
+        timeout: Option<u64>,
     },
     /// Start WebSocket tunnel to forward webhooks to local server
     Listen {
-        /// Debug endpoint slug to listen to
-        endpoint: String,
+        /// Debug endpoint slug to listen to (defaults to `endpoint` in .hooklistener.toml)
+        endpoint: Option<String>,
 
-        /// Local URL to forward requests to
-        #[arg(short, long, default_value = "http://localhost:3000")]
-        target: String,
+        /// Local URL to forward requests to [default: http://localhost:3000]
+        #[arg(short, long)]
+        target: Option<String>,
 
-        /// WebSocket server URL (defaults to production)
+        /// WebSocket server URL (defaults to the profile's, then production)
         #[arg(long)]
         ws_url: Option<String>,
+
+        /// Maximum number of webhooks forwarded to the target concurrently
+        #[arg(
+            long,
+            default_value_t = tunnel::DEFAULT_MAX_IN_FLIGHT,
+            value_parser = parse_max_in_flight
+        )]
+        max_in_flight: usize,
+
+        /// Hold matching webhooks for editing before forwarding (repeatable):
+        /// `[METHOD ]PATH_GLOB[,header=NAME[:VALUE]]`
+        #[arg(long = "intercept", value_name = "RULE")]
+        intercepts: Vec<String>,
+
+        /// Inject a fault into forwarding (repeatable): `latency=DURATION`, `error[=STATUS]`,
+        /// `reset` or `timeout`, then `[,percent=N][,method=M][,path=GLOB][,header=H]`
+        #[arg(long = "fault", value_name = "FAULT")]
+        faults: Vec<String>,
+
+        /// Serve a local web inspector for captured requests (default 127.0.0.1:4040)
+        #[arg(
+            long,
+            value_name = "ADDR",
+            num_args = 0..=1,
+            default_missing_value = inspector::DEFAULT_INSPECT_ADDR
+        )]
+        inspect: Option<SocketAddr>,
+
+        /// Print events as log lines (or NDJSON with --json) instead of the interactive UI
+        #[arg(long, conflicts_with_all = ["intercepts", "inspect"])]
+        no_tui: bool,
+
+        /// With --no-tui, exit with code 7 when a request finds nothing listening on the target
+        #[arg(long, requires = "no_tui")]
+        fail_on_unreachable: bool,
     },
+    /// Check the local setup for common problems
+    Doctor,
     /// Generate a diagnostic bundle for support
     Diagnostics {
         /// Output directory for the diagnostic bundle
@@ -101,11 +165,23 @@ enum Commands {
     },
     /// Sign out and clear locally stored token
     Logout,
+    /// Show who you are logged in as (same as `auth status`)
+    Whoami,
+    /// Inspect the credentials in use
+    Auth {
+        #[command(subcommand)]
+        action: AuthAction,
+    },
     /// Organization helpers
     Org {
         #[command(subcommand)]
         action: OrgAction,
     },
+    /// Manage named profiles for accounts and self-hosted instances
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
     /// Debug endpoint helpers
     Endpoint {
         #[command(subcommand)]
@@ -141,13 +217,37 @@ enum Commands {
     Update,
     /// Start HTTP tunnel to forward requests to local server
     Tunnel {
-        /// Local port to forward requests to
-        #[arg(short, long, default_value = "3000")]
-        port: u16,
+        /// Local port to forward requests to [default: 3000]
+        #[arg(short, long)]
+        port: Option<u16>,
+
+        /// Local host to forward to [default: localhost]
+        #[arg(long)]
+        host: Option<String>,
+
+        /// Full local target URL, e.g. https://localhost:8443 (overrides --host/--port)
+        #[arg(short, long, conflicts_with_all = ["port", "host"])]
+        target: Option<String>,
+
+        /// PEM CA bundle to trust for HTTPS local targets
+        #[arg(long, value_name = "PATH")]
+        ca_file: Option<PathBuf>,
+
+        /// Skip certificate verification for HTTPS local targets (self-signed dev certs)
+        #[arg(long)]
+        insecure: bool,
+
+        /// PEM client certificate to present to mTLS-protected local targets
+        #[arg(long, value_name = "PATH")]
+        client_cert: Option<PathBuf>,
 
-        /// Local host to forward to
-        #[arg(long, default_value = "localhost")]
-        host: String,
+        /// PEM private key for --client-cert (if not bundled in the certificate file)
+        #[arg(long, value_name = "PATH", requires = "client_cert")]
+        client_key: Option<PathBuf>,
+
+        /// TLS server name (SNI) to use when connecting to the local target
+        #[arg(long, value_name = "NAME")]
+        sni: Option<String>,
 
         /// Organization ID (optional, uses default)
         #[arg(short, long)]
@@ -156,7 +256,89 @@ enum Commands {
         /// Static tunnel slug (paid plans only, creates persistent subdomain)
         #[arg(short, long)]
         slug: Option<String>,
+
+        /// Route requests to another local target (repeatable, first match wins):
+        /// `[METHOD ]PREFIX=TARGET[,strip][,header=NAME[:VALUE]]`
+        #[arg(long = "route", value_name = "RULE")]
+        routes: Vec<String>,
+
+        /// JSON file with routing rules, applied after any --route flags
+        #[arg(long, value_name = "PATH")]
+        routes_file: Option<PathBuf>,
+
+        /// Maximum number of requests forwarded to local targets concurrently
+        #[arg(
+            long,
+            default_value_t = tunnel::DEFAULT_MAX_IN_FLIGHT,
+            value_parser = parse_max_in_flight
+        )]
+        max_in_flight: usize,
+
+        /// Relay large, unknown-length and event-stream bodies in chunks (SSE, downloads)
+        #[arg(long)]
+        stream: bool,
+
+        /// Hold matching requests for editing before forwarding (repeatable):
+        /// `[METHOD ]PATH_GLOB[,header=NAME[:VALUE]]`
+        #[arg(long = "intercept", value_name = "RULE")]
+        intercepts: Vec<String>,
+
+        /// YAML or JSON file with mock responses, used always or when the target is down
+        #[arg(long, value_name = "PATH")]
+        mocks: Option<PathBuf>,
+
+        /// Inject a fault into forwarding (repeatable): `latency=DURATION`, `error[=STATUS]`,
+        /// `reset` or `timeout`, then `[,percent=N][,method=M][,path=GLOB][,header=H]`
+        #[arg(long = "fault", value_name = "FAULT")]
+        faults: Vec<String>,
+
+        /// Serve a local web inspector for captured requests (default 127.0.0.1:4040)
+        #[arg(
+            long,
+            value_name = "ADDR",
+            num_args = 0..=1,
+            default_missing_value = inspector::DEFAULT_INSPECT_ADDR
+        )]
+        inspect: Option<SocketAddr>,
+
+        /// Print events as log lines (or NDJSON with --json) instead of the interactive UI
+        #[arg(long, conflicts_with_all = ["intercepts", "inspect"])]
+        no_tui: bool,
+
+        /// With --no-tui, exit with code 7 when a request finds nothing listening on the target
+        #[arg(long, requires = "no_tui")]
+        fail_on_unreachable: bool,
     },
+    /// Start every tunnel and listener declared under [up] in the project file, one tab each
+    Up,
+}
+
+/// How a command depends on the project file.
+enum ProjectUsage {
+    /// Settings come from it, so an invalid file is an error
+    Required,
+    /// It is only displayed, so an invalid file is a warning
+    Shown,
+    /// It is not read at all
+    Unused,
+}
+
+impl Commands {
+    fn project_usage(&self) -> ProjectUsage {
+        match self {
+            Commands::Listen { .. }
+            | Commands::Tunnel { .. }
+            | Commands::Up
+            | Commands::Endpoint { .. }
+            | Commands::StaticTunnel { .. }
+            | Commands::Share { .. }
+            | Commands::Monitor { .. } => ProjectUsage::Required,
+            Commands::Config {
+                action: ConfigAction::Show,
+            } => ProjectUsage::Shown,
+            _ => ProjectUsage::Unused,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -181,6 +363,16 @@ enum ConfigAction {
     },
 }
 
+#[derive(Subcommand)]
+enum AuthAction {
+    /// Show the account, organization and credentials in use; exits 1 if not logged in
+    Status,
+    /// Encrypt the credentials file with a passphrase (also HOOKLISTENER_PASSPHRASE)
+    Encrypt,
+    /// Store the credentials file unencrypted again
+    Decrypt,
+}
+
 #[derive(Subcommand)]
 enum OrgAction {
     /// List organizations available to your account
@@ -194,6 +386,33 @@ enum OrgAction {
     Clear,
 }
 
+#[derive(Subcommand)]
+enum ProfileAction {
+    /// List profiles, marking the active one
+    List,
+    /// Add a profile; run `hooklistener --profile NAME login` to sign in to it
+    Add {
+        /// Profile name
+        name: String,
+        /// API base URL [default: https://app.hooklistener.com]
+        #[arg(long)]
+        api_url: Option<String>,
+        /// WebSocket base URL [default: derived from --api-url, or wss://api.hooklistener.com]
+        #[arg(long)]
+        ws_url: Option<String>,
+    },
+    /// Make a profile the default for later commands
+    Use {
+        /// Profile name
+        name: String,
+    },
+    /// Remove a profile and its stored tokens
+    Remove {
+        /// Profile name
+        name: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum EndpointAction {
     /// Create a debug endpoint
@@ -239,6 +458,12 @@ enum EndpointAction {
         /// Page size
         #[arg(long, default_value = "50")]
         page_size: u32,
+        /// Fetch every page from --page onwards instead of a single page
+        #[arg(long)]
+        all: bool,
+        /// Stop after N items, fetching as many pages as needed
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
         /// Organization ID override (falls back to configured default)
         #[arg(long)]
         org: Option<String>,
@@ -269,8 +494,8 @@ enum EndpointAction {
         endpoint_id: String,
         /// Debug request ID
         request_id: String,
-        /// Target URL to replay to
-        target_url: String,
+        /// Target URL, or a target name from .hooklistener.toml (defaults to its `[replay]` default)
+        target_url: Option<String>,
         /// Optional HTTP method override (GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS)
         #[arg(long)]
         method: Option<String>,
@@ -290,6 +515,12 @@ enum EndpointAction {
         /// Page size
         #[arg(long, default_value = "50")]
         page_size: u32,
+        /// Fetch every page from --page onwards instead of a single page
+        #[arg(long)]
+        all: bool,
+        /// Stop after N items, fetching as many pages as needed
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
         /// Organization ID override (falls back to configured default)
         #[arg(long)]
         org: Option<String>,
@@ -359,6 +590,12 @@ enum AnonAction {
         /// Page size
         #[arg(long, default_value = "50")]
         page_size: u32,
+        /// Fetch every page from --page onwards instead of a single page
+        #[arg(long)]
+        all: bool,
+        /// Stop after N items, fetching as many pages as needed
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
     },
     /// Show a single captured event
     Event {
@@ -508,6 +745,12 @@ enum MonitorAction {
         /// Page size
         #[arg(long, default_value = "50")]
         page_size: u32,
+        /// Fetch every page from --page onwards instead of a single page
+        #[arg(long)]
+        all: bool,
+        /// Stop after N items, fetching as many pages as needed
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
         /// Organization ID override (falls back to configured default)
         #[arg(long)]
         org: Option<String>,
@@ -524,6 +767,41 @@ fn validate_log_level(s: &str) -> Result<String, String> {
     }
 }
 
+fn parse_max_in_flight(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err("must be a positive integer".to_string()),
+    }
+}
+
+fn parse_interceptor(specs: &[String]) -> Result<intercept::Interceptor> {
+    let rules = specs
+        .iter()
+        .map(|spec| intercept::InterceptRule::parse(spec))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(intercept::Interceptor::new(rules))
+}
+
+fn parse_faults(specs: &[String]) -> Result<fault::FaultInjector> {
+    let rules = specs
+        .iter()
+        .map(|spec| fault::FaultRule::parse(spec))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(fault::FaultInjector::new(rules))
+}
+
+/// Start the local web inspector, returning it with the URL it is served on.
+async fn start_inspector(
+    addr: Option<SocketAddr>,
+) -> Result<Option<(Arc<inspector::Inspector>, String)>> {
+    let Some(addr) = addr else {
+        return Ok(None);
+    };
+    let inspector = inspector::Inspector::new();
+    let local_addr = inspector.start(addr).await?;
+    Ok(Some((inspector, format!("http://{}", local_addr))))
+}
+
 fn normalize_http_method(method: Option<String>) -> Result<Option<String>> {
     let Some(method) = method else {
         return Ok(None);
@@ -536,10 +814,11 @@ fn normalize_http_method(method: Option<String>) -> Result<Option<String>> {
     );
 
     if !valid {
-        return Err(anyhow!(
+        return Err(errors::CliError::InvalidInput(format!(
             "Invalid HTTP method '{}'. Valid values: GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS",
             method
-        ));
+        ))
+        .into());
     }
 
     Ok(Some(normalized))
@@ -550,17 +829,61 @@ pub(crate) fn print_json<T: serde::Serialize>(value: &T) -> Result<()> {
     Ok(())
 }
 
+/// Drain a paginated stream, stopping after `limit` items. With `--json` every
+/// item is written as one NDJSON line as soon as its page arrives and nothing is
+/// collected; otherwise the items are returned for table output.
+async fn drain_pages<T: serde::Serialize>(
+    items: impl futures_util::Stream<Item = Result<T>>,
+    limit: Option<usize>,
+    json: bool,
+) -> Result<Vec<T>> {
+    use futures_util::{StreamExt, TryStreamExt};
+
+    let mut items = std::pin::pin!(items.take(limit.unwrap_or(usize::MAX)));
+    let mut collected = Vec::new();
+    while let Some(item) = items.try_next().await? {
+        if !json {
+            collected.push(item);
+            continue;
+        }
+        let mut stdout = io::stdout().lock();
+        let written =
+            writeln!(stdout, "{}", serde_json::to_string(&item)?).and_then(|_| stdout.flush());
+        match written {
+            // The reader went away (e.g. `| head`), which is not a failure.
+            Err(err) if err.kind() == io::ErrorKind::BrokenPipe => break,
+            result => result?,
+        }
+    }
+    Ok(collected)
+}
+
+/// Print the footer for a listing that spanned several pages.
+fn print_fetched(count: usize) {
+    println!("{}", format!("Fetched {} items", count).dim());
+}
+
 const SESSION_TOKEN_VALIDITY_DAYS: i64 = 60;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() {
+    let cli = Cli::parse();
+    let json = cli.json;
+    if let Err(err) = run(cli).await {
+        error!(error = %err, "Command failed");
+        exit_with_error(&err, json);
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
     let Cli {
         command,
         json,
+        profile,
         log_level,
         log_dir,
         log_stdout,
-    } = Cli::parse();
+    } = cli;
 
     let Some(command) = command else {
         Cli::command().print_help()?;
@@ -568,6 +891,26 @@ async fn main() -> Result<()> {
         return Ok(());
     };
 
+    if let Some(name) = profile.or_else(|| std::env::var("HOOKLISTENER_PROFILE").ok()) {
+        config::select_profile(name);
+        if !matches!(command, Commands::Profile { .. }) {
+            // Fail early on an unknown profile
+            config::Config::load()?;
+        }
+    }
+
+    // Only commands that read `.hooklistener.toml` look for it, so a broken file
+    // cannot stop `login`, `doctor` or `update`.
+    let project = match command.project_usage() {
+        ProjectUsage::Required => project::Project::discover()?,
+        ProjectUsage::Shown => project::Project::discover().unwrap_or_else(|err| {
+            warn!(error = %err, "Ignoring invalid project file");
+            eprintln!("{} {:#}", "Warning:".yellow().bold(), err);
+            project::Project::default()
+        }),
+        ProjectUsage::Unused => project::Project::default(),
+    };
+
     // Spawn background version check for non-interactive, non-update commands
     let update_handle =
         if !json && !matches!(command, Commands::Update | Commands::Completions { .. }) {
@@ -579,7 +922,12 @@ async fn main() -> Result<()> {
         };
 
     match command {
-        Commands::Login { force } => {
+        Commands::Login {
+            force,
+            with_token,
+            no_browser,
+            timeout,
+        } => {
             let log_config = LogConfig {
                 level: log_level.clone(),
                 output_to_stdout: log_stdout,
@@ -589,17 +937,42 @@ async fn main() -> Result<()> {
                 ..Default::default()
             };
             let _logger = Logger::new(log_config)?;
-            run_login_flow(force).await?;
+            if with_token {
+                run_token_login(json).await?;
+            } else {
+                run_login_flow(force, !no_browser, timeout.map(Duration::from_secs)).await?;
+            }
         }
         Commands::Listen {
             endpoint,
             target,
             ws_url,
+            max_in_flight,
+            intercepts,
+            faults,
+            inspect,
+            no_tui,
+            fail_on_unreachable,
         } => {
+            let endpoint = endpoint
+                .or_else(|| project.config.listen.endpoint.clone())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No endpoint given. Pass one, or set `endpoint` under [listen] in {}.",
+                        project::PROJECT_FILE_NAME
+                    )
+                })?;
+            let target = target
+                .or_else(|| project.config.listen.target.clone())
+                .unwrap_or_else(|| project::DEFAULT_LISTEN_TARGET.to_string());
+            let interceptor = parse_interceptor(&intercepts)?;
+            let faults = parse_faults(&faults)?;
+
             // Initialize logging for tunnel
             let log_config = LogConfig {
                 level: log_level.clone(),
-                output_to_stdout: false, // Disable stdout logging for TUI
+                // Disable stdout logging for TUI
+                output_to_stdout: no_tui && log_stdout,
                 directory: log_dir
                     .clone()
                     .unwrap_or_else(|| LogConfig::default().directory),
@@ -608,28 +981,8 @@ async fn main() -> Result<()> {
             let _logger = Logger::new(log_config)?;
 
             // Load config for auth token
-            let config = config::Config::load()?;
-
-            // Check if authenticated
-            if !config.is_token_valid() {
-                eprintln!(
-                    "❌ Not authenticated. Please run 'hooklistener login' to authenticate first."
-                );
-                std::process::exit(1);
-            }
-
-            let access_token = config
-                .access_token
-                .ok_or_else(|| anyhow::anyhow!("No access token found"))?;
-
-            // Setup TUI for listen command
-            let mut terminal = setup_terminal()?;
-            let mut app = App::new()?;
-
-            // Set app state to listening
-            app.state = AppState::Listening;
-            app.listening_endpoint = endpoint.clone();
-            app.listening_target = target.clone();
+            let mut config = config::Config::load()?;
+            let access_token = ensure_valid_token(&mut config).await?;
 
             // Create channel for tunnel events
             let (event_tx, event_rx) = mpsc::channel(100);
@@ -641,24 +994,82 @@ async fn main() -> Result<()> {
                 target.clone(),
                 ws_url,
                 event_tx,
-            );
+            )?
+            .with_max_in_flight(max_in_flight)
+            .with_interceptor(interceptor.clone())
+            .with_faults(faults)
+            .with_token_refresher(token_refresher());
+            let tunnel_client = Arc::new(tunnel_client);
+
+            if no_tui {
+                tokio::spawn(run_tunnel_client_connection(tunnel_client));
+                run_headless(
+                    event_rx,
+                    json,
+                    project.config.redact.clone(),
+                    fail_on_unreachable,
+                )
+                .await;
+                return Ok(());
+            }
+            let reconnect_tx = spawn_tunnel_client_manager(tunnel_client);
 
-            tokio::spawn(async move {
-                if let Err(e) = tunnel_client
-                    .connect_with_reconnect(tunnel::ReconnectConfig::default())
-                    .await
-                {
-                    error!("Tunnel client error: {}", e);
-                }
-            });
+            let inspector = start_inspector(inspect).await?;
+
+            // Setup TUI for listen command
+            let mut terminal = setup_terminal()?;
+            let mut app = App::new()?;
+
+            // Set app state to listening
+            app.state = AppState::Listening;
+            apply_project_defaults(&mut app, &project.config);
+            app.listening_endpoint = endpoint.clone();
+            app.listening_target = target;
+            if interceptor.has_rules() {
+                app.interceptor = Some(interceptor);
+            }
+            if let Some((inspector, url)) = inspector {
+                app.inspector = Some(inspector);
+                app.inspector_url = Some(url);
+            }
 
-            let res = run_app(&mut terminal, &mut app, event_rx, None).await;
+            let mut sessions = [Session::new(endpoint, app, event_rx, Some(reconnect_tx))];
+            let res = run_app(&mut terminal, &mut sessions).await;
 
             restore_terminal(&mut terminal)?;
 
             if let Err(err) = res {
                 error!(error = %err, "Application terminated with error");
-                display_error(&err);
+                return Err(err);
+            }
+        }
+        Commands::Doctor => {
+            let checks = run_doctor_checks();
+            let failed = checks
+                .iter()
+                .any(|check| check.status == CheckStatus::Error);
+            if json {
+                print_json(&serde_json::json!({
+                    "ok": !failed,
+                    "checks": checks
+                }))?;
+            } else {
+                for check in &checks {
+                    let symbol = match check.status {
+                        CheckStatus::Ok => "✅",
+                        CheckStatus::Warning => "⚠️ ",
+                        CheckStatus::Error => "❌",
+                    };
+                    println!(
+                        "{} {} {}",
+                        symbol,
+                        format!("{}:", check.name).bold(),
+                        check.detail
+                    );
+                }
+            }
+            if failed {
+                std::process::exit(1);
             }
         }
         Commands::Diagnostics { output } => {
@@ -708,57 +1119,95 @@ async fn main() -> Result<()> {
             ConfigAction::Show => {
                 let config = config::Config::load()?;
                 let config_path = config::Config::config_path()?;
-                if json {
-                    let token_status = if config.access_token.is_none() {
-                        "none"
-                    } else if config.is_token_valid() {
-                        "valid"
-                    } else {
+                let source = config.credential_source();
+                // Only login sessions expire locally; API tokens are checked by the server
+                let token_status = match source {
+                    None => "none",
+                    Some(config::CredentialSource::Session) if !config.is_token_valid() => {
                         "expired"
-                    };
-
+                    }
+                    Some(_) => "valid",
+                };
+                if json {
+                    let settings: serde_json::Map<String, serde_json::Value> = project
+                        .settings(&config)
+                        .into_iter()
+                        .map(|setting| {
+                            (
+                                setting.key.to_string(),
+                                serde_json::json!({
+                                    "value": setting.value,
+                                    "source": setting.source
+                                }),
+                            )
+                        })
+                        .collect();
                     print_json(&serde_json::json!({
                         "config_path": config_path.display().to_string(),
+                        "profile": config.profile_name(),
+                        "api_url": config::api_url()?,
+                        "ws_url": config::ws_url()?,
+                        "project_path": project.path.as_ref().map(|p| p.display().to_string()),
                         "token": {
-                            "present": config.access_token.is_some(),
+                            "present": source.is_some(),
+                            "source": source.map(config::CredentialSource::label),
                             "status": token_status
                         },
-                        "organization_id": config.selected_organization_id
+                        "organization_id": config.selected_organization_id,
+                        "settings": settings
                     }))?;
                 } else {
                     println!("{} {}", "Config file:".dim(), config_path.display());
                     println!();
-                    match &config.access_token {
-                        Some(token) => {
+                    println!("  {} {}", "Profile:".bold(), config.profile_name());
+                    println!("  {} {}", "API URL:".bold(), config::api_url()?);
+                    println!("  {} {}", "WebSocket URL:".bold(), config::ws_url()?);
+                    match source {
+                        Some(source) => {
+                            let token = config.active_token().unwrap_or_default();
                             let truncated = if token.len() > 8 {
                                 format!("{}...", &token[..8])
                             } else {
-                                token.clone()
+                                token
                             };
-                            if config.is_token_valid() {
-                                println!(
-                                    "  {} {} {}",
-                                    "Token:".bold(),
-                                    truncated,
-                                    "(valid)".green()
-                                );
+                            let status = if token_status == "valid" {
+                                "(valid)".green()
                             } else {
-                                println!(
-                                    "  {} {} {}",
-                                    "Token:".bold(),
-                                    truncated,
-                                    "(expired)".red()
-                                );
-                            }
+                                "(expired)".red()
+                            };
+                            println!(
+                                "  {} {} {} {}",
+                                "Token:".bold(),
+                                truncated,
+                                status,
+                                format!("from {}", source.label()).dim()
+                            );
                         }
                         None => println!("  {} {}", "Token:".bold(), "(none)".dim()),
                     }
-                    match &config.selected_organization_id {
-                        Some(org_id) => {
-                            println!("  {} {}", "Organization:".bold(), org_id)
-                        }
-                        None => {
-                            println!("  {} {}", "Organization:".bold(), "(none)".dim())
+                    println!();
+                    match &project.path {
+                        Some(path) => println!("{} {}", "Project file:".dim(), path.display()),
+                        None => println!(
+                            "{} {}",
+                            "Project file:".dim(),
+                            format!("(no {} found)", project::PROJECT_FILE_NAME).dim()
+                        ),
+                    }
+                    println!();
+                    for setting in project.settings(&config) {
+                        match (&setting.value, setting.source) {
+                            (Some(value), Some(source)) => println!(
+                                "  {} {} {}",
+                                format!("{}:", setting.key).bold(),
+                                value,
+                                format!("({})", source.label()).dim()
+                            ),
+                            _ => println!(
+                                "  {} {}",
+                                format!("{}:", setting.key).bold(),
+                                "(none)".dim()
+                            ),
                         }
                     }
                 }
@@ -797,17 +1246,20 @@ async fn main() -> Result<()> {
                     }
                 }
                 _ => {
-                    eprintln!(
+                    return Err(errors::CliError::InvalidInput(format!(
                         "Unknown config key: {}. Available keys: selected_organization_id",
                         key
-                    );
-                    std::process::exit(1);
+                    ))
+                    .into());
                 }
             },
         },
         Commands::Logout => {
             let mut config = config::Config::load()?;
-            if config.access_token.is_none() && config.token_expires_at.is_none() {
+            if config.access_token.is_none()
+                && config.token_expires_at.is_none()
+                && config.api_token.is_none()
+            {
                 if json {
                     print_json(&serde_json::json!({
                         "status": "already_logged_out"
@@ -831,6 +1283,40 @@ async fn main() -> Result<()> {
                 }
             }
         }
+        Commands::Whoami => run_auth_status(json).await?,
+        Commands::Auth { action } => match action {
+            AuthAction::Status => run_auth_status(json).await?,
+            AuthAction::Encrypt => {
+                let mut config = config::Config::load_all()?;
+                if config.credentials_encrypted() {
+                    return Err(anyhow!("The credentials file is already encrypted."));
+                }
+                config.set_credentials_passphrase(Some(read_new_passphrase()?));
+                config.save()?;
+                if json {
+                    print_json(&serde_json::json!({ "status": "ok", "encrypted": true }))?;
+                } else {
+                    println!("✅ Credentials encrypted.");
+                    println!(
+                        "   Commands will ask for the passphrase, or read {}.",
+                        credentials::PASSPHRASE_ENV_VAR.bold()
+                    );
+                }
+            }
+            AuthAction::Decrypt => {
+                let mut config = config::Config::load_all()?;
+                if !config.credentials_encrypted() {
+                    return Err(anyhow!("The credentials file is not encrypted."));
+                }
+                config.set_credentials_passphrase(None);
+                config.save()?;
+                if json {
+                    print_json(&serde_json::json!({ "status": "ok", "encrypted": false }))?;
+                } else {
+                    println!("✅ Credentials decrypted.");
+                }
+            }
+        },
         Commands::Org { action } => match action {
             OrgAction::List => {
                 let mut config = config::Config::load()?;
@@ -889,10 +1375,96 @@ async fn main() -> Result<()> {
                 }
             }
         },
+        Commands::Profile { action } => match action {
+            ProfileAction::List => {
+                let config = config::Config::load_all()?;
+                let active = config.active_profile_name().to_string();
+                let profiles = config.list_profiles();
+                if json {
+                    let profiles: Vec<serde_json::Value> = profiles
+                        .iter()
+                        .map(|(name, profile)| {
+                            serde_json::json!({
+                                "name": name,
+                                "active": *name == active,
+                                "api_url": profile.api_url.as_deref().unwrap_or(config::DEFAULT_API_URL),
+                                "ws_url": config::profile_ws_url_or_default(
+                                    profile.api_url.as_deref(),
+                                    profile.ws_url.as_deref(),
+                                ),
+                                "logged_in": profile.credential_source().is_some(),
+                                "organization_id": profile.selected_organization_id
+                            })
+                        })
+                        .collect();
+                    print_json(&serde_json::json!({
+                        "active_profile": active,
+                        "profiles": profiles
+                    }))?;
+                } else {
+                    print_profiles(&profiles, &active);
+                }
+            }
+            ProfileAction::Add {
+                name,
+                api_url,
+                ws_url,
+            } => {
+                let mut config = config::Config::load_all()?;
+                config.add_profile(
+                    &name,
+                    config::Profile {
+                        api_url,
+                        ws_url,
+                        ..config::Profile::default()
+                    },
+                )?;
+                config.save()?;
+                if json {
+                    print_json(&serde_json::json!({
+                        "status": "ok",
+                        "profile": name
+                    }))?;
+                } else {
+                    println!("✅ Added profile {}", name.as_str().bold());
+                    println!(
+                        "   Sign in with {}",
+                        format!("hooklistener --profile {} login", name).bold()
+                    );
+                }
+            }
+            ProfileAction::Use { name } => {
+                let mut config = config::Config::load_all()?;
+                config.use_profile(&name)?;
+                config.save()?;
+                if json {
+                    print_json(&serde_json::json!({
+                        "status": "ok",
+                        "active_profile": name
+                    }))?;
+                } else {
+                    println!("✅ Switched to profile {}", name.bold());
+                }
+            }
+            ProfileAction::Remove { name } => {
+                let mut config = config::Config::load_all()?;
+                config.remove_profile(&name)?;
+                config.save()?;
+                if json {
+                    print_json(&serde_json::json!({
+                        "status": "ok",
+                        "removed": name,
+                        "active_profile": config.active_profile_name()
+                    }))?;
+                } else {
+                    println!("✅ Removed profile {}", name.bold());
+                }
+            }
+        },
         Commands::Endpoint { action } => match action {
             EndpointAction::Create { name, slug, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let endpoint = client.create_endpoint(&name, slug.as_deref()).await?;
@@ -909,7 +1481,7 @@ async fn main() -> Result<()> {
             }
             EndpointAction::List { org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let endpoints = client.list_endpoints().await?;
@@ -925,7 +1497,7 @@ async fn main() -> Result<()> {
             }
             EndpointAction::Show { endpoint_id, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let endpoint = client.get_endpoint(&endpoint_id).await?;
@@ -941,7 +1513,7 @@ async fn main() -> Result<()> {
             }
             EndpointAction::Delete { endpoint_id, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 client.delete_endpoint(&endpoint_id).await?;
@@ -963,25 +1535,42 @@ async fn main() -> Result<()> {
                 endpoint_id,
                 page,
                 page_size,
+                all,
+                limit,
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
-                let requests = client
-                    .list_endpoint_requests(&endpoint_id, page, page_size)
+                if all || limit.is_some() {
+                    let requests = drain_pages(
+                        client.stream_endpoint_requests(&endpoint_id, page, page_size),
+                        limit,
+                        json,
+                    )
                     .await?;
-                if json {
-                    print_json(&serde_json::json!({
-                        "organization_id": organization_id,
-                        "endpoint_id": endpoint_id,
-                        "requests": requests
-                    }))?;
+                    if !json {
+                        print_context("Organization:", &organization_id);
+                        print_context("Endpoint:", &endpoint_id);
+                        print_endpoint_requests(&requests, None);
+                        print_fetched(requests.len());
+                    }
                 } else {
-                    print_context("Organization:", &organization_id);
-                    print_context("Endpoint:", &endpoint_id);
-                    print_endpoint_requests(&requests);
+                    let requests = client
+                        .list_endpoint_requests(&endpoint_id, page, page_size)
+                        .await?;
+                    if json {
+                        print_json(&serde_json::json!({
+                            "organization_id": organization_id,
+                            "endpoint_id": endpoint_id,
+                            "requests": requests
+                        }))?;
+                    } else {
+                        print_context("Organization:", &organization_id);
+                        print_context("Endpoint:", &endpoint_id);
+                        print_endpoint_requests(&requests.data, Some(&requests.pagination));
+                    }
                 }
             }
             EndpointAction::Request {
@@ -990,7 +1579,7 @@ async fn main() -> Result<()> {
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let request = client
@@ -1014,7 +1603,7 @@ async fn main() -> Result<()> {
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 client
@@ -1046,8 +1635,18 @@ async fn main() -> Result<()> {
                 method,
                 org,
             } => {
+                let target_url = project
+                    .config
+                    .replay
+                    .resolve(target_url.as_deref())
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "No target URL given. Pass one, or set `default` under [replay] in {}.",
+                            project::PROJECT_FILE_NAME
+                        )
+                    })?;
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let normalized_method = normalize_http_method(method)?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
@@ -1083,32 +1682,55 @@ async fn main() -> Result<()> {
                 request_id,
                 page,
                 page_size,
+                all,
+                limit,
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
-                let forwards = client
-                    .list_endpoint_request_forwards(&endpoint_id, &request_id, page, page_size)
+                if all || limit.is_some() {
+                    let forwards = drain_pages(
+                        client.stream_endpoint_request_forwards(
+                            &endpoint_id,
+                            &request_id,
+                            page,
+                            page_size,
+                        ),
+                        limit,
+                        json,
+                    )
                     .await?;
-                if json {
-                    print_json(&serde_json::json!({
-                        "organization_id": organization_id,
-                        "endpoint_id": endpoint_id,
-                        "request_id": request_id,
-                        "forwards": forwards
-                    }))?;
+                    if !json {
+                        print_context("Organization:", &organization_id);
+                        print_context("Endpoint:", &endpoint_id);
+                        print_context("Request:", &request_id);
+                        print_endpoint_request_forwards(&forwards, None);
+                        print_fetched(forwards.len());
+                    }
                 } else {
-                    print_context("Organization:", &organization_id);
-                    print_context("Endpoint:", &endpoint_id);
-                    print_context("Request:", &request_id);
-                    print_endpoint_request_forwards(&forwards);
+                    let forwards = client
+                        .list_endpoint_request_forwards(&endpoint_id, &request_id, page, page_size)
+                        .await?;
+                    if json {
+                        print_json(&serde_json::json!({
+                            "organization_id": organization_id,
+                            "endpoint_id": endpoint_id,
+                            "request_id": request_id,
+                            "forwards": forwards
+                        }))?;
+                    } else {
+                        print_context("Organization:", &organization_id);
+                        print_context("Endpoint:", &endpoint_id);
+                        print_context("Request:", &request_id);
+                        print_endpoint_request_forwards(&forwards.data, Some(&forwards.pagination));
+                    }
                 }
             }
             EndpointAction::Forward { forward_id, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let forward = client.get_forward(&forward_id).await?;
@@ -1126,7 +1748,7 @@ async fn main() -> Result<()> {
         Commands::StaticTunnel { action } => match action {
             StaticTunnelAction::List { org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let tunnels = client.list_static_tunnels(&organization_id).await?;
@@ -1142,7 +1764,7 @@ async fn main() -> Result<()> {
             }
             StaticTunnelAction::Create { slug, name, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let created = client
@@ -1166,7 +1788,7 @@ async fn main() -> Result<()> {
             }
             StaticTunnelAction::Delete { slug_id, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let response = client
@@ -1245,19 +1867,35 @@ async fn main() -> Result<()> {
                 token,
                 page,
                 page_size,
+                all,
+                limit,
             } => {
                 let client = ApiClient::with_organization(token, None)?;
-                let response = client
-                    .list_anon_events(&endpoint_id, page, page_size)
+                if all || limit.is_some() {
+                    let events = drain_pages(
+                        client.stream_anon_events(&endpoint_id, page, page_size),
+                        limit,
+                        json,
+                    )
                     .await?;
-                if json {
-                    print_json(&serde_json::json!({
-                        "endpoint_id": endpoint_id,
-                        "events": response
-                    }))?;
+                    if !json {
+                        print_context("Endpoint:", &endpoint_id);
+                        print_anon_events(&events, None);
+                        print_fetched(events.len());
+                    }
                 } else {
-                    print_context("Endpoint:", &endpoint_id);
-                    print_anon_events(&response);
+                    let response = client
+                        .list_anon_events(&endpoint_id, page, page_size)
+                        .await?;
+                    if json {
+                        print_json(&serde_json::json!({
+                            "endpoint_id": endpoint_id,
+                            "events": response
+                        }))?;
+                    } else {
+                        print_context("Endpoint:", &endpoint_id);
+                        print_anon_events(&response.data, Some(&response.pagination));
+                    }
                 }
             }
             AnonAction::Event {
@@ -1287,7 +1925,7 @@ async fn main() -> Result<()> {
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let shared = client
@@ -1329,7 +1967,7 @@ async fn main() -> Result<()> {
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let shares = client.list_shared_requests(&debug_request_id).await?;
@@ -1368,7 +2006,7 @@ async fn main() -> Result<()> {
             }
             ShareAction::Revoke { token, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let access_token = ensure_valid_token(&mut config).await?;
                 let client =
                     ApiClient::with_organization(access_token, Some(organization_id.clone()))?;
@@ -1402,7 +2040,7 @@ async fn main() -> Result<()> {
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
 
@@ -1436,7 +2074,7 @@ async fn main() -> Result<()> {
             }
             MonitorAction::List { org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let monitors = client.list_uptime_monitors().await?;
@@ -1452,7 +2090,7 @@ async fn main() -> Result<()> {
             }
             MonitorAction::Show { id, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 let monitor = client.get_uptime_monitor(&id).await?;
@@ -1478,7 +2116,7 @@ async fn main() -> Result<()> {
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
 
@@ -1527,7 +2165,7 @@ async fn main() -> Result<()> {
             }
             MonitorAction::Delete { id, org } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
                 client.delete_uptime_monitor(&id).await?;
@@ -1549,23 +2187,42 @@ async fn main() -> Result<()> {
                 id,
                 page,
                 page_size,
+                all,
+                limit,
                 org,
             } => {
                 let mut config = config::Config::load()?;
-                let organization_id = require_organization(org, &config)?;
+                let organization_id = require_organization(org, &project.config, &config)?;
                 let token = ensure_valid_token(&mut config).await?;
                 let client = ApiClient::with_organization(token, Some(organization_id.clone()))?;
-                let response = client.list_uptime_checks(&id, page, page_size).await?;
-                if json {
-                    print_json(&serde_json::json!({
-                        "organization_id": organization_id,
-                        "monitor_id": id,
-                        "checks": response
-                    }))?;
+                if all || limit.is_some() {
+                    let (stats, checks) = client
+                        .stream_uptime_checks_with_stats(&id, page, page_size)
+                        .await?;
+                    let checks = drain_pages(checks, limit, json).await?;
+                    if !json {
+                        print_context("Organization:", &organization_id);
+                        print_context("Monitor:", &id);
+                        print_uptime_checks(&checks, stats.as_ref(), None);
+                        print_fetched(checks.len());
+                    }
                 } else {
-                    print_context("Organization:", &organization_id);
-                    print_context("Monitor:", &id);
-                    print_uptime_checks(&response);
+                    let response = client.list_uptime_checks(&id, page, page_size).await?;
+                    if json {
+                        print_json(&serde_json::json!({
+                            "organization_id": organization_id,
+                            "monitor_id": id,
+                            "checks": response
+                        }))?;
+                    } else {
+                        print_context("Organization:", &organization_id);
+                        print_context("Monitor:", &id);
+                        print_uptime_checks(
+                            &response.data,
+                            response.stats.as_ref(),
+                            Some(&response.pagination),
+                        );
+                    }
                 }
             }
         },
@@ -1593,13 +2250,69 @@ async fn main() -> Result<()> {
         Commands::Tunnel {
             port,
             host,
+            target,
+            ca_file,
+            insecure,
+            client_cert,
+            client_key,
+            sni,
             org,
             slug,
+            routes,
+            routes_file,
+            max_in_flight,
+            stream,
+            intercepts,
+            mocks,
+            faults,
+            inspect,
+            no_tui,
+            fail_on_unreachable,
         } => {
+            let (host, port, target) = match target {
+                Some(target) => {
+                    let (host, port) = parse_local_target(&target)?;
+                    (host, port, target)
+                }
+                None => {
+                    let host = host
+                        .or_else(|| project.config.tunnel.host.clone())
+                        .unwrap_or_else(|| project::DEFAULT_TUNNEL_HOST.to_string());
+                    let port = port
+                        .or(project.config.tunnel.port)
+                        .unwrap_or(project::DEFAULT_TUNNEL_PORT);
+                    let target = format!("http://{}:{}", host, port);
+                    (host, port, target)
+                }
+            };
+            let slug = slug.or_else(|| project.config.tunnel.slug.clone());
+            let local_tls = local_tls::LocalTlsOptions {
+                ca_file,
+                insecure,
+                client_cert,
+                client_key,
+                sni,
+            };
+
+            let mut routes = routes
+                .iter()
+                .map(|spec| routing::Route::parse(spec))
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(path) = routes_file {
+                routes.extend(routing::RoutingTable::load_routes(&path)?);
+            }
+            let interceptor = parse_interceptor(&intercepts)?;
+            let mocks = match mocks {
+                Some(path) => mock::MockSet::load(&path)?,
+                None => mock::MockSet::default(),
+            };
+            let faults = parse_faults(&faults)?;
+
             // Initialize logging for tunnel
             let log_config = LogConfig {
                 level: log_level.clone(),
-                output_to_stdout: false, // Disable stdout logging for TUI
+                // Disable stdout logging for TUI
+                output_to_stdout: no_tui && log_stdout,
                 directory: log_dir
                     .clone()
                     .unwrap_or_else(|| LogConfig::default().directory),
@@ -1608,21 +2321,50 @@ async fn main() -> Result<()> {
             let _logger = Logger::new(log_config)?;
 
             // Load config for auth token
-            let config = config::Config::load()?;
+            let mut config = config::Config::load()?;
+            let access_token = ensure_valid_token(&mut config).await?;
 
-            // Check if authenticated
-            if !config.is_token_valid() {
-                eprintln!(
-                    "❌ Not authenticated. Please run 'hooklistener login' to authenticate first."
-                );
-                std::process::exit(1);
-            }
+            let selected_org = resolve_tunnel_org(org, &project.config, &config);
 
-            let selected_org = resolve_tunnel_org(org, &config);
+            let local_https = target.starts_with("https://");
+            let route_descriptions = routes.iter().map(routing::Route::describe).collect();
 
-            let access_token = config
-                .access_token
-                .ok_or_else(|| anyhow::anyhow!("No access token found"))?;
+            // Create channel for tunnel events
+            let (event_tx, event_rx) = mpsc::channel(100);
+
+            let forwarder = tunnel::TunnelForwarder::new(
+                access_token,
+                host.clone(),
+                port,
+                selected_org.clone(),
+                slug.clone(),
+                None,
+                event_tx,
+            )?
+            .with_token_refresher(token_refresher())
+            .with_target(target.clone())
+            .with_routes(routes)
+            .with_max_in_flight(max_in_flight)
+            .with_streaming(stream)
+            .with_interceptor(interceptor.clone())
+            .with_mocks(mocks)
+            .with_faults(faults)
+            .with_local_tls(local_tls)?;
+
+            if no_tui {
+                // No manual reconnects without the TUI, so skip the manager
+                tokio::spawn(run_tunnel_forwarder_connection(Arc::new(forwarder)));
+                run_headless(
+                    event_rx,
+                    json,
+                    project.config.redact.clone(),
+                    fail_on_unreachable,
+                )
+                .await;
+                return Ok(());
+            }
+
+            let inspector = start_inspector(inspect).await?;
 
             // Setup TUI for tunnel command
             let mut terminal = setup_terminal()?;
@@ -1630,32 +2372,133 @@ async fn main() -> Result<()> {
 
             // Set app state to tunneling
             app.state = AppState::Tunneling;
-            app.tunnel_local_host = host.clone();
+            apply_project_defaults(&mut app, &project.config);
+            app.tunnel_local_host = host;
             app.tunnel_local_port = port;
+            app.tunnel_local_https = local_https;
             // Prefer explicit CLI org, then fall back to configured organization.
-            app.tunnel_org_id = selected_org.clone();
-            app.tunnel_requested_slug = slug.clone();
+            app.tunnel_org_id = selected_org;
+            app.tunnel_requested_slug = slug;
+            app.tunnel_routes = route_descriptions;
+            if interceptor.has_rules() {
+                app.interceptor = Some(interceptor);
+            }
+            if let Some((inspector, url)) = inspector {
+                app.inspector = Some(inspector);
+                app.inspector_url = Some(url);
+            }
 
-            // Create channel for tunnel events
-            let (event_tx, event_rx) = mpsc::channel(100);
+            // Spawn tunnel forwarder manager
+            let reconnect_tx = spawn_tunnel_forwarder_manager(Arc::new(forwarder));
 
-            // Create and spawn tunnel forwarder manager
-            let reconnect_tx = spawn_tunnel_forwarder_manager(
-                access_token,
-                host,
-                port,
-                selected_org,
-                slug,
-                event_tx,
-            );
+            let mut sessions = [Session::new(
+                "tunnel".to_string(),
+                app,
+                event_rx,
+                Some(reconnect_tx),
+            )];
+            let res = run_app(&mut terminal, &mut sessions).await;
 
-            let res = run_app(&mut terminal, &mut app, event_rx, Some(reconnect_tx)).await;
+            restore_terminal(&mut terminal)?;
+
+            if let Err(err) = res {
+                error!(error = %err, "Application terminated with error");
+                return Err(err);
+            }
+        }
+        Commands::Up => {
+            let up = &project.config.up;
+            match &project.path {
+                Some(path) => up
+                    .validate()
+                    .with_context(|| format!("Invalid [up] section in {}", path.display()))?,
+                None => {
+                    return Err(errors::CliError::InvalidInput(format!(
+                        "No {} found. Declare your sessions under [up] in it.",
+                        project::PROJECT_FILE_NAME
+                    ))
+                    .into());
+                }
+            }
+
+            let log_config = LogConfig {
+                level: log_level.clone(),
+                output_to_stdout: false, // Disable stdout logging for TUI
+                directory: log_dir
+                    .clone()
+                    .unwrap_or_else(|| LogConfig::default().directory),
+                ..Default::default()
+            };
+            let _logger = Logger::new(log_config)?;
+
+            // Every session connects with the same login
+            let mut config = config::Config::load()?;
+            let access_token = ensure_valid_token(&mut config).await?;
+            let refresher = token_refresher();
+
+            let mut sessions = Vec::new();
+            for spec in &up.tunnels {
+                let selected_org = resolve_tunnel_org(spec.org.clone(), &project.config, &config);
+                let (event_tx, event_rx) = mpsc::channel(100);
+                let forwarder = tunnel::TunnelForwarder::new(
+                    access_token.clone(),
+                    spec.host.clone(),
+                    spec.port,
+                    selected_org.clone(),
+                    spec.slug.clone(),
+                    None,
+                    event_tx,
+                )?
+                .with_token_refresher(refresher.clone());
+                let reconnect_tx = spawn_tunnel_forwarder_manager(Arc::new(forwarder));
+
+                let mut app = App::new()?;
+                app.state = AppState::Tunneling;
+                apply_project_defaults(&mut app, &project.config);
+                app.tunnel_local_host = spec.host.clone();
+                app.tunnel_local_port = spec.port;
+                app.tunnel_org_id = selected_org;
+                app.tunnel_requested_slug = spec.slug.clone();
+                sessions.push(Session::new(
+                    spec.label(),
+                    app,
+                    event_rx,
+                    Some(reconnect_tx),
+                ));
+            }
+            for spec in &up.listeners {
+                let (event_tx, event_rx) = mpsc::channel(100);
+                let client = tunnel::TunnelClient::new(
+                    access_token.clone(),
+                    spec.endpoint.clone(),
+                    spec.target.clone(),
+                    None,
+                    event_tx,
+                )?
+                .with_token_refresher(refresher.clone());
+                let reconnect_tx = spawn_tunnel_client_manager(Arc::new(client));
+
+                let mut app = App::new()?;
+                app.state = AppState::Listening;
+                apply_project_defaults(&mut app, &project.config);
+                app.listening_endpoint = spec.endpoint.clone();
+                app.listening_target = spec.target.clone();
+                sessions.push(Session::new(
+                    spec.label(),
+                    app,
+                    event_rx,
+                    Some(reconnect_tx),
+                ));
+            }
+
+            let mut terminal = setup_terminal()?;
+            let res = run_app(&mut terminal, &mut sessions).await;
 
             restore_terminal(&mut terminal)?;
 
             if let Err(err) = res {
                 error!(error = %err, "Application terminated with error");
-                display_error(&err);
+                return Err(err);
             }
         }
     }
@@ -1672,11 +2515,20 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn run_login_flow(force_reauth: bool) -> Result<()> {
+async fn run_login_flow(
+    force_reauth: bool,
+    open_browser: bool,
+    timeout: Option<Duration>,
+) -> Result<()> {
     let mut config = config::Config::load()?;
 
-    if config.is_token_valid() && !force_reauth {
-        println!("\n  ✅ You're already authenticated.\n");
+    let api_token_saved = config.api_token.is_some();
+    if (config.is_token_valid() || api_token_saved) && !force_reauth {
+        if api_token_saved {
+            println!("\n  ✅ You're already authenticated with an API token.\n");
+        } else {
+            println!("\n  ✅ You're already authenticated.\n");
+        }
         println!(
             "  Run {} to start forwarding webhooks.",
             "hooklistener listen <endpoint>".bold()
@@ -1693,17 +2545,22 @@ async fn run_login_flow(force_reauth: bool) -> Result<()> {
         config.save()?;
     }
 
-    let mut device_flow = auth::DeviceCodeFlow::new(api::default_base_url());
+    let mut device_flow = auth::DeviceCodeFlow::new(http::client()?, api::default_base_url()?);
 
     let user_code = device_flow.initiate_device_flow().await?;
     let display_code = device_flow
         .format_user_code()
         .unwrap_or_else(|| user_code.clone());
-    let portal_url = device_portal_url();
+    let (portal_url, complete_url) = device_flow.verification_urls(device_portal_url()?);
+    let browser_url = complete_url.clone().unwrap_or_else(|| portal_url.clone());
+    let deadline = timeout.map(|timeout| tokio::time::Instant::now() + timeout);
 
     let clipboard_ok = arboard::Clipboard::new()
         .and_then(|mut cb| cb.set_text(&display_code))
         .is_ok();
+    // Over SSH a browser would open on the wrong machine, if at all.
+    let browser_opened =
+        open_browser && !is_remote_session() && webbrowser::open(&browser_url).is_ok();
 
     println!("\n  🔐 {}\n", "Hooklistener Login".bold());
     println!("  Open:  {}", portal_url.as_str().underlined());
@@ -1712,15 +2569,58 @@ async fn run_login_flow(force_reauth: bool) -> Result<()> {
         print!("  {}", "(copied to clipboard)".dim());
     }
     println!("\n");
+    if browser_opened {
+        println!("  {}\n", "Opened the page in your browser.".dim());
+    } else if let Ok(qr) = auth::render_qr(&browser_url) {
+        println!("  {}\n", "Or scan to log in from your phone:".dim());
+        for line in qr.lines() {
+            println!("  {}", line);
+        }
+        println!();
+    }
 
     let spinner_chars = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"];
     let mut spinner_idx: usize = 0;
     let mut stdout = io::stdout();
 
-    let mut poll_interval = tokio::time::interval(Duration::from_secs(5));
-    poll_interval.tick().await; // consume the immediate first tick
-
     loop {
+        // Wait the server's interval before each poll, as RFC 8628 asks
+        let next_poll = sleep(device_flow.interval());
+        tokio::pin!(next_poll);
+        loop {
+            if deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+                execute!(stdout, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+                return Err(anyhow!(
+                    "Timed out waiting for approval. Please run `hooklistener login` again."
+                ));
+            }
+
+            let spinner = spinner_chars[spinner_idx % spinner_chars.len()];
+            spinner_idx = (spinner_idx + 1) % spinner_chars.len();
+
+            let status = if let Some(remaining) = device_flow.time_remaining() {
+                let minutes = remaining.num_minutes();
+                let seconds = remaining.num_seconds() % 60;
+                let timer = if minutes > 0 {
+                    format!("{minutes}m {seconds:02}s")
+                } else {
+                    format!("{seconds}s")
+                };
+                format!("  {spinner} Waiting for approval... {}", timer.dim())
+            } else {
+                format!("  {spinner} Waiting for approval...")
+            };
+
+            execute!(stdout, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+            print!("{status}");
+            stdout.flush()?;
+
+            tokio::select! {
+                _ = sleep(Duration::from_millis(80)) => continue,
+                _ = &mut next_poll => break,
+            }
+        }
+
         // Poll the API
         match device_flow.poll_for_authorization().await {
             Ok(Some(token_response)) => {
@@ -1740,6 +2640,13 @@ async fn run_login_flow(force_reauth: bool) -> Result<()> {
                 );
                 config.save()?;
                 println!("  ✅ Authentication successful!\n");
+                if config::env_token().is_some() {
+                    println!(
+                        "  {} {} is set and is used instead of this login.\n",
+                        "Note:".yellow().bold(),
+                        config::TOKEN_ENV_VAR
+                    );
+                }
                 println!(
                     "  Run {} to forward webhooks.\n",
                     "hooklistener listen <endpoint>".bold()
@@ -1761,48 +2668,367 @@ async fn run_login_flow(force_reauth: bool) -> Result<()> {
                 return Err(anyhow!("Authentication failed: {}", err));
             }
         }
+    }
+}
 
-        // Animate spinner until next poll
-        loop {
-            let spinner = spinner_chars[spinner_idx % spinner_chars.len()];
-            spinner_idx = (spinner_idx + 1) % spinner_chars.len();
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
 
-            let status = if let Some(remaining) = device_flow.time_remaining() {
-                let minutes = remaining.num_minutes();
-                let seconds = remaining.num_seconds() % 60;
-                let timer = if minutes > 0 {
-                    format!("{minutes}m {seconds:02}s")
+#[derive(Debug, serde::Serialize)]
+struct DoctorCheck {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+}
+
+impl DoctorCheck {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Checks behind `hooklistener doctor`: config, credentials file and login.
+fn run_doctor_checks() -> Vec<DoctorCheck> {
+    let mut checks = Vec::new();
+    let config_path = match config::Config::config_path() {
+        Ok(path) => path,
+        Err(err) => {
+            checks.push(DoctorCheck::new(
+                "Config",
+                CheckStatus::Error,
+                err.to_string(),
+            ));
+            return checks;
+        }
+    };
+
+    let credentials_path = credentials::credentials_path(&config_path);
+    checks.push(match credentials::permission_problem(&credentials_path) {
+        Some(problem) => DoctorCheck::new("Credentials file", CheckStatus::Warning, problem),
+        None if credentials_path.exists() => DoctorCheck::new(
+            "Credentials file",
+            CheckStatus::Ok,
+            format!("{} (owner only)", credentials_path.display()),
+        ),
+        None => DoctorCheck::new("Credentials file", CheckStatus::Ok, "not created yet"),
+    });
+
+    match config::Config::load() {
+        Ok(config) => {
+            checks.push(DoctorCheck::new(
+                "Config",
+                CheckStatus::Ok,
+                format!(
+                    "{} (profile {})",
+                    config_path.display(),
+                    config.profile_name()
+                ),
+            ));
+            checks.push(DoctorCheck::new(
+                "Encryption",
+                CheckStatus::Ok,
+                if config.credentials_encrypted() {
+                    "credentials are passphrase-encrypted"
                 } else {
-                    format!("{seconds}s")
-                };
-                format!("  {spinner} Waiting for approval... {}", timer.dim())
-            } else {
-                format!("  {spinner} Waiting for approval...")
-            };
+                    "credentials are stored unencrypted (see `hooklistener auth encrypt`)"
+                },
+            ));
+            checks.push(match config.credential_source() {
+                Some(source) => DoctorCheck::new("Login", CheckStatus::Ok, source.label()),
+                None => DoctorCheck::new(
+                    "Login",
+                    CheckStatus::Warning,
+                    "not logged in (run `hooklistener login`)",
+                ),
+            });
+        }
+        Err(err) => checks.push(DoctorCheck::new(
+            "Config",
+            CheckStatus::Error,
+            format!("{:#}", err),
+        )),
+    }
 
-            execute!(stdout, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
-            print!("{status}");
-            stdout.flush()?;
+    checks.push(match http::settings() {
+        Ok(network) => {
+            let mut details = Vec::new();
+            if let Some(proxy) = &network.https_proxy {
+                details.push(format!("proxy {}", proxy));
+            }
+            if let Some(ca_bundle) = &network.ca_bundle {
+                details.push(format!("CA bundle {}", ca_bundle.display()));
+            }
+            details.push(format!(
+                "timeouts {}s connect, {}s read",
+                network.connect_timeout.as_secs(),
+                network.read_timeout.as_secs()
+            ));
+            DoctorCheck::new("Network", CheckStatus::Ok, details.join(", "))
+        }
+        Err(err) => DoctorCheck::new("Network", CheckStatus::Error, format!("{:#}", err)),
+    });
+    checks
+}
 
-            tokio::select! {
-                _ = sleep(Duration::from_millis(80)) => continue,
-                _ = poll_interval.tick() => break,
+/// Passphrase for `auth encrypt`: `HOOKLISTENER_PASSPHRASE`, else asked twice.
+fn read_new_passphrase() -> Result<credentials::Passphrase> {
+    if let Ok(passphrase) = std::env::var(credentials::PASSPHRASE_ENV_VAR) {
+        return Ok(credentials::Passphrase::new(passphrase));
+    }
+    let passphrase = rpassword::prompt_password("New passphrase: ")?;
+    if passphrase.is_empty() {
+        return Err(anyhow!("The passphrase must not be empty"));
+    }
+    if rpassword::prompt_password("Repeat passphrase: ")? != passphrase {
+        return Err(anyhow!("Passphrases do not match"));
+    }
+    Ok(credentials::Passphrase::new(passphrase))
+}
+
+/// Save a long-lived API token read from stdin, once the API accepts it.
+async fn run_token_login(json: bool) -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    let token = input.trim().to_string();
+    if token.is_empty() {
+        return Err(anyhow!(
+            "No token on stdin. Pipe one in, e.g. `echo \"$TOKEN\" | hooklistener login --with-token`."
+        ));
+    }
+
+    ApiClient::with_organization(token.clone(), None)?
+        .list_organizations()
+        .await
+        .context("Could not verify the token")?;
+
+    let mut config = config::Config::load()?;
+    config.set_api_token(token);
+    config.save()?;
+    if json {
+        print_json(&serde_json::json!({
+            "status": "ok",
+            "profile": config.profile_name(),
+            "source": config::CredentialSource::ApiToken
+        }))?;
+    } else {
+        println!(
+            "✅ Saved API token for profile {}",
+            config.profile_name().bold()
+        );
+    }
+    Ok(())
+}
+
+/// Show the account and credentials for the active profile, checking them
+/// against the API. Exits with the auth error code when not logged in, and
+/// with the network code when the session can't be refreshed for other reasons.
+async fn run_auth_status(json: bool) -> Result<()> {
+    let mut config = config::Config::load()?;
+    let source = config.credential_source();
+    let session = source == Some(config::CredentialSource::Session);
+
+    let mut problem = None;
+    let mut user = None;
+    let mut organization = None;
+    match source {
+        None => problem = Some("not logged in"),
+        Some(_) => match ensure_valid_token(&mut config).await {
+            Err(err) if errors::ErrorKind::of(&err) == errors::ErrorKind::Auth => {
+                problem = Some("session expired")
             }
+            Err(err) => return Err(err),
+            Ok(token) => {
+                let client = ApiClient::with_organization(token, None)?;
+                match client.list_organizations().await {
+                    Err(err)
+                        if matches!(
+                            err.downcast_ref::<errors::ApiError>(),
+                            Some(errors::ApiError::Unauthorized)
+                        ) =>
+                    {
+                        problem = Some("credentials rejected")
+                    }
+                    Err(err) => return Err(err),
+                    Ok(organizations) => {
+                        organization = config.selected_organization_id.as_ref().map(|id| {
+                            organizations
+                                .into_iter()
+                                .find(|org| &org.id == id)
+                                .unwrap_or_else(|| api::Organization {
+                                    id: id.clone(),
+                                    name: "(not accessible)".to_string(),
+                                })
+                        });
+                        // Older servers have no user endpoint; the rest still applies.
+                        match client.get_current_user().await {
+                            Ok(current) => user = Some(current),
+                            Err(err) => match err.downcast_ref::<errors::ApiError>() {
+                                Some(errors::ApiError::Unauthorized) => {
+                                    problem = Some("credentials rejected")
+                                }
+                                Some(errors::ApiError::NotFound { .. }) => {}
+                                _ => {
+                                    warn!(error = %err, "Could not load the current user");
+                                    eprintln!(
+                                        "{} Could not load the account: {:#}",
+                                        "Warning:".yellow().bold(),
+                                        err
+                                    );
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        },
+    }
+
+    let expires_at = config.token_expires_at.filter(|_| session);
+    let refresh_expires_at = config.refresh_token_expires_at.filter(|_| session);
+    if json {
+        print_json(&serde_json::json!({
+            "profile": config.profile_name(),
+            "authenticated": problem.is_none(),
+            "error": problem,
+            "source": source,
+            "user": user,
+            "organization": organization,
+            "expires_at": expires_at,
+            "refresh_expires_at": refresh_expires_at
+        }))?;
+    } else {
+        println!("  {} {}", "Profile:".bold(), config.profile_name());
+        if let Some(user) = &user {
+            let account = match (&user.email, &user.name) {
+                (Some(email), Some(name)) => format!("{} <{}>", name, email),
+                (Some(email), None) => email.clone(),
+                (None, Some(name)) => name.clone(),
+                (None, None) => user.id.clone(),
+            };
+            println!("  {} {}", "Account:".bold(), account);
         }
+        if let Some(org) = &organization {
+            println!(
+                "  {} {} {}",
+                "Organization:".bold(),
+                org.name,
+                org.id.as_str().dim()
+            );
+        }
+        match source {
+            Some(source) => println!("  {} {}", "Credentials:".bold(), source.label()),
+            None => println!("  {} {}", "Credentials:".bold(), "(none)".dim()),
+        }
+        if let Some(expires_at) = expires_at {
+            println!(
+                "  {} {}",
+                "Access token:".bold(),
+                format_expiry(expires_at, Utc::now())
+            );
+        }
+        if let Some(expires_at) = refresh_expires_at {
+            println!(
+                "  {} {}",
+                "Refresh token:".bold(),
+                format_expiry(expires_at, Utc::now())
+            );
+        }
+        match &problem {
+            None => println!("  {} {}", "Status:".bold(), "authenticated".green()),
+            Some(problem) => println!(
+                "  {} {} {}",
+                "Status:".bold(),
+                problem.red(),
+                "(run `hooklistener login`)".dim()
+            ),
+        }
+    }
+    if problem.is_some() {
+        std::process::exit(errors::ErrorKind::Auth.exit_code());
+    }
+    Ok(())
+}
+
+/// "expires in 2h 5m (2024-01-01 12:00 UTC)" or "expired 3m ago (...)".
+fn format_expiry(expires_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let remaining = expires_at - now;
+    let span = |d: ChronoDuration| {
+        let minutes = d.num_minutes().abs();
+        match (minutes / (24 * 60), minutes / 60 % 24, minutes % 60) {
+            (0, 0, m) => format!("{}m", m),
+            (0, h, m) => format!("{}h {}m", h, m),
+            (days, h, _) => format!("{}d {}h", days, h),
+        }
+    };
+    let when = expires_at.format("%Y-%m-%d %H:%M UTC");
+    if remaining > ChronoDuration::zero() {
+        format!("expires in {} ({})", span(remaining), when)
+    } else {
+        format!("expired {} ago ({})", span(remaining), when)
     }
 }
 
-fn device_portal_url() -> String {
-    std::env::var("HOOKLISTENER_DEVICE_PORTAL_URL")
-        .unwrap_or_else(|_| "https://app.hooklistener.com/device-codes".to_string())
+/// Whether we run on a remote host, where launching a browser does not help.
+fn is_remote_session() -> bool {
+    std::env::var_os("SSH_CONNECTION").is_some() || std::env::var_os("SSH_TTY").is_some()
+}
+
+fn device_portal_url() -> Result<String> {
+    if let Ok(url) = std::env::var("HOOKLISTENER_DEVICE_PORTAL_URL") {
+        return Ok(url);
+    }
+    Ok(format!(
+        "{}/device-codes",
+        config::api_url()?.trim_end_matches('/')
+    ))
+}
+
+/// Split a `--target` URL into host and port, defaulting the port from the scheme.
+fn parse_local_target(target: &str) -> Result<(String, u16)> {
+    let invalid = |reason: String| {
+        errors::CliError::InvalidInput(format!("Invalid --target '{}': {}", target, reason))
+    };
+    let url = reqwest::Url::parse(target).map_err(|e| invalid(e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid("scheme must be http or https".to_string()).into());
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| invalid("missing host".to_string()))?
+        .to_string();
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| invalid("missing port".to_string()))?;
+    Ok((host, port))
 }
 
-fn resolve_tunnel_org(cli_org: Option<String>, config: &config::Config) -> Option<String> {
-    cli_org.or_else(|| config.selected_organization_id.clone())
+/// Organization from `--org`, then the project file, then the user config.
+fn resolve_tunnel_org(
+    cli_org: Option<String>,
+    project: &project::ProjectConfig,
+    config: &config::Config,
+) -> Option<String> {
+    cli_org
+        .or_else(|| project.organization.clone())
+        .or_else(|| config.selected_organization_id.clone())
 }
 
 async fn ensure_valid_token(config: &mut config::Config) -> Result<String> {
-    // 1. If access token is still valid, return it
+    // 1. HOOKLISTENER_TOKEN or a saved API token is used as-is, never written back
+    if let Some(token) = config::env_token().or_else(|| config.api_token.clone()) {
+        return Ok(token);
+    }
+
+    // 2. If access token is still valid, return it
     if config.is_token_valid() {
         return config
             .access_token
@@ -1810,33 +3036,127 @@ async fn ensure_valid_token(config: &mut config::Config) -> Result<String> {
             .ok_or_else(|| anyhow!("No access token found. Please run `hooklistener login`."));
     }
 
-    // 2. If refresh token is valid, try refreshing
-    if let Some(ref refresh_token) = config.refresh_token.clone()
-        && config.is_refresh_token_valid()
-        && let Ok(response) = api::refresh_access_token(refresh_token).await
-    {
-        let expires_at = Utc::now() + ChronoDuration::seconds(response.expires_in as i64);
-        config.set_tokens(
-            response.access_token.clone(),
-            expires_at,
-            config.refresh_token.clone(),
-            config.refresh_token_expires_at,
-        );
-        config.save()?;
-        return Ok(response.access_token);
+    // 3. If refresh token is valid, try refreshing. Only a refresh token the
+    // server rejected means the session is over; other failures are reported.
+    match refresh_session(config).await {
+        Ok(token) => return Ok(token),
+        Err(err) if !is_rejected_refresh(&err) => return Err(err),
+        Err(_) => {}
     }
 
-    // 3. No valid tokens
-    Err(anyhow!(
-        "Session expired. Please run `hooklistener login` to re-authenticate."
-    ))
+    // 4. No valid tokens
+    Err(errors::CliError::NotAuthenticated(
+        "Session expired. Please run `hooklistener login` to re-authenticate.".to_string(),
+    )
+    .into())
+}
+
+/// Whether a failed refresh means there is no usable refresh token, as
+/// opposed to a network or server problem that a retry may get past.
+fn is_rejected_refresh(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<errors::ApiError>(),
+            Some(
+                errors::ApiError::Unauthorized
+                    | errors::ApiError::Forbidden
+                    | errors::ApiError::InvalidRequest(_)
+            )
+        ) || cause.downcast_ref::<errors::CliError>().is_some()
+    })
+}
+
+/// Exchange the stored refresh token for a new access token and save it.
+///
+/// Runs under the config lock and re-reads the file first, so processes that
+/// race to refresh reuse the token the first one saved.
+async fn refresh_session(config: &mut config::Config) -> Result<String> {
+    if !config.is_refresh_token_valid() {
+        return Err(no_refresh_token());
+    }
+    let lock = tokio::task::spawn_blocking(config::ConfigLock::acquire).await??;
+    let mut latest = config::Config::load_locked(&lock)?;
+
+    if latest.is_token_valid() && latest.access_token != config.access_token {
+        copy_session(&latest, config);
+        return latest
+            .access_token
+            .ok_or_else(|| anyhow!("No access token found. Please run `hooklistener login`."));
+    }
+
+    let refresh_token = latest
+        .refresh_token
+        .clone()
+        .filter(|_| latest.is_refresh_token_valid())
+        .ok_or_else(no_refresh_token)?;
+    let response = api::refresh_access_token(&refresh_token).await?;
+    let expires_at = Utc::now() + ChronoDuration::seconds(response.expires_in as i64);
+    latest.set_tokens(
+        response.access_token.clone(),
+        expires_at,
+        latest.refresh_token.clone(),
+        latest.refresh_token_expires_at,
+    );
+    latest.save_locked(&lock)?;
+    copy_session(&latest, config);
+    Ok(response.access_token)
+}
+
+fn no_refresh_token() -> anyhow::Error {
+    errors::CliError::NotAuthenticated(
+        "No valid refresh token. Please run `hooklistener login`.".to_string(),
+    )
+    .into()
+}
+
+fn copy_session(from: &config::Config, to: &mut config::Config) {
+    to.access_token = from.access_token.clone();
+    to.token_expires_at = from.token_expires_at;
+    to.refresh_token = from.refresh_token.clone();
+    to.refresh_token_expires_at = from.refresh_token_expires_at;
+}
+
+/// Refresher for long-running connections. Only login sessions can be
+/// refreshed; a rejected API token stays fatal.
+///
+/// Connections sharing one refresher also share its refreshes: a caller that
+/// waited while another refresh finished gets that token instead of spending
+/// the rotated refresh token again.
+fn token_refresher() -> tunnel::TokenRefresher {
+    let last_refresh: Arc<tokio::sync::Mutex<Option<(Instant, String)>>> = Arc::default();
+    Arc::new(move || {
+        let last_refresh = last_refresh.clone();
+        Box::pin(async move {
+            let requested_at = Instant::now();
+            let mut last_refresh = last_refresh.lock().await;
+            if let Some((refreshed_at, token)) = last_refresh.as_ref()
+                && *refreshed_at >= requested_at
+            {
+                return Ok(token.clone());
+            }
+
+            let mut config = config::Config::load()?;
+            if config.credential_source() != Some(config::CredentialSource::Session) {
+                return Err(anyhow!("API tokens cannot be refreshed"));
+            }
+            let token = refresh_session(&mut config).await?;
+            *last_refresh = Some((Instant::now(), token.clone()));
+            Ok(token)
+        })
+    })
 }
 
-fn require_organization(cli_org: Option<String>, config: &config::Config) -> Result<String> {
-    resolve_tunnel_org(cli_org, config).ok_or_else(|| {
-        anyhow!(
+fn require_organization(
+    cli_org: Option<String>,
+    project: &project::ProjectConfig,
+    config: &config::Config,
+) -> Result<String> {
+    resolve_tunnel_org(cli_org, project, config).ok_or_else(|| {
+        errors::CliError::InvalidInput(
             "No organization selected. Use `hooklistener org use <organization-id>` or pass --org."
+                .to_string(),
         )
+        .into()
     })
 }
 
@@ -1912,6 +3232,28 @@ fn print_organizations(organizations: &[api::Organization], selected_org: Option
     println!("{table}");
 }
 
+fn print_profiles(profiles: &[(String, config::Profile)], active: &str) {
+    let mut table = new_table(&["", "Name", "API URL", "Logged in", "Organization"]);
+    for (name, profile) in profiles {
+        let marker = if name == active { "*" } else { "" };
+        table.add_row(vec![
+            marker,
+            name,
+            profile
+                .api_url
+                .as_deref()
+                .unwrap_or(config::DEFAULT_API_URL),
+            if profile.credential_source().is_some() {
+                "yes"
+            } else {
+                "no"
+            },
+            profile.selected_organization_id.as_deref().unwrap_or(""),
+        ]);
+    }
+    println!("{table}");
+}
+
 fn print_endpoints(endpoints: &[api::DebugEndpointSummary]) {
     if endpoints.is_empty() {
         println!("{}", "No debug endpoints found.".dim());
@@ -1931,14 +3273,17 @@ fn print_endpoints(endpoints: &[api::DebugEndpointSummary]) {
     println!("{table}");
 }
 
-fn print_endpoint_requests(response: &api::EndpointRequestsResponse) {
-    if response.data.is_empty() {
+fn print_endpoint_requests(
+    requests: &[api::DebugRequestSummary],
+    pagination: Option<&api::Pagination>,
+) {
+    if requests.is_empty() {
         println!("{}", "No requests found.".dim());
         return;
     }
 
     let mut table = new_table(&["ID", "Method", "URL", "Remote"]);
-    for request in &response.data {
+    for request in requests {
         table.add_row(vec![
             &request.id,
             &request.method,
@@ -1947,7 +3292,9 @@ fn print_endpoint_requests(response: &api::EndpointRequestsResponse) {
         ]);
     }
     println!("{table}");
-    print_pagination(&response.pagination);
+    if let Some(pagination) = pagination {
+        print_pagination(pagination);
+    }
 }
 
 fn print_endpoint_request_detail(request: &api::DebugRequestDetail) {
@@ -1981,14 +3328,17 @@ fn print_endpoint_request_detail(request: &api::DebugRequestDetail) {
     );
 }
 
-fn print_endpoint_request_forwards(response: &api::EndpointRequestForwardsResponse) {
-    if response.data.is_empty() {
+fn print_endpoint_request_forwards(
+    forwards: &[api::DebugRequestForwardSummary],
+    pagination: Option<&api::Pagination>,
+) {
+    if forwards.is_empty() {
         println!("{}", "No forwards found.".dim());
         return;
     }
 
     let mut table = new_table(&["ID", "Method", "Status", "Duration", "Target"]);
-    for forward in &response.data {
+    for forward in forwards {
         let status = forward
             .status_code
             .map(|c| c.to_string())
@@ -2010,7 +3360,9 @@ fn print_endpoint_request_forwards(response: &api::EndpointRequestForwardsRespon
         ]);
     }
     println!("{table}");
-    print_pagination(&response.pagination);
+    if let Some(pagination) = pagination {
+        print_pagination(pagination);
+    }
 }
 
 fn print_forward_detail(forward: &api::DebugRequestForwardDetail) {
@@ -2074,15 +3426,15 @@ fn print_static_tunnels(response: &api::StaticTunnelsResponse) {
     );
 }
 
-fn print_anon_events(response: &api::AnonEventsResponse) {
-    if response.data.is_empty() {
+fn print_anon_events(events: &[api::AnonEvent], pagination: Option<&api::Pagination>) {
+    if events.is_empty() {
         println!("{}", "No events captured yet.".dim());
     } else {
         println!(
             "{}",
             format!("{:<36}  {:<7}  Received At", "ID", "Method").dim()
         );
-        for event in &response.data {
+        for event in events {
             println!(
                 "{:<36}  {:<7}  {}",
                 event.id,
@@ -2091,7 +3443,9 @@ fn print_anon_events(response: &api::AnonEventsResponse) {
             );
         }
     }
-    print_pagination(&response.pagination);
+    if let Some(pagination) = pagination {
+        print_pagination(pagination);
+    }
 }
 
 fn print_anon_event_detail(event: &api::AnonEvent) {
@@ -2328,9 +3682,13 @@ fn print_monitor_detail(m: &api::UptimeMonitor) {
     }
 }
 
-fn print_uptime_checks(response: &api::UptimeChecksResponse) {
+fn print_uptime_checks(
+    checks: &[api::UptimeCheck],
+    stats: Option<&api::UptimeChecksStats>,
+    pagination: Option<&api::Pagination>,
+) {
     // Stats summary
-    if let Some(ref stats) = response.stats {
+    if let Some(stats) = stats {
         let uptime = stats
             .uptime_percentage
             .map(|p| {
@@ -2358,7 +3716,7 @@ fn print_uptime_checks(response: &api::UptimeChecksResponse) {
         println!();
     }
 
-    if response.data.is_empty() {
+    if checks.is_empty() {
         println!("{}", "No checks recorded yet.".dim());
     } else {
         println!(
@@ -2369,7 +3727,7 @@ fn print_uptime_checks(response: &api::UptimeChecksResponse) {
             )
             .dim()
         );
-        for check in &response.data {
+        for check in checks {
             let status = match check.status.as_str() {
                 "up" => "up".green().to_string(),
                 "down" => "down".red().to_string(),
@@ -2399,28 +3757,22 @@ fn print_uptime_checks(response: &api::UptimeChecksResponse) {
         }
     }
 
-    print_pagination(&response.pagination);
+    if let Some(pagination) = pagination {
+        print_pagination(pagination);
+    }
 }
 
-fn spawn_tunnel_forwarder_manager(
-    access_token: String,
-    host: String,
-    port: u16,
-    org: Option<String>,
-    slug: Option<String>,
-    event_tx: mpsc::Sender<TunnelEvent>,
-) -> mpsc::UnboundedSender<()> {
+/// Run `connect` in the background, restarting it whenever a reconnect is requested
+/// through the returned sender.
+fn spawn_connection_manager<F, Fut>(connect: F) -> mpsc::UnboundedSender<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = ()> + Send + 'static,
+{
     let (reconnect_tx, mut reconnect_rx) = mpsc::unbounded_channel::<()>();
 
     tokio::spawn(async move {
-        let mut worker = tokio::spawn(run_tunnel_forwarder_connection(
-            access_token.clone(),
-            host.clone(),
-            port,
-            org.clone(),
-            slug.clone(),
-            event_tx.clone(),
-        ));
+        let mut worker = tokio::spawn(connect());
 
         while reconnect_rx.recv().await.is_some() {
             worker.abort();
@@ -2429,14 +3781,7 @@ fn spawn_tunnel_forwarder_manager(
             // Collapse bursty manual reconnect presses into a single restart.
             while reconnect_rx.try_recv().is_ok() {}
 
-            worker = tokio::spawn(run_tunnel_forwarder_connection(
-                access_token.clone(),
-                host.clone(),
-                port,
-                org.clone(),
-                slug.clone(),
-                event_tx.clone(),
-            ));
+            worker = tokio::spawn(connect());
         }
 
         worker.abort();
@@ -2446,18 +3791,18 @@ fn spawn_tunnel_forwarder_manager(
     reconnect_tx
 }
 
-async fn run_tunnel_forwarder_connection(
-    access_token: String,
-    host: String,
-    port: u16,
-    org: Option<String>,
-    slug: Option<String>,
-    event_tx: mpsc::Sender<TunnelEvent>,
-) {
-    let tunnel_forwarder =
-        tunnel::TunnelForwarder::new(access_token, host, port, org, slug, event_tx);
+fn spawn_tunnel_forwarder_manager(
+    forwarder: Arc<tunnel::TunnelForwarder>,
+) -> mpsc::UnboundedSender<()> {
+    spawn_connection_manager(move || run_tunnel_forwarder_connection(Arc::clone(&forwarder)))
+}
 
-    if let Err(e) = tunnel_forwarder
+fn spawn_tunnel_client_manager(client: Arc<tunnel::TunnelClient>) -> mpsc::UnboundedSender<()> {
+    spawn_connection_manager(move || run_tunnel_client_connection(Arc::clone(&client)))
+}
+
+async fn run_tunnel_forwarder_connection(forwarder: Arc<tunnel::TunnelForwarder>) {
+    if let Err(e) = forwarder
         .connect_with_reconnect(tunnel::ReconnectConfig::default())
         .await
     {
@@ -2465,163 +3810,480 @@ async fn run_tunnel_forwarder_connection(
     }
 }
 
+async fn run_tunnel_client_connection(client: Arc<tunnel::TunnelClient>) {
+    if let Err(e) = client
+        .connect_with_reconnect(tunnel::ReconnectConfig::default())
+        .await
+    {
+        error!("Tunnel client error: {}", e);
+    }
+}
+
+/// Apply project-wide redaction rules and replay target to a session's app.
+fn apply_project_defaults(app: &mut App, project: &project::ProjectConfig) {
+    app.redact = project.redact.clone();
+    if let Some(target) = project.replay.resolve(None) {
+        app.forward_url_input = target;
+    }
+}
+
+/// Print tunnel events without the TUI, exiting non-zero if the connection is lost for good.
+async fn run_headless(
+    event_rx: mpsc::Receiver<TunnelEvent>,
+    json: bool,
+    redact: project::RedactRules,
+    fail_on_unreachable: bool,
+) {
+    if let Err(err) = headless::run(event_rx, json, redact, fail_on_unreachable).await {
+        error!(error = %err, "Headless session terminated with error");
+        exit_with_error(&err, json);
+    }
+}
+
+/// A `listen` or `tunnel` session shown in the TUI, one tab each under `up`.
+struct Session {
+    name: String,
+    app: App,
+    events: mpsc::Receiver<TunnelEvent>,
+    reconnect_tx: Option<mpsc::UnboundedSender<()>>,
+}
+
+impl Session {
+    fn new(
+        name: String,
+        app: App,
+        events: mpsc::Receiver<TunnelEvent>,
+        reconnect_tx: Option<mpsc::UnboundedSender<()>>,
+    ) -> Self {
+        Self {
+            name,
+            app,
+            events,
+            reconnect_tx,
+        }
+    }
+
+    /// Tab title with connection state and request count.
+    fn tab_title(&self) -> String {
+        let (connected, failed, requests) = match self.app.state {
+            AppState::Listening => (
+                self.app.listening_connected,
+                self.app.listening_error.is_some(),
+                self.app.listening_requests.len(),
+            ),
+            _ => (
+                self.app.tunnel_connected,
+                self.app.tunnel_error.is_some(),
+                self.app.tunnel_requests.len(),
+            ),
+        };
+        let symbol = if connected {
+            "●"
+        } else if failed {
+            "✗"
+        } else {
+            "○"
+        };
+        format!("{} {} ({})", symbol, self.name, requests)
+    }
+
+    /// Whether the session shows its request list, where Tab switches sessions.
+    fn on_main_screen(&self) -> bool {
+        match self.app.state {
+            AppState::Listening => !self.app.search_active,
+            AppState::Tunneling => true,
+            _ => false,
+        }
+    }
+}
+
+/// Apply a tunnel event to the app state.
+fn apply_tunnel_event(app: &mut App, mut event: TunnelEvent) {
+    event.redact(&app.redact);
+    // Progress counters are not part of the inspector entry, so they don't republish it
+    let request_id = match event {
+        TunnelEvent::BytesTransferred { .. } | TunnelEvent::WebSocketFrames { .. } => None,
+        _ => event.request_id().map(str::to_string),
+    };
+    match event {
+        TunnelEvent::Connecting => {
+            // Update UI to show connecting state
+        }
+        TunnelEvent::Connected => {
+            app.listening_connected = true;
+            app.listening_error = None;
+            app.tunnel_connected = true;
+            app.tunnel_connected_at = Some(std::time::Instant::now());
+        }
+        TunnelEvent::TunnelEstablished {
+            subdomain,
+            tunnel_id,
+            is_static,
+        } => {
+            app.tunnel_subdomain = Some(subdomain);
+            app.tunnel_id = Some(tunnel_id);
+            app.tunnel_is_static = is_static;
+            app.tunnel_connected = true;
+            app.tunnel_connected_at = Some(std::time::Instant::now());
+        }
+        TunnelEvent::ConnectionError(err) => {
+            app.listening_connected = false;
+            app.listening_error = Some(err.clone());
+            app.tunnel_connected = false;
+            app.tunnel_error = Some(err);
+        }
+        TunnelEvent::Disconnected => {
+            app.listening_connected = false;
+            app.tunnel_connected = false;
+        }
+        TunnelEvent::RequestIntercepted {
+            request_id,
+            request,
+            can_respond,
+        } => {
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.intercept = Some(app::InterceptOutcome::Held);
+            }
+            let message = Some((
+                format!(
+                    "⏸ {} {} held — press i to edit",
+                    request.method, request.path
+                ),
+                std::time::Instant::now(),
+            ));
+            if can_respond {
+                app.tunnel_status_message = message;
+            } else {
+                app.status_message = message;
+            }
+            app.held_requests.push_back(app::HeldEntry {
+                request_id,
+                request,
+                can_respond,
+            });
+        }
+        TunnelEvent::WebhookReceived(request) => {
+            app.listening_requests.push(*request);
+            app.listening_stats.total_requests += 1;
+        }
+        TunnelEvent::RequestReceived {
+            request_id,
+            method,
+            path,
+            headers,
+            body,
+            query_string,
+            target,
+            route,
+        } => {
+            use std::time::Instant;
+            let tunnel_request = app::TunnelRequest {
+                request_id,
+                method,
+                path,
+                received_at: Instant::now(),
+                status: None,
+                completed_at: None,
+                error: None,
+                headers,
+                body: app::truncate_body(body),
+                query_string,
+                response_headers: None,
+                response_body: None,
+                target_url: target,
+                route,
+                bytes_in: 0,
+                bytes_out: 0,
+                stream_status: app::StreamStatus::Buffered,
+                websocket: None,
+                intercept: None,
+                mocked: None,
+                fault: None,
+            };
+            app.tunnel_requests.push_back(tunnel_request);
+            if app.tunnel_requests.len() > app::MAX_TUNNEL_REQUESTS {
+                app.tunnel_requests.pop_front();
+                // Clamp selected index if it now exceeds the new length
+                if !app.tunnel_requests.is_empty() {
+                    app.tunnel_selected_index =
+                        app.tunnel_selected_index.min(app.tunnel_requests.len() - 1);
+                }
+            }
+            app.tunnel_stats.total += 1;
+        }
+        TunnelEvent::RequestForwarded {
+            request_id,
+            status,
+            duration_ms,
+            response_headers,
+            response_body,
+        } => {
+            // Update the request in the list
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.status = Some(status);
+                req.completed_at = Some(std::time::Instant::now());
+                req.response_headers = Some(response_headers);
+                req.response_body = app::truncate_body(response_body);
+            }
+            app.tunnel_stats.success += 1;
+            app.tunnel_stats.total_duration_ms += duration_ms;
+        }
+        TunnelEvent::FaultInjected { request_id, faults } => {
+            // Listen mode has no per-request rows, only the counter
+            match app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                Some(req) => {
+                    req.fault = Some(faults);
+                    app.tunnel_stats.faults += 1;
+                }
+                None => app.listening_stats.faults += 1,
+            }
+        }
+        TunnelEvent::RequestMocked {
+            request_id,
+            status,
+            duration_ms,
+            response_headers,
+            response_body,
+            mode,
+        } => {
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.status = Some(status);
+                req.completed_at = Some(std::time::Instant::now());
+                req.response_headers = Some(response_headers);
+                req.response_body = app::truncate_body(response_body);
+                req.mocked = Some(mode);
+            }
+            app.tunnel_stats.success += 1;
+            app.tunnel_stats.mocked += 1;
+            app.tunnel_stats.total_duration_ms += duration_ms;
+        }
+        TunnelEvent::RequestFailed {
+            request_id, error, ..
+        } => {
+            // Update the request in the list
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.error = Some(error);
+                req.completed_at = Some(std::time::Instant::now());
+            }
+            app.tunnel_stats.failed += 1;
+        }
+        TunnelEvent::BytesTransferred {
+            request_id,
+            bytes_in,
+            bytes_out,
+        } => {
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.bytes_in += bytes_in;
+                req.bytes_out += bytes_out;
+            }
+            app.tunnel_stats.bytes_in += bytes_in;
+            app.tunnel_stats.bytes_out += bytes_out;
+        }
+        TunnelEvent::ResponseStreamStarted {
+            request_id,
+            status,
+            response_headers,
+        } => {
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.status = Some(status);
+                req.response_headers = Some(response_headers);
+                req.stream_status = app::StreamStatus::Streaming;
+            }
+        }
+        TunnelEvent::ResponseStreamEnded {
+            request_id,
+            duration_ms,
+            response_body,
+            error,
+        } => {
+            let failed = error.is_some();
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.completed_at = Some(std::time::Instant::now());
+                req.response_body = app::truncate_body(response_body);
+                req.stream_status = app::StreamStatus::Streamed;
+                req.error = error;
+            }
+            if failed {
+                app.tunnel_stats.failed += 1;
+            } else {
+                app.tunnel_stats.success += 1;
+                app.tunnel_stats.total_duration_ms += duration_ms;
+            }
+        }
+        TunnelEvent::WebSocketOpened {
+            request_id,
+            response_headers,
+        } => {
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.status = Some(101);
+                req.response_headers = Some(response_headers);
+                req.websocket = Some(app::WebSocketInfo {
+                    open: true,
+                    ..Default::default()
+                });
+            }
+        }
+        TunnelEvent::WebSocketFrames {
+            request_id,
+            frames_in,
+            frames_out,
+        } => {
+            if let Some(ws) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+                .and_then(|r| r.websocket.as_mut())
+            {
+                ws.frames_in += frames_in;
+                ws.frames_out += frames_out;
+            }
+        }
+        TunnelEvent::WebSocketClosed {
+            request_id,
+            code,
+            reason,
+        } => {
+            if let Some(req) = app
+                .tunnel_requests
+                .iter_mut()
+                .find(|r| r.request_id == request_id)
+            {
+                req.completed_at = Some(std::time::Instant::now());
+                if let Some(ws) = req.websocket.as_mut() {
+                    ws.open = false;
+                    ws.close_code = code;
+                }
+                // 1011 marks a relay failure rather than a normal close
+                if code == Some(1011) {
+                    req.error = reason;
+                }
+            }
+            if code == Some(1011) {
+                app.tunnel_stats.failed += 1;
+            } else {
+                app.tunnel_stats.success += 1;
+            }
+        }
+        TunnelEvent::ForwardSuccess => {
+            app.listening_stats.successful_forwards += 1;
+        }
+        TunnelEvent::ForwardError { .. } => {
+            app.listening_stats.failed_forwards += 1;
+        }
+        TunnelEvent::Reconnecting {
+            attempt,
+            max_attempts,
+            next_retry_in_secs,
+        } => {
+            let msg = format!(
+                "Reconnecting (attempt {}/{})... next retry in {}s",
+                attempt, max_attempts, next_retry_in_secs
+            );
+            app.listening_connected = false;
+            app.listening_error = Some(msg.clone());
+            app.tunnel_connected = false;
+            app.tunnel_error = Some(msg);
+        }
+        TunnelEvent::TokenRefreshed => {
+            app.token_refreshed_at = Some(chrono::Local::now());
+        }
+        TunnelEvent::InFlight {
+            active,
+            queued,
+            max,
+        } => {
+            app.in_flight = app::InFlightStats {
+                active,
+                queued,
+                max,
+            };
+        }
+        TunnelEvent::ReconnectFailed { reason, .. } => {
+            let msg = format!("Connection lost: {}", reason);
+            app.listening_connected = false;
+            app.listening_error = Some(msg.clone());
+            app.tunnel_connected = false;
+            app.tunnel_error = Some(msg);
+        }
+    }
+    if let Some(request_id) = request_id {
+        app.publish_to_inspector(&request_id);
+    }
+}
+
 async fn run_app<B: ratatui::backend::Backend + Send>(
     terminal: &mut Terminal<B>,
-    app: &mut App,
-    mut tunnel_rx: mpsc::Receiver<TunnelEvent>,
-    tunnel_reconnect_tx: Option<mpsc::UnboundedSender<()>>,
+    sessions: &mut [Session],
 ) -> Result<()>
 where
     <B as ratatui::backend::Backend>::Error: std::error::Error + Send + Sync + 'static,
 {
     // Ensure proper terminal cleanup on any exit
     let _cleanup = TerminalCleanup;
+    let mut active = 0;
 
     loop {
-        terminal.draw(|frame| ui::draw(frame, app))?;
+        let titles: Vec<String> = if sessions.len() > 1 {
+            sessions.iter().map(Session::tab_title).collect()
+        } else {
+            Vec::new()
+        };
+        terminal.draw(|frame| ui::draw_sessions(frame, &sessions[active].app, &titles, active))?;
 
         // Update animations
-        app.tick();
+        for session in sessions.iter_mut() {
+            session.app.tick();
+        }
 
-        if app.should_quit {
+        if sessions.iter().any(|session| session.app.should_quit) {
             break;
         }
 
         // Handle tunnel events
-        while let Ok(event) = tunnel_rx.try_recv() {
-            match event {
-                TunnelEvent::Connecting => {
-                    // Update UI to show connecting state
-                }
-                TunnelEvent::Connected => {
-                    app.listening_connected = true;
-                    app.listening_error = None;
-                    app.tunnel_connected = true;
-                    app.tunnel_connected_at = Some(std::time::Instant::now());
-                }
-                TunnelEvent::TunnelEstablished {
-                    subdomain,
-                    tunnel_id,
-                    is_static,
-                } => {
-                    app.tunnel_subdomain = Some(subdomain);
-                    app.tunnel_id = Some(tunnel_id);
-                    app.tunnel_is_static = is_static;
-                    app.tunnel_connected = true;
-                    app.tunnel_connected_at = Some(std::time::Instant::now());
-                }
-                TunnelEvent::ConnectionError(err) => {
-                    app.listening_connected = false;
-                    app.listening_error = Some(err.clone());
-                    app.tunnel_connected = false;
-                    app.tunnel_error = Some(err);
-                }
-                TunnelEvent::Disconnected => {
-                    app.listening_connected = false;
-                    app.tunnel_connected = false;
-                }
-                TunnelEvent::WebhookReceived(request) => {
-                    app.listening_requests.push(*request);
-                    app.listening_stats.total_requests += 1;
-                }
-                TunnelEvent::RequestReceived {
-                    request_id,
-                    method,
-                    path,
-                    headers,
-                    body,
-                    query_string,
-                } => {
-                    use std::time::Instant;
-                    let tunnel_request = app::TunnelRequest {
-                        request_id,
-                        method,
-                        path,
-                        received_at: Instant::now(),
-                        status: None,
-                        completed_at: None,
-                        error: None,
-                        headers,
-                        body: app::truncate_body(body),
-                        query_string,
-                        response_headers: None,
-                        response_body: None,
-                    };
-                    app.tunnel_requests.push_back(tunnel_request);
-                    if app.tunnel_requests.len() > app::MAX_TUNNEL_REQUESTS {
-                        app.tunnel_requests.pop_front();
-                        // Clamp selected index if it now exceeds the new length
-                        if !app.tunnel_requests.is_empty() {
-                            app.tunnel_selected_index =
-                                app.tunnel_selected_index.min(app.tunnel_requests.len() - 1);
-                        }
-                    }
-                    app.tunnel_stats.total += 1;
-                }
-                TunnelEvent::RequestForwarded {
-                    request_id,
-                    status,
-                    duration_ms,
-                    response_headers,
-                    response_body,
-                } => {
-                    // Update the request in the list
-                    if let Some(req) = app
-                        .tunnel_requests
-                        .iter_mut()
-                        .find(|r| r.request_id == request_id)
-                    {
-                        req.status = Some(status);
-                        req.completed_at = Some(std::time::Instant::now());
-                        req.response_headers = Some(response_headers);
-                        req.response_body = app::truncate_body(response_body);
-                    }
-                    app.tunnel_stats.success += 1;
-                    app.tunnel_stats.total_duration_ms += duration_ms;
-                }
-                TunnelEvent::RequestFailed { request_id, error } => {
-                    // Update the request in the list
-                    if let Some(req) = app
-                        .tunnel_requests
-                        .iter_mut()
-                        .find(|r| r.request_id == request_id)
-                    {
-                        req.error = Some(error);
-                        req.completed_at = Some(std::time::Instant::now());
-                    }
-                    app.tunnel_stats.failed += 1;
-                }
-                TunnelEvent::ForwardSuccess => {
-                    app.listening_stats.successful_forwards += 1;
-                }
-                TunnelEvent::ForwardError => {
-                    app.listening_stats.failed_forwards += 1;
-                }
-                TunnelEvent::Reconnecting {
-                    attempt,
-                    max_attempts,
-                    next_retry_in_secs,
-                } => {
-                    let msg = format!(
-                        "Reconnecting (attempt {}/{})... next retry in {}s",
-                        attempt, max_attempts, next_retry_in_secs
-                    );
-                    app.listening_connected = false;
-                    app.listening_error = Some(msg.clone());
-                    app.tunnel_connected = false;
-                    app.tunnel_error = Some(msg);
-                }
-                TunnelEvent::ReconnectFailed { reason } => {
-                    let msg = format!("Connection lost: {}", reason);
-                    app.listening_connected = false;
-                    app.listening_error = Some(msg.clone());
-                    app.tunnel_connected = false;
-                    app.tunnel_error = Some(msg);
-                }
+        for session in sessions.iter_mut() {
+            while let Ok(event) = session.events.try_recv() {
+                apply_tunnel_event(&mut session.app, event);
             }
         }
 
         // Handle async states that don't require user input
+        let app = &mut sessions[active].app;
         if matches!(app.state, AppState::ForwardingRequest) {
             app.forward_request().await?;
             continue;
@@ -2631,13 +4293,31 @@ where
             && let Event::Key(key) = event::read()?
             && key.kind == KeyEventKind::Press
         {
+            if sessions.len() > 1 && sessions[active].on_main_screen() {
+                match key.code {
+                    KeyCode::Tab => {
+                        active = (active + 1) % sessions.len();
+                        continue;
+                    }
+                    KeyCode::BackTab => {
+                        active = (active + sessions.len() - 1) % sessions.len();
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            let session = &mut sessions[active];
+            let app = &mut session.app;
             app.handle_key_event(key)?;
 
             if app.take_tunnel_reconnect_request()
-                && let Some(tx) = tunnel_reconnect_tx.as_ref()
+                && let Some(tx) = session.reconnect_tx.as_ref()
                 && tx.send(()).is_err()
             {
-                app.tunnel_error = Some("Failed to request tunnel reconnect".to_string());
+                let msg = "Failed to request reconnect".to_string();
+                app.listening_error = Some(msg.clone());
+                app.tunnel_error = Some(msg);
             }
 
             if matches!(app.state, AppState::ForwardingRequest) {
@@ -2660,19 +4340,42 @@ fn style_status_code(code: u16) -> String {
 }
 
 fn error_hint(err: &anyhow::Error) -> Option<&str> {
-    if let Some(e) = err.downcast_ref::<errors::ApiError>() {
-        return e.hint();
-    }
-    if let Some(e) = err.downcast_ref::<errors::TunnelError>() {
-        return e.hint();
-    }
-    if let Some(e) = err.downcast_ref::<errors::ConfigError>() {
-        return e.hint();
-    }
-    if let Some(e) = err.downcast_ref::<errors::UpdateError>() {
-        return e.hint();
+    err.chain().find_map(|cause| {
+        if let Some(e) = cause.downcast_ref::<errors::CliError>() {
+            return e.hint();
+        }
+        if let Some(e) = cause.downcast_ref::<errors::ApiError>() {
+            return e.hint();
+        }
+        if let Some(e) = cause.downcast_ref::<errors::TunnelError>() {
+            return e.hint();
+        }
+        if let Some(e) = cause.downcast_ref::<errors::ConfigError>() {
+            return e.hint();
+        }
+        if let Some(e) = cause.downcast_ref::<errors::UpdateError>() {
+            return e.hint();
+        }
+        None
+    })
+}
+
+/// Report `err` and exit with the code for its class. With `--json` the
+/// report is a JSON object on stderr, so stdout stays machine-readable.
+fn exit_with_error(err: &anyhow::Error, json: bool) -> ! {
+    let kind = errors::ErrorKind::of(err);
+    if json {
+        let report = serde_json::json!({
+            "code": kind,
+            "exit_code": kind.exit_code(),
+            "message": format!("{:#}", err),
+            "hint": error_hint(err)
+        });
+        eprintln!("{}", report);
+    } else {
+        display_error(err);
     }
-    None
+    std::process::exit(kind.exit_code());
 }
 
 fn display_error(err: &anyhow::Error) {
@@ -2726,35 +4429,58 @@ mod tests {
     #[test]
     fn resolve_tunnel_org_prefers_cli_arg() {
         let config = make_config(Some("org-config"));
-        let resolved = resolve_tunnel_org(Some("org-cli".to_string()), &config);
+        let resolved = resolve_tunnel_org(
+            Some("org-cli".to_string()),
+            &project::ProjectConfig::default(),
+            &config,
+        );
         assert_eq!(resolved.as_deref(), Some("org-cli"));
     }
 
     #[test]
     fn resolve_tunnel_org_falls_back_to_config() {
         let config = make_config(Some("org-config"));
-        let resolved = resolve_tunnel_org(None, &config);
+        let resolved = resolve_tunnel_org(None, &project::ProjectConfig::default(), &config);
         assert_eq!(resolved.as_deref(), Some("org-config"));
     }
 
+    #[test]
+    fn resolve_tunnel_org_prefers_project_over_config() {
+        let config = make_config(Some("org-config"));
+        let project = project::ProjectConfig {
+            organization: Some("org-project".to_string()),
+            ..Default::default()
+        };
+        let resolved = resolve_tunnel_org(None, &project, &config);
+        assert_eq!(resolved.as_deref(), Some("org-project"));
+        let resolved = resolve_tunnel_org(Some("org-cli".to_string()), &project, &config);
+        assert_eq!(resolved.as_deref(), Some("org-cli"));
+    }
+
     #[test]
     fn resolve_tunnel_org_none_when_not_set() {
         let config = make_config(None);
-        let resolved = resolve_tunnel_org(None, &config);
+        let resolved = resolve_tunnel_org(None, &project::ProjectConfig::default(), &config);
         assert!(resolved.is_none());
     }
 
     #[test]
     fn require_organization_uses_cli_value() {
         let config = make_config(Some("org-config"));
-        let org = require_organization(Some("org-cli".to_string()), &config).unwrap();
+        let org = require_organization(
+            Some("org-cli".to_string()),
+            &project::ProjectConfig::default(),
+            &config,
+        )
+        .unwrap();
         assert_eq!(org, "org-cli");
     }
 
     #[test]
     fn require_organization_errors_when_missing() {
         let config = make_config(None);
-        let err = require_organization(None, &config).unwrap_err();
+        let err =
+            require_organization(None, &project::ProjectConfig::default(), &config).unwrap_err();
         assert!(
             err.to_string().contains("No organization selected"),
             "unexpected error: {}",
@@ -2773,6 +4499,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn only_a_rejected_refresh_ends_the_session() {
+        assert!(is_rejected_refresh(&no_refresh_token()));
+        assert!(is_rejected_refresh(&errors::ApiError::Unauthorized.into()));
+        assert!(!is_rejected_refresh(
+            &errors::ApiError::NetworkError("connection refused".to_string()).into()
+        ));
+        let err: anyhow::Error = errors::ApiError::ServerError { status: 502 }.into();
+        assert!(!is_rejected_refresh(&err));
+        assert_eq!(errors::ErrorKind::of(&err), errors::ErrorKind::Network);
+    }
+
+    #[test]
+    fn format_expiry_counts_down_and_up() {
+        let now = Utc::now();
+        assert!(
+            format_expiry(now + ChronoDuration::minutes(125), now)
+                .starts_with("expires in 2h 5m (")
+        );
+        assert!(
+            format_expiry(
+                now + ChronoDuration::days(29) + ChronoDuration::hours(3),
+                now
+            )
+            .starts_with("expires in 29d 3h (")
+        );
+        assert!(
+            format_expiry(now - ChronoDuration::minutes(3), now).starts_with("expired 3m ago (")
+        );
+    }
+
+    #[test]
+    fn parse_local_target_defaults_port_from_scheme() {
+        assert_eq!(
+            parse_local_target("https://localhost").unwrap(),
+            ("localhost".to_string(), 443)
+        );
+        assert_eq!(
+            parse_local_target("https://127.0.0.1:8443/app").unwrap(),
+            ("127.0.0.1".to_string(), 8443)
+        );
+        assert!(parse_local_target("ftp://localhost").is_err());
+        assert!(parse_local_target("localhost:3000").is_err());
+    }
+
     #[test]
     fn normalize_http_method_accepts_lowercase() {
         let method = normalize_http_method(Some("post".to_string())).unwrap();
@@ -0,0 +1,297 @@
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+use crate::models::Headers;
+use crate::rules::{RequestMatcher, rule_list};
+
+/// When a mock rule answers a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MockMode {
+    /// Answer every matching request without contacting the local target
+    #[default]
+    Always,
+    /// Answer only when the local target refuses the connection
+    Fallback,
+}
+
+/// A canned response for tunnel requests matching the rule.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MockRule {
+    #[serde(flatten)]
+    pub matcher: RequestMatcher,
+    #[serde(default)]
+    pub mode: MockMode,
+    #[serde(default = "default_mock_status")]
+    pub status: u16,
+    /// Response headers; values may use templates, repeated names take a list
+    #[serde(default)]
+    pub headers: Headers,
+    /// Response body template, e.g. `{"id": "{{body.id}}", "path": "{{path}}"}`
+    #[serde(default)]
+    pub body: String,
+    /// Wait this long before answering, to simulate a slow service
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+fn default_mock_status() -> u16 {
+    200
+}
+
+impl MockRule {
+    fn validate(&self) -> Result<()> {
+        if !self.matcher.has_valid_path() {
+            return Err(anyhow!(
+                "Invalid mock path '{}': must start with '/' or '*'",
+                self.matcher.path
+            ));
+        }
+        if !(100..=599).contains(&self.status) {
+            return Err(anyhow!(
+                "Invalid mock status {}: must be between 100 and 599",
+                self.status
+            ));
+        }
+        Ok(())
+    }
+
+    /// Render the response for a request, interpolating template fields.
+    pub fn render(&self, request: &MockRequest) -> MockResponse {
+        MockResponse {
+            status: self.status,
+            headers: self
+                .headers
+                .iter()
+                .map(|(k, v)| (k.clone(), render_template(v, request)))
+                .collect(),
+            body: render_template(&self.body, request),
+        }
+    }
+}
+
+/// The parts of a tunnel request available to response templates.
+#[derive(Debug, Clone, Default)]
+pub struct MockRequest {
+    pub request_id: String,
+    pub method: String,
+    pub path: String,
+    pub query_string: String,
+    pub headers: Headers,
+    pub body: String,
+}
+
+impl MockRequest {
+    /// Resolve a template expression; `None` means the expression is not recognised.
+    fn lookup(&self, expr: &str) -> Option<String> {
+        match expr {
+            "request_id" => return Some(self.request_id.clone()),
+            "method" => return Some(self.method.clone()),
+            "path" => return Some(self.path.clone()),
+            "query" => return Some(self.query_string.clone()),
+            "body" => return Some(self.body.clone()),
+            _ => {}
+        }
+
+        if let Some(name) = expr.strip_prefix("header.") {
+            return Some(self.headers.get(name).unwrap_or_default().to_string());
+        }
+        if let Some(name) = expr.strip_prefix("query.") {
+            let params = crate::app::parse_query_string(&self.query_string);
+            return Some(params.get(name).cloned().unwrap_or_default());
+        }
+        if let Some(field_path) = expr.strip_prefix("body.") {
+            return Some(json_field(&self.body, field_path).unwrap_or_default());
+        }
+        None
+    }
+}
+
+/// Look up a dotted path (`user.emails.0`) in a JSON body.
+fn json_field(body: &str, field_path: &str) -> Option<String> {
+    let root: serde_json::Value = serde_json::from_str(body).ok()?;
+    let mut value = &root;
+    for key in field_path.split('.') {
+        value = match value {
+            serde_json::Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+            _ => value.get(key)?,
+        };
+    }
+    Some(match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    })
+}
+
+/// Replace `{{ expr }}` placeholders; unknown expressions are left as written.
+pub fn render_template(template: &str, request: &MockRequest) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let placeholder = &rest[start..start + 2 + end + 2];
+        match request.lookup(after[..end].trim()) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(placeholder),
+        }
+        rest = &after[end + 2..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// A rendered mock response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Headers,
+    pub body: String,
+}
+
+/// Ordered mock rules; the first matching rule wins.
+#[derive(Debug, Clone, Default)]
+pub struct MockSet {
+    rules: Vec<MockRule>,
+}
+
+impl MockSet {
+    /// Load rules from a YAML (`.yaml`/`.yml`) or JSON file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read mocks file {}", path.display()))?;
+        let is_yaml = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+        let file: serde_json::Value = if is_yaml {
+            serde_yaml::from_str(&content)
+                .with_context(|| format!("Failed to parse mocks file {}", path.display()))?
+        } else {
+            serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse mocks file {}", path.display()))?
+        };
+        let rules: Vec<MockRule> = rule_list(file, "mocks")
+            .with_context(|| format!("Failed to parse mocks file {}", path.display()))?;
+        for rule in &rules {
+            rule.validate()?;
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// First rule with the given mode that matches the request.
+    pub fn find(
+        &self,
+        mode: MockMode,
+        method: &str,
+        path: &str,
+        headers: &Headers,
+    ) -> Option<&MockRule> {
+        self.rules
+            .iter()
+            .find(|rule| rule.mode == mode && rule.matcher.matches(method, path, headers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> MockRequest {
+        let headers: Headers = [("X-Source", "stripe")].into_iter().collect();
+        MockRequest {
+            request_id: "req-1".to_string(),
+            method: "POST".to_string(),
+            path: "/orders/42".to_string(),
+            query_string: "page=2".to_string(),
+            headers,
+            body: r#"{"order":{"id":42,"items":["a","b"]}}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_template_fields() {
+        let request = request();
+        assert_eq!(
+            render_template("{{method}} {{ path }}?{{query}}", &request),
+            "POST /orders/42?page=2"
+        );
+        assert_eq!(
+            render_template("{{header.x-source}}/{{query.page}}", &request),
+            "stripe/2"
+        );
+        assert_eq!(
+            render_template("{{body.order.id}} {{body.order.items.1}}", &request),
+            "42 b"
+        );
+        assert_eq!(render_template("{{body.missing}}|", &request), "|");
+        assert_eq!(
+            render_template("{{unknown}} {{", &request),
+            "{{unknown}} {{"
+        );
+    }
+
+    #[test]
+    fn test_find_respects_mode_and_order() {
+        let rules: Vec<MockRule> = serde_json::from_str(
+            r#"[
+                {"path": "/orders/*", "method": "POST", "status": 201, "body": "{{body.order.id}}"},
+                {"path": "*", "mode": "fallback", "status": 503}
+            ]"#,
+        )
+        .unwrap();
+        let mocks = MockSet { rules };
+        let headers = Headers::new();
+
+        let rule = mocks
+            .find(MockMode::Always, "POST", "/orders/42", &headers)
+            .unwrap();
+        assert_eq!(rule.render(&request()).body, "42");
+        assert!(
+            mocks
+                .find(MockMode::Always, "GET", "/orders/42", &headers)
+                .is_none()
+        );
+
+        let fallback = mocks
+            .find(MockMode::Fallback, "GET", "/", &headers)
+            .unwrap();
+        assert_eq!(fallback.status, 503);
+    }
+
+    #[test]
+    fn test_load_yaml_and_json_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        let yaml = dir.path().join("mocks.yaml");
+        fs::write(
+            &yaml,
+            "mocks:\n  - path: /health\n    body: ok\n    headers:\n      content-type: text/plain\n",
+        )
+        .unwrap();
+        let mocks = MockSet::load(&yaml).unwrap();
+        let rule = mocks
+            .find(MockMode::Always, "GET", "/health", &Headers::new())
+            .unwrap();
+        assert_eq!(rule.status, 200);
+        assert_eq!(rule.headers.get("content-type"), Some("text/plain"));
+
+        let json = dir.path().join("mocks.json");
+        fs::write(&json, r#"[{"path": "health", "status": 200}]"#).unwrap();
+        assert!(MockSet::load(&json).is_err());
+
+        fs::write(&json, r#"[{"status": 700}]"#).unwrap();
+        assert!(MockSet::load(&json).is_err());
+    }
+}
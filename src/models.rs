@@ -1,12 +1,396 @@
-use serde::{Deserialize, Serialize};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
+
+/// HTTP headers in arrival order, keeping repeated names such as `Set-Cookie`.
+///
+/// Serializes as a JSON object where a repeated name maps to an array of values,
+/// and deserializes from that form or from a list of `[name, value]` pairs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Headers(Vec<(String, String)>);
+
+impl Headers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a value, keeping any existing values for the same name.
+    pub fn push(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.push((name.into(), value.into()));
+    }
+
+    /// First value for a name, compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Every value for a name, in order, compared case-insensitively.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.0
+            .iter()
+            .filter(move |(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, (String, String)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// One string per header name, with repeated values joined by ", ", for
+    /// tunnel messages to the server. The tunnel protocol carries a single
+    /// value per name, so `Set-Cookie` values are joined as well.
+    pub fn to_wire(&self) -> serde_json::Map<String, serde_json::Value> {
+        let mut map = serde_json::Map::new();
+        for (name, value) in &self.0 {
+            let existing = map
+                .iter_mut()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v);
+            match existing {
+                Some(serde_json::Value::String(joined)) => {
+                    joined.push_str(", ");
+                    joined.push_str(value);
+                }
+                _ => {
+                    map.insert(name.clone(), serde_json::Value::String(value.clone()));
+                }
+            }
+        }
+        map
+    }
+
+    /// Collect headers from a reqwest header map, skipping values that are not valid UTF-8.
+    pub fn from_header_map(map: &reqwest::header::HeaderMap) -> Self {
+        map.iter()
+            .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+            .collect()
+    }
+
+    /// Read headers from a JSON object whose values are strings or arrays of strings.
+    pub fn from_json(map: &serde_json::Map<String, serde_json::Value>) -> Self {
+        let mut headers = Headers::new();
+        for (name, value) in map {
+            headers.push_json(name, value);
+        }
+        headers
+    }
+
+    /// Append a JSON header value; an array adds one value per element.
+    fn push_json(&mut self, name: &str, value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Array(values) => {
+                for value in values {
+                    self.push_json(name, value);
+                }
+            }
+            serde_json::Value::String(s) => self.push(name, s.clone()),
+            other => self.push(name, other.to_string()),
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Headers {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+impl IntoIterator for Headers {
+    type Item = (String, String);
+    type IntoIter = std::vec::IntoIter<(String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Headers {
+    type Item = &'a (String, String);
+    type IntoIter = std::slice::Iter<'a, (String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Serialize for Headers {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Group values by exact name, in order of first appearance
+        let mut grouped: Vec<(&str, Vec<&str>)> = Vec::new();
+        for (name, value) in &self.0 {
+            match grouped.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, values)) => values.push(value),
+                None => grouped.push((name, vec![value])),
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(grouped.len()))?;
+        for (name, values) in grouped {
+            match values.as_slice() {
+                [single] => map.serialize_entry(name, single)?,
+                many => map.serialize_entry(name, many)?,
+            }
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Headers {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(HeadersVisitor)
+    }
+}
+
+struct HeadersVisitor;
+
+impl<'de> Visitor<'de> for HeadersVisitor {
+    type Value = Headers;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of header names to values, or a list of [name, value] pairs")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Headers, A::Error> {
+        let mut headers = Headers::new();
+        while let Some((name, value)) = access.next_entry::<String, serde_json::Value>()? {
+            headers.push_json(&name, &value);
+        }
+        Ok(headers)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Headers, A::Error> {
+        let mut headers = Headers::new();
+        while let Some((name, value)) = access.next_element::<(String, String)>()? {
+            headers.push(name, value);
+        }
+        Ok(headers)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Headers, E> {
+        Ok(Headers::new())
+    }
+}
+
+/// Encode body bytes for a JSON payload: raw when valid UTF-8, base64 otherwise.
+///
+/// Returns the text and its `body_encoding` marker (`raw` or `base64`).
+pub fn encode_body(bytes: &[u8]) -> (String, &'static str) {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text.to_string(), "raw"),
+        Err(_) => (URL_SAFE_NO_PAD.encode(bytes), "base64"),
+    }
+}
+
+/// Decode body text using its `body_encoding` marker.
+pub fn decode_body(text: &str, encoding: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    if encoding == "base64" {
+        let padded = text.trim_end_matches('=');
+        URL_SAFE_NO_PAD.decode(padded.replace('+', "-").replace('/', "_"))
+    } else {
+        Ok(text.as_bytes().to_vec())
+    }
+}
+
+/// A request or response body kept as the exact bytes received.
+///
+/// Serializes as a plain string when the bytes are valid UTF-8, otherwise as
+/// `{"encoding": "base64", "data": "..."}`, so exports reproduce binary payloads.
+/// A body cut by [`Body::truncate`] keeps only the leading bytes and remembers
+/// how long it was; the cut is only marked in [`Body::display`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Body {
+    bytes: Vec<u8>,
+    /// Length received, when only a prefix was kept
+    original_len: Option<usize>,
+}
+
+/// Appended to the displayed text of a truncated body.
+pub const TRUNCATED_MARKER: &str = "\n...(truncated)";
+
+impl Body {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Whether only a prefix of the received body was kept.
+    pub fn is_truncated(&self) -> bool {
+        self.original_len.is_some()
+    }
+
+    /// Size of the body as received, before any truncation.
+    pub fn original_len(&self) -> usize {
+        self.original_len.unwrap_or(self.bytes.len())
+    }
+
+    /// The body as text, or `None` when it is not valid UTF-8.
+    pub fn as_text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.bytes).ok()
+    }
+
+    /// Whether the body is not readable text: invalid UTF-8 or control characters
+    /// other than whitespace (e.g. NUL bytes in a protobuf payload).
+    pub fn is_binary(&self) -> bool {
+        self.as_text()
+            .is_none_or(|text| text.chars().any(|c| c.is_control() && !c.is_whitespace()))
+    }
+
+    /// Text for the TUI: the body itself, or a hex dump when it is binary, with
+    /// the cut marked when the body was truncated.
+    pub fn display(&self) -> Cow<'_, str> {
+        let text = match self.as_text() {
+            Some(text) if !self.is_binary() => Cow::Borrowed(text),
+            _ => Cow::Owned(hex_dump(&self.bytes)),
+        };
+        if self.is_truncated() {
+            Cow::Owned(format!("{}{}", text, TRUNCATED_MARKER))
+        } else {
+            text
+        }
+    }
+
+    /// Keep at most `max` bytes; text is cut on a character boundary.
+    pub fn truncate(mut self, max: usize) -> Self {
+        if self.bytes.len() <= max {
+            return self;
+        }
+        // Text cut mid-character (a streamed preview) still counts as text
+        let text = match std::str::from_utf8(&self.bytes) {
+            Ok(text) => Some(text),
+            Err(e) if e.error_len().is_none() => {
+                std::str::from_utf8(&self.bytes[..e.valid_up_to()]).ok()
+            }
+            Err(_) => None,
+        };
+        let mut cutoff = max;
+        if let Some(text) = text {
+            cutoff = cutoff.min(text.len());
+            while !text.is_char_boundary(cutoff) {
+                cutoff -= 1;
+            }
+        }
+        self.original_len = Some(self.original_len());
+        self.bytes.truncate(cutoff);
+        self
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            original_len: None,
+        }
+    }
+}
+
+impl From<String> for Body {
+    fn from(text: String) -> Self {
+        Self::from(text.into_bytes())
+    }
+}
+
+impl From<&str> for Body {
+    fn from(text: &str) -> Self {
+        Self::from(text.as_bytes().to_vec())
+    }
+}
+
+impl Serialize for Body {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match encode_body(&self.bytes) {
+            (text, "raw") => serializer.serialize_str(&text),
+            (data, encoding) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("encoding", encoding)?;
+                map.serialize_entry("data", &data)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Body {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Text(String),
+            Encoded { encoding: String, data: String },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => Ok(Body::from(text)),
+            Repr::Encoded { encoding, data } => decode_body(&data, &encoding)
+                .map(Body::from)
+                .map_err(de::Error::custom),
+        }
+    }
+}
+
+/// Format bytes as `offset  hex bytes  |ascii|` lines, 16 bytes per line.
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08x}  {:<47}  |{}|", i * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebhookRequest {
     pub id: String,
     pub timestamp: i64,
     pub remote_addr: String,
-    pub headers: HashMap<String, String>,
+    pub headers: Headers,
     pub content_length: i64,
     pub method: String,
     pub url: String,
@@ -16,16 +400,109 @@ pub struct WebhookRequest {
     pub created_at: String,
     pub body_preview: Option<String>,
     #[serde(default)]
-    pub body: Option<String>, // Full body content (fetched separately)
+    pub body: Option<Body>, // Full body content (fetched separately)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ForwardResponse {
     pub success: bool,
     pub status_code: Option<u16>,
-    pub headers: HashMap<String, String>,
-    pub body: String,
+    pub headers: Headers,
+    pub body: Body,
     pub error_message: Option<String>,
     pub target_url: String,
     pub duration_ms: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_keep_order_and_duplicates() {
+        let headers: Headers = [
+            ("Via", "1.1 a"),
+            ("Set-Cookie", "a=1"),
+            ("Via", "1.1 b"),
+            ("Set-Cookie", "b=2"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(headers.get("via"), Some("1.1 a"));
+        assert_eq!(
+            headers.get_all("set-cookie").collect::<Vec<_>>(),
+            vec!["a=1", "b=2"]
+        );
+        assert!(!headers.contains("host"));
+    }
+
+    #[test]
+    fn test_headers_json_round_trip() {
+        let headers: Headers = [
+            ("content-type", "application/json"),
+            ("set-cookie", "a=1"),
+            ("set-cookie", "b=2"),
+        ]
+        .into_iter()
+        .collect();
+
+        let json = serde_json::to_string(&headers).unwrap();
+        assert_eq!(
+            json,
+            r#"{"content-type":"application/json","set-cookie":["a=1","b=2"]}"#
+        );
+        assert_eq!(serde_json::from_str::<Headers>(&json).unwrap(), headers);
+
+        let pairs: Headers = serde_json::from_str(r#"[["x-a","1"],["x-a","2"]]"#).unwrap();
+        assert_eq!(pairs.get_all("x-a").count(), 2);
+    }
+
+    #[test]
+    fn test_headers_wire_format_joins_values() {
+        let headers: Headers = [
+            ("content-type", "text/plain"),
+            ("Via", "1.1 a"),
+            ("via", "1.1 b"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            serde_json::Value::Object(headers.to_wire()),
+            serde_json::json!({"content-type": "text/plain", "Via": "1.1 a, 1.1 b"})
+        );
+    }
+
+    #[test]
+    fn test_body_json_round_trip_keeps_bytes() {
+        let text = Body::from("{\"ok\":true}");
+        assert_eq!(serde_json::to_string(&text).unwrap(), r#""{\"ok\":true}""#);
+
+        let binary = Body::from(vec![0x1f, 0x8b, 0x08, 0x00, 0xff]);
+        let json = serde_json::to_value(&binary).unwrap();
+        assert_eq!(json["encoding"], "base64");
+        assert_eq!(serde_json::from_value::<Body>(json).unwrap(), binary);
+
+        // Standard (padded) base64 is accepted too
+        let padded: Body =
+            serde_json::from_str(r#"{"encoding":"base64","data":"H4sIAP8="}"#).unwrap();
+        assert_eq!(padded, binary);
+    }
+
+    #[test]
+    fn test_body_display_and_truncate() {
+        let binary = Body::from(b"PK\x03\x04hello".to_vec());
+        assert!(binary.is_binary());
+        assert_eq!(
+            binary.display(),
+            "00000000  50 4b 03 04 68 65 6c 6c 6f                       |PK..hello|"
+        );
+
+        let text = Body::from("héllo").truncate(2);
+        assert_eq!(text.as_text(), Some("h"));
+        assert!(text.is_truncated());
+        assert_eq!(text.original_len(), 6);
+        assert_eq!(text.display(), "h\n...(truncated)");
+    }
+}
@@ -0,0 +1,445 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::models::{Body, Headers};
+use crate::sessions::UpSessions;
+
+/// Project config file, discovered by walking up from the working directory.
+pub const PROJECT_FILE_NAME: &str = ".hooklistener.toml";
+
+/// Defaults used when neither a flag nor the project file sets a value.
+pub const DEFAULT_LISTEN_TARGET: &str = "http://localhost:3000";
+pub const DEFAULT_TUNNEL_HOST: &str = "localhost";
+pub const DEFAULT_TUNNEL_PORT: u16 = 3000;
+
+/// Replacement for redacted header values and body fields.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Settings shared by everyone working in a repository.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Organization ID used when `--org` is not given
+    #[serde(default)]
+    pub organization: Option<String>,
+    #[serde(default)]
+    pub listen: ListenDefaults,
+    #[serde(default)]
+    pub tunnel: TunnelDefaults,
+    #[serde(default)]
+    pub replay: ReplayTargets,
+    #[serde(default)]
+    pub redact: RedactRules,
+    #[serde(default)]
+    pub up: UpSessions,
+}
+
+/// `[listen]`: defaults for `hooklistener listen`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListenDefaults {
+    /// Debug endpoint slug
+    pub endpoint: Option<String>,
+    /// Local URL to forward to
+    pub target: Option<String>,
+}
+
+/// `[tunnel]`: defaults for `hooklistener tunnel`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TunnelDefaults {
+    pub port: Option<u16>,
+    pub host: Option<String>,
+    pub slug: Option<String>,
+}
+
+/// `[replay]`: where captured requests are replayed to.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayTargets {
+    /// URL, or name from `targets`, used when no target is given
+    pub default: Option<String>,
+    /// Named target URLs, e.g. `staging = "https://staging.example.com/hooks"`
+    #[serde(default)]
+    pub targets: BTreeMap<String, String>,
+}
+
+/// `[redact]`: values hidden from the TUI, the inspector and `--no-tui` output.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RedactRules {
+    /// Header names, compared case-insensitively
+    #[serde(default)]
+    pub headers: Vec<String>,
+    /// JSON body field names, matched at any depth
+    #[serde(default)]
+    pub body_fields: Vec<String>,
+}
+
+/// The project config in effect and the file it came from, if any.
+#[derive(Debug, Clone, Default)]
+pub struct Project {
+    pub path: Option<PathBuf>,
+    pub config: ProjectConfig,
+}
+
+impl Project {
+    /// Find the nearest project file from the working directory upwards.
+    pub fn discover() -> Result<Self> {
+        let cwd = std::env::current_dir().context("Failed to read the working directory")?;
+        Self::discover_from(&cwd)
+    }
+
+    pub fn discover_from(dir: &Path) -> Result<Self> {
+        for ancestor in dir.ancestors() {
+            let path = ancestor.join(PROJECT_FILE_NAME);
+            if path.is_file() {
+                let config = ProjectConfig::load(&path)?;
+                return Ok(Self {
+                    path: Some(path),
+                    config,
+                });
+            }
+        }
+        Ok(Self::default())
+    }
+}
+
+/// Where an effective setting comes from, for `config show`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    /// The project file
+    Project,
+    /// The per-user config file
+    User,
+    /// Built-in default
+    Default,
+}
+
+impl Source {
+    pub fn label(self) -> &'static str {
+        match self {
+            Source::Project => "project",
+            Source::User => "user config",
+            Source::Default => "default",
+        }
+    }
+}
+
+/// A setting as `listen`, `tunnel` and friends see it without flags.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting {
+    pub key: &'static str,
+    pub value: Option<String>,
+    /// `None` when the setting is not set anywhere
+    pub source: Option<Source>,
+}
+
+impl Setting {
+    fn new(key: &'static str, layers: Vec<(Option<String>, Source)>) -> Self {
+        let found = layers
+            .into_iter()
+            .find_map(|(value, source)| value.map(|value| (value, source)));
+        Self {
+            key,
+            source: found.as_ref().map(|(_, source)| *source),
+            value: found.map(|(value, _)| value),
+        }
+    }
+}
+
+impl Project {
+    /// Effective settings with the layer each one comes from.
+    pub fn settings(&self, config: &Config) -> Vec<Setting> {
+        let project = &self.config;
+        let list = |values: &[String]| (!values.is_empty()).then(|| values.join(", "));
+        let targets = project
+            .replay
+            .targets
+            .iter()
+            .map(|(name, url)| format!("{}={}", name, url))
+            .collect::<Vec<_>>();
+        vec![
+            Setting::new(
+                "organization",
+                vec![
+                    (project.organization.clone(), Source::Project),
+                    (config.selected_organization_id.clone(), Source::User),
+                ],
+            ),
+            Setting::new(
+                "listen.endpoint",
+                vec![(project.listen.endpoint.clone(), Source::Project)],
+            ),
+            Setting::new(
+                "listen.target",
+                vec![
+                    (project.listen.target.clone(), Source::Project),
+                    (Some(DEFAULT_LISTEN_TARGET.to_string()), Source::Default),
+                ],
+            ),
+            Setting::new(
+                "tunnel.host",
+                vec![
+                    (project.tunnel.host.clone(), Source::Project),
+                    (Some(DEFAULT_TUNNEL_HOST.to_string()), Source::Default),
+                ],
+            ),
+            Setting::new(
+                "tunnel.port",
+                vec![
+                    (project.tunnel.port.map(|p| p.to_string()), Source::Project),
+                    (Some(DEFAULT_TUNNEL_PORT.to_string()), Source::Default),
+                ],
+            ),
+            Setting::new(
+                "tunnel.slug",
+                vec![(project.tunnel.slug.clone(), Source::Project)],
+            ),
+            Setting::new(
+                "replay.default",
+                vec![(project.replay.resolve(None), Source::Project)],
+            ),
+            Setting::new("replay.targets", vec![(list(&targets), Source::Project)]),
+            Setting::new(
+                "redact.headers",
+                vec![(list(&project.redact.headers), Source::Project)],
+            ),
+            Setting::new(
+                "redact.body_fields",
+                vec![(list(&project.redact.body_fields), Source::Project)],
+            ),
+        ]
+    }
+}
+
+impl ProjectConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read project config {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse project config {}", path.display()))
+    }
+}
+
+impl ReplayTargets {
+    /// Resolve a replay target: a name from `targets`, a URL, or the default when `None`.
+    pub fn resolve(&self, target: Option<&str>) -> Option<String> {
+        let target = target.or(self.default.as_deref())?;
+        Some(
+            self.targets
+                .get(target)
+                .cloned()
+                .unwrap_or_else(|| target.to_string()),
+        )
+    }
+}
+
+impl RedactRules {
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty() && self.body_fields.is_empty()
+    }
+
+    pub fn redact_headers(&self, headers: &mut Headers) {
+        if !headers
+            .iter()
+            .any(|(name, _)| self.headers.iter().any(|h| h.eq_ignore_ascii_case(name)))
+        {
+            return;
+        }
+        *headers = headers
+            .iter()
+            .map(|(name, value)| {
+                if self.headers.iter().any(|h| h.eq_ignore_ascii_case(name)) {
+                    (name.clone(), REDACTED.to_string())
+                } else {
+                    (name.clone(), value.clone())
+                }
+            })
+            .collect();
+    }
+
+    /// Replace matching fields of a JSON body; other bodies are left as they are.
+    pub fn redact_body(&self, body: &mut Option<Body>) {
+        if let Some(json) = body.as_ref().and_then(|b| self.redacted_json(b.as_bytes())) {
+            *body = Some(Body::from(json));
+        }
+    }
+
+    /// Like [`RedactRules::redact_body`], for a body already decoded as text.
+    pub fn redact_text(&self, text: &mut String) {
+        if let Some(json) = self.redacted_json(text.as_bytes()) {
+            *text = String::from_utf8_lossy(&json).into_owned();
+        }
+    }
+
+    /// The JSON document in `bytes` with matching fields replaced, if any matched.
+    fn redacted_json(&self, bytes: &[u8]) -> Option<Vec<u8>> {
+        if self.body_fields.is_empty() {
+            return None;
+        }
+        let mut value = serde_json::from_slice::<serde_json::Value>(bytes).ok()?;
+        if !self.redact_value(&mut value) {
+            return None;
+        }
+        serde_json::to_vec(&value).ok()
+    }
+
+    /// Returns whether anything was replaced.
+    fn redact_value(&self, value: &mut serde_json::Value) -> bool {
+        let mut changed = false;
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, field) in map.iter_mut() {
+                    if self.body_fields.iter().any(|f| f.eq_ignore_ascii_case(key)) {
+                        *field = serde_json::Value::String(REDACTED.to_string());
+                        changed = true;
+                    } else {
+                        changed |= self.redact_value(field);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    changed |= self.redact_value(item);
+                }
+            }
+            _ => {}
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover_walks_up_to_nearest_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(PROJECT_FILE_NAME),
+            r#"
+organization = "org-1"
+
+[listen]
+endpoint = "stripe-hooks"
+
+[tunnel]
+port = 8080
+
+[replay]
+default = "local"
+targets = { local = "http://localhost:3000/hooks" }
+
+[redact]
+headers = ["Authorization"]
+body_fields = ["password"]
+"#,
+        )
+        .unwrap();
+        let nested = dir.path().join("services").join("api");
+        fs::create_dir_all(&nested).unwrap();
+
+        let project = Project::discover_from(&nested).unwrap();
+        assert_eq!(project.path, Some(dir.path().join(PROJECT_FILE_NAME)));
+        assert_eq!(project.config.organization.as_deref(), Some("org-1"));
+        assert_eq!(
+            project.config.listen.endpoint.as_deref(),
+            Some("stripe-hooks")
+        );
+        assert_eq!(project.config.tunnel.port, Some(8080));
+        assert_eq!(project.config.tunnel.host, None);
+        assert_eq!(
+            project.config.replay.resolve(None).as_deref(),
+            Some("http://localhost:3000/hooks")
+        );
+        assert_eq!(
+            project
+                .config
+                .replay
+                .resolve(Some("https://example.com"))
+                .as_deref(),
+            Some("https://example.com")
+        );
+    }
+
+    #[test]
+    fn test_discover_without_file_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let project = Project::discover_from(dir.path()).unwrap();
+        assert!(project.path.is_none());
+        assert_eq!(project.config, ProjectConfig::default());
+    }
+
+    #[test]
+    fn test_settings_report_their_source() {
+        let project = Project {
+            path: None,
+            config: ProjectConfig {
+                tunnel: TunnelDefaults {
+                    port: Some(8080),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        };
+        let config = Config {
+            selected_organization_id: Some("org-user".to_string()),
+            ..Config::default()
+        };
+        let settings = project.settings(&config);
+        let find = |key: &str| settings.iter().find(|s| s.key == key).unwrap().clone();
+
+        let org = find("organization");
+        assert_eq!(org.value.as_deref(), Some("org-user"));
+        assert_eq!(org.source, Some(Source::User));
+        let port = find("tunnel.port");
+        assert_eq!(port.value.as_deref(), Some("8080"));
+        assert_eq!(port.source, Some(Source::Project));
+        let host = find("tunnel.host");
+        assert_eq!(host.value.as_deref(), Some("localhost"));
+        assert_eq!(host.source, Some(Source::Default));
+        assert_eq!(find("listen.endpoint").source, None);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_keys() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(PROJECT_FILE_NAME);
+        fs::write(&path, "[tunnel]\nprot = 8080\n").unwrap();
+        assert!(ProjectConfig::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_redact_headers_and_json_fields() {
+        let rules = RedactRules {
+            headers: vec!["authorization".to_string()],
+            body_fields: vec!["password".to_string()],
+        };
+        let mut headers: Headers = [("Authorization", "Bearer x"), ("Accept", "*/*")]
+            .into_iter()
+            .collect();
+        rules.redact_headers(&mut headers);
+        assert_eq!(headers.get("authorization"), Some(REDACTED));
+        assert_eq!(headers.get("accept"), Some("*/*"));
+
+        let mut body = Some(Body::from(
+            r#"{"user":{"name":"a","password":"secret"},"items":[{"password":"x"}]}"#,
+        ));
+        rules.redact_body(&mut body);
+        let value: serde_json::Value = serde_json::from_slice(body.unwrap().as_bytes()).unwrap();
+        assert_eq!(value["user"]["password"], REDACTED);
+        assert_eq!(value["user"]["name"], "a");
+        assert_eq!(value["items"][0]["password"], REDACTED);
+
+        let mut text = Some(Body::from("password=secret"));
+        rules.redact_body(&mut text);
+        assert_eq!(text.unwrap().as_text(), Some("password=secret"));
+    }
+}
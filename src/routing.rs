@@ -0,0 +1,360 @@
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+use crate::models::Headers;
+use crate::rules::{header_matches, rule_list};
+
+/// A single routing rule mapping matching tunnel requests to a local target.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Route {
+    /// Path prefix to match on segment boundaries (`/api` matches `/api/users`, not `/apix`)
+    #[serde(default = "default_route_path")]
+    pub path: String,
+    /// Optional HTTP method the request must use
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Optional header match, written as `name` (presence) or `name:value`
+    #[serde(default)]
+    pub header: Option<String>,
+    /// Local target base URL, e.g. `http://localhost:8080`
+    pub target: String,
+    /// Remove the matched path prefix before forwarding
+    #[serde(default)]
+    pub strip_prefix: bool,
+}
+
+fn default_route_path() -> String {
+    "/".to_string()
+}
+
+/// Outcome of resolving a request against the routing table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteDecision {
+    /// Full local URL the request is forwarded to (including query string)
+    pub url: String,
+    /// Human-readable description of the matched rule, `None` for the default target
+    pub route: Option<String>,
+}
+
+impl Route {
+    /// Parse a `--route` value.
+    ///
+    /// Syntax: `[METHOD ]PREFIX=TARGET[,strip][,header=NAME[:VALUE]]`, for example
+    /// `/api=http://localhost:8080,strip` or `POST /hooks=http://localhost:9000`.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (matcher, rest) = spec
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Invalid route '{}': expected PREFIX=TARGET", spec))?;
+
+        let matcher = matcher.trim();
+        let (method, path) = match matcher.split_once(char::is_whitespace) {
+            Some((method, path)) => (Some(method.trim().to_uppercase()), path.trim()),
+            None => (None, matcher),
+        };
+
+        let mut options = rest.split(',');
+        let target = options.next().unwrap_or_default().trim().to_string();
+
+        let mut route = Route {
+            path: path.to_string(),
+            method,
+            header: None,
+            target,
+            strip_prefix: false,
+        };
+
+        for option in options {
+            let option = option.trim();
+            if option == "strip" {
+                route.strip_prefix = true;
+            } else if let Some(header) = option.strip_prefix("header=") {
+                route.header = Some(header.to_string());
+            } else if !option.is_empty() {
+                return Err(anyhow!(
+                    "Invalid route '{}': unknown option '{}'",
+                    spec,
+                    option
+                ));
+            }
+        }
+
+        route.validate()?;
+        Ok(route)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if !self.path.starts_with('/') {
+            return Err(anyhow!(
+                "Invalid route path '{}': must start with '/'",
+                self.path
+            ));
+        }
+        if !(self.target.starts_with("http://") || self.target.starts_with("https://")) {
+            return Err(anyhow!(
+                "Invalid route target '{}': must start with http:// or https://",
+                self.target
+            ));
+        }
+        Ok(())
+    }
+
+    fn matches(&self, method: &str, path: &str, headers: &Headers) -> bool {
+        if let Some(expected) = &self.method
+            && !expected.eq_ignore_ascii_case(method)
+        {
+            return false;
+        }
+
+        if !path_has_prefix(path, &self.path) {
+            return false;
+        }
+
+        if let Some(header) = &self.header
+            && !header_matches(header, headers)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Short description used in the TUI, e.g. `POST /api → http://localhost:8080`.
+    pub fn describe(&self) -> String {
+        let mut desc = String::new();
+        if let Some(method) = &self.method {
+            desc.push_str(method);
+            desc.push(' ');
+        }
+        desc.push_str(&self.path);
+        if let Some(header) = &self.header {
+            desc.push_str(&format!(" [{}]", header));
+        }
+        desc.push_str(" → ");
+        desc.push_str(&self.target);
+        if self.strip_prefix {
+            desc.push_str(" (strip)");
+        }
+        desc
+    }
+}
+
+fn path_has_prefix(path: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    if prefix.is_empty() {
+        return true;
+    }
+    match path.strip_prefix(prefix) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/') || rest.starts_with('?'),
+        None => false,
+    }
+}
+
+/// Ordered list of routes with a fallback target; the first matching route wins.
+#[derive(Debug, Clone)]
+pub struct RoutingTable {
+    routes: Vec<Route>,
+    default_target: String,
+}
+
+impl RoutingTable {
+    pub fn new(default_target: String, routes: Vec<Route>) -> Self {
+        Self {
+            routes,
+            default_target,
+        }
+    }
+
+    /// Load routes from a JSON file.
+    pub fn load_routes(path: &Path) -> Result<Vec<Route>> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read routes file {}", path.display()))?;
+        let routes: Vec<Route> = serde_json::from_str(&content)
+            .and_then(|file| rule_list(file, "routes"))
+            .with_context(|| format!("Failed to parse routes file {}", path.display()))?;
+        for route in &routes {
+            route.validate()?;
+        }
+        Ok(routes)
+    }
+
+    pub fn with_default_target(self, default_target: String) -> Self {
+        Self {
+            default_target,
+            ..self
+        }
+    }
+
+    pub fn default_target(&self) -> &str {
+        &self.default_target
+    }
+
+    /// Pick the local URL for a request.
+    pub fn resolve(
+        &self,
+        method: &str,
+        path: &str,
+        query_string: &str,
+        headers: &Headers,
+    ) -> RouteDecision {
+        let matched = self
+            .routes
+            .iter()
+            .find(|route| route.matches(method, path, headers));
+
+        let (base, forward_path, route) = match matched {
+            Some(route) => {
+                let forward_path = if route.strip_prefix {
+                    let rest = path
+                        .strip_prefix(route.path.trim_end_matches('/'))
+                        .unwrap_or(path);
+                    if rest.starts_with('/') {
+                        rest.to_string()
+                    } else {
+                        format!("/{}", rest)
+                    }
+                } else {
+                    path.to_string()
+                };
+                (route.target.as_str(), forward_path, Some(route.describe()))
+            }
+            None => (self.default_target.as_str(), path.to_string(), None),
+        };
+
+        let mut url = format!("{}{}", base.trim_end_matches('/'), forward_path);
+        if !query_string.is_empty() {
+            url.push('?');
+            url.push_str(query_string);
+        }
+
+        RouteDecision { url, route }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(specs: &[&str]) -> RoutingTable {
+        RoutingTable::new(
+            "http://localhost:3000".to_string(),
+            specs.iter().map(|s| Route::parse(s).unwrap()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_parse_simple_route() {
+        let route = Route::parse("/api=http://localhost:8080").unwrap();
+        assert_eq!(route.path, "/api");
+        assert_eq!(route.target, "http://localhost:8080");
+        assert!(route.method.is_none());
+        assert!(!route.strip_prefix);
+    }
+
+    #[test]
+    fn test_parse_route_with_method_and_options() {
+        let route =
+            Route::parse("post /hooks=http://localhost:9000,strip,header=x-worker:1").unwrap();
+        assert_eq!(route.method.as_deref(), Some("POST"));
+        assert_eq!(route.path, "/hooks");
+        assert!(route.strip_prefix);
+        assert_eq!(route.header.as_deref(), Some("x-worker:1"));
+    }
+
+    #[test]
+    fn test_parse_route_rejects_invalid() {
+        assert!(Route::parse("/api").is_err());
+        assert!(Route::parse("api=http://localhost:8080").is_err());
+        assert!(Route::parse("/api=localhost:8080").is_err());
+        assert!(Route::parse("/api=http://localhost:8080,bogus").is_err());
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_default() {
+        let decision = table(&["/api=http://localhost:8080"]).resolve(
+            "GET",
+            "/index.html",
+            "",
+            &Headers::new(),
+        );
+        assert_eq!(decision.url, "http://localhost:3000/index.html");
+        assert!(decision.route.is_none());
+    }
+
+    #[test]
+    fn test_resolve_prefix_matches_on_segment_boundary() {
+        let routes = table(&["/api=http://localhost:8080"]);
+        let hit = routes.resolve("GET", "/api/users", "page=2", &Headers::new());
+        assert_eq!(hit.url, "http://localhost:8080/api/users?page=2");
+        assert!(hit.route.is_some());
+
+        let miss = routes.resolve("GET", "/apix", "", &Headers::new());
+        assert_eq!(miss.url, "http://localhost:3000/apix");
+    }
+
+    #[test]
+    fn test_resolve_strips_prefix() {
+        let routes = table(&["/worker=http://localhost:9000/callbacks,strip"]);
+        let decision = routes.resolve("POST", "/worker/done", "", &Headers::new());
+        assert_eq!(decision.url, "http://localhost:9000/callbacks/done");
+
+        let root = routes.resolve("POST", "/worker", "", &Headers::new());
+        assert_eq!(root.url, "http://localhost:9000/callbacks/");
+    }
+
+    #[test]
+    fn test_resolve_first_match_wins_with_method_and_header() {
+        let routes = table(&[
+            "POST /=http://localhost:9000,header=X-Source:stripe",
+            "/=http://localhost:8080",
+        ]);
+
+        let headers: Headers = [("x-source", "stripe")].into_iter().collect();
+        assert_eq!(
+            routes.resolve("POST", "/hook", "", &headers).url,
+            "http://localhost:9000/hook"
+        );
+        assert_eq!(
+            routes.resolve("GET", "/hook", "", &headers).url,
+            "http://localhost:8080/hook"
+        );
+        assert_eq!(
+            routes.resolve("POST", "/hook", "", &Headers::new()).url,
+            "http://localhost:8080/hook"
+        );
+    }
+
+    #[test]
+    fn test_load_routes_file_formats() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        let wrapped = dir.path().join("wrapped.json");
+        fs::write(
+            &wrapped,
+            r#"{"routes":[{"path":"/api","target":"http://localhost:8080","strip_prefix":true}]}"#,
+        )
+        .unwrap();
+        let routes = RoutingTable::load_routes(&wrapped).unwrap();
+        assert_eq!(routes.len(), 1);
+        assert!(routes[0].strip_prefix);
+
+        let bare = dir.path().join("bare.json");
+        fs::write(
+            &bare,
+            r#"[{"method":"POST","target":"http://localhost:9000"}]"#,
+        )
+        .unwrap();
+        let routes = RoutingTable::load_routes(&bare).unwrap();
+        assert_eq!(routes[0].path, "/");
+        assert_eq!(routes[0].method.as_deref(), Some("POST"));
+    }
+
+    #[test]
+    fn test_load_routes_rejects_invalid_target() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("routes.json");
+        fs::write(&path, r#"[{"path":"/api","target":"localhost:8080"}]"#).unwrap();
+        assert!(RoutingTable::load_routes(&path).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::models::Headers;
+
+/// Which requests an intercept, mock or fault rule applies to.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RequestMatcher {
+    /// Optional HTTP method the request must use
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Path glob: `*` matches any run of characters, `?` a single character
+    #[serde(default = "any_path")]
+    pub path: String,
+    /// Optional header match, written as `name` (presence) or `name:value`
+    #[serde(default)]
+    pub header: Option<String>,
+}
+
+fn any_path() -> String {
+    "*".to_string()
+}
+
+impl Default for RequestMatcher {
+    fn default() -> Self {
+        Self {
+            method: None,
+            path: any_path(),
+            header: None,
+        }
+    }
+}
+
+impl RequestMatcher {
+    /// Whether the path glob starts with `/` or `*`, as request paths always do.
+    pub fn has_valid_path(&self) -> bool {
+        self.path.starts_with('/') || self.path.starts_with('*')
+    }
+
+    pub fn matches(&self, method: &str, path: &str, headers: &Headers) -> bool {
+        if let Some(expected) = &self.method
+            && !expected.eq_ignore_ascii_case(method)
+        {
+            return false;
+        }
+
+        if !glob_match(&self.path, path) {
+            return false;
+        }
+
+        match &self.header {
+            Some(header) => header_matches(header, headers),
+            None => true,
+        }
+    }
+}
+
+/// Match `text` against a glob where `*` matches any run of characters and `?` one character.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, star_t)) = backtrack {
+            p = star + 1;
+            t = star_t + 1;
+            backtrack = Some((star, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Match a `name` (presence) or `name:value` header spec; names are case-insensitive.
+pub fn header_matches(spec: &str, headers: &Headers) -> bool {
+    let (name, value) = match spec.split_once(':') {
+        Some((name, value)) => (name.trim(), Some(value.trim())),
+        None => (spec.trim(), None),
+    };
+    // Any value of a repeated header may satisfy the match
+    match value {
+        Some(expected) => headers.get_all(name).any(|actual| actual == expected),
+        None => headers.contains(name),
+    }
+}
+
+/// Rules from a file holding either `{"<key>": [...]}` or a bare list.
+pub fn rule_list<T: DeserializeOwned>(
+    mut file: serde_json::Value,
+    key: &str,
+) -> serde_json::Result<Vec<T>> {
+    if let Some(rules) = file.get_mut(key) {
+        file = rules.take();
+    }
+    serde_json::from_value(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "/anything/at/all"));
+        assert!(glob_match("/api/*", "/api/users/1"));
+        assert!(!glob_match("/api/*", "/apix"));
+        assert!(glob_match("/hooks/?", "/hooks/a"));
+        assert!(!glob_match("/hooks/?", "/hooks/ab"));
+        assert!(glob_match("/*/events", "/stripe/events"));
+        assert!(!glob_match("/*/events", "/stripe/events/1"));
+        assert!(glob_match("/a*b*c", "/aXbYbZc"));
+    }
+
+    #[test]
+    fn test_matcher_checks_method_path_and_header() {
+        let matcher = RequestMatcher {
+            method: Some("POST".to_string()),
+            path: "/hooks/*".to_string(),
+            header: Some("x-source:stripe".to_string()),
+        };
+        let headers: Headers = [("X-Source", "stripe")].into_iter().collect();
+
+        assert!(matcher.matches("post", "/hooks/1", &headers));
+        assert!(!matcher.matches("GET", "/hooks/1", &headers));
+        assert!(!matcher.matches("POST", "/other", &headers));
+        assert!(!matcher.matches("POST", "/hooks/1", &Headers::new()));
+        assert!(RequestMatcher::default().matches("GET", "/", &Headers::new()));
+    }
+
+    #[test]
+    fn test_rule_list_accepts_wrapped_and_bare_lists() {
+        let wrapped: Vec<RequestMatcher> =
+            rule_list(json!({"rules": [{"path": "/a"}]}), "rules").unwrap();
+        let bare: Vec<RequestMatcher> = rule_list(json!([{"path": "/a"}]), "rules").unwrap();
+        assert_eq!(wrapped, bare);
+        assert_eq!(wrapped[0].path, "/a");
+
+        assert!(rule_list::<RequestMatcher>(json!({"other": []}), "rules").is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+use crate::project::{DEFAULT_LISTEN_TARGET, DEFAULT_TUNNEL_HOST, DEFAULT_TUNNEL_PORT};
+
+/// An HTTP tunnel started by `hooklistener up`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TunnelSession {
+    /// Tab title; defaults to the slug or `host:port`
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    #[serde(default = "default_host")]
+    pub host: String,
+    /// Static tunnel slug (paid plans only)
+    #[serde(default)]
+    pub slug: Option<String>,
+    /// Organization ID; defaults to the configured organization
+    #[serde(default)]
+    pub org: Option<String>,
+}
+
+/// A debug endpoint listener started by `hooklistener up`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListenSession {
+    /// Tab title; defaults to the endpoint slug
+    #[serde(default)]
+    pub name: Option<String>,
+    pub endpoint: String,
+    #[serde(default = "default_target")]
+    pub target: String,
+}
+
+fn default_port() -> u16 {
+    DEFAULT_TUNNEL_PORT
+}
+
+fn default_host() -> String {
+    DEFAULT_TUNNEL_HOST.to_string()
+}
+
+fn default_target() -> String {
+    DEFAULT_LISTEN_TARGET.to_string()
+}
+
+impl TunnelSession {
+    pub fn label(&self) -> String {
+        match (&self.name, &self.slug) {
+            (Some(name), _) => name.clone(),
+            (None, Some(slug)) => slug.clone(),
+            (None, None) => format!("{}:{}", self.host, self.port),
+        }
+    }
+}
+
+impl ListenSession {
+    pub fn label(&self) -> String {
+        self.name.clone().unwrap_or_else(|| self.endpoint.clone())
+    }
+}
+
+/// `[up]`: sessions started by `hooklistener up`; either list may be omitted.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpSessions {
+    #[serde(default)]
+    pub tunnels: Vec<TunnelSession>,
+    #[serde(default)]
+    pub listeners: Vec<ListenSession>,
+}
+
+impl UpSessions {
+    pub fn validate(&self) -> Result<()> {
+        if self.tunnels.is_empty() && self.listeners.is_empty() {
+            return Err(anyhow!(
+                "Declare at least one entry under [[up.tunnels]] or [[up.listeners]]"
+            ));
+        }
+        for listener in &self.listeners {
+            if listener.endpoint.trim().is_empty() {
+                return Err(anyhow!("Listener endpoint must not be empty"));
+            }
+        }
+
+        let mut labels = HashSet::new();
+        let all = self
+            .tunnels
+            .iter()
+            .map(TunnelSession::label)
+            .chain(self.listeners.iter().map(ListenSession::label));
+        for label in all {
+            if !labels.insert(label.clone()) {
+                return Err(anyhow!(
+                    "Duplicate session name '{}': give each entry a unique `name`",
+                    label
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::ProjectConfig;
+
+    fn parse(content: &str) -> UpSessions {
+        toml::from_str::<ProjectConfig>(content).unwrap().up
+    }
+
+    #[test]
+    fn test_parse_with_defaults() {
+        let up = parse(
+            r#"
+[[up.tunnels]]
+port = 3000
+
+[[up.tunnels]]
+name = "api"
+port = 8080
+slug = "my-api"
+
+[[up.listeners]]
+endpoint = "stripe-hooks"
+"#,
+        );
+        up.validate().unwrap();
+
+        assert_eq!(up.tunnels.len(), 2);
+        assert_eq!(up.tunnels[0].host, "localhost");
+        assert_eq!(up.tunnels[0].label(), "localhost:3000");
+        assert_eq!(up.tunnels[1].label(), "api");
+        assert_eq!(up.listeners[0].target, "http://localhost:3000");
+        assert_eq!(up.listeners[0].label(), "stripe-hooks");
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_and_duplicate_sessions() {
+        let err = parse("").validate().unwrap_err();
+        assert!(err.to_string().contains("at least one entry"));
+
+        let err = parse("[[up.tunnels]]\nport = 3000\n\n[[up.tunnels]]\nport = 3000\n")
+            .validate()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Duplicate session name 'localhost:3000'")
+        );
+
+        assert!(toml::from_str::<ProjectConfig>("[[up.tunnels]]\nprot = 3000\n").is_err());
+    }
+}
@@ -3,14 +3,31 @@ use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, SystemTime};
-use tokio::sync::mpsc;
-use tokio_tungstenite::{
-    connect_async,
-    tungstenite::{Message, error::Error as WsError, http::StatusCode},
+use tokio::sync::{Semaphore, mpsc};
+use tokio_tungstenite::tungstenite::{
+    Message,
+    client::IntoClientRequest,
+    error::Error as WsError,
+    http::StatusCode,
+    protocol::{CloseFrame, frame::coding::CloseCode},
 };
 use tracing::{debug, error, info, warn};
 
+use crate::errors::TunnelError;
+use crate::fault::{Fault, FaultInjector};
+use crate::http::NetworkSettings;
+use crate::intercept::{HeldRequest, InterceptDecision, Interceptor};
+use crate::local_tls::LocalTlsOptions;
+use crate::mock::{MockMode, MockRequest, MockRule, MockSet};
+use crate::models::{self, Body, Headers, encode_body};
+use crate::project::RedactRules;
+use crate::routing::{Route, RoutingTable};
+
 /// Extract the string representation of a JSON value.
 /// Returns the inner string for `Value::String`, otherwise uses `to_string()`.
 fn json_value_to_string(v: &serde_json::Value) -> String {
@@ -39,8 +56,26 @@ pub struct TunnelWebhookRequest {
     #[serde(default)]
     pub query_params: HashMap<String, serde_json::Value>,
     #[serde(default)]
-    pub headers: HashMap<String, serde_json::Value>,
+    pub headers: Headers,
     pub body: Option<String>,
+    /// `raw` (default) or `base64` for binary bodies
+    #[serde(default)]
+    pub body_encoding: Option<String>,
+}
+
+impl TunnelWebhookRequest {
+    /// The body bytes, decoded according to `body_encoding`.
+    fn body_bytes(&self) -> Option<Body> {
+        let body = self.body.as_deref()?;
+        let encoding = self.body_encoding.as_deref().unwrap_or("raw");
+        Some(match models::decode_body(body, encoding) {
+            Ok(bytes) => Body::from(bytes),
+            Err(e) => {
+                warn!("Failed to decode base64 body: {}", e);
+                Body::from(body)
+            }
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -58,24 +93,86 @@ pub enum TunnelEvent {
         request_id: String,
         method: String,
         path: String,
-        headers: HashMap<String, String>,
-        body: Option<String>,
+        headers: Headers,
+        body: Option<Body>,
         query_string: String,
+        target: String,
+        route: Option<String>,
     },
     RequestForwarded {
         request_id: String,
         status: u16,
         duration_ms: u64,
-        response_headers: HashMap<String, String>,
-        response_body: Option<String>,
+        response_headers: Headers,
+        response_body: Option<Body>,
     },
     RequestFailed {
         request_id: String,
         error: String,
+        /// Set when nothing accepted the connection to this local target
+        unreachable_target: Option<String>,
+    },
+    /// Faults were injected into forwarding; `faults` lists their labels
+    FaultInjected {
+        request_id: String,
+        faults: String,
+    },
+    /// A mock rule answered the request instead of the local target
+    RequestMocked {
+        request_id: String,
+        status: u16,
+        duration_ms: u64,
+        response_headers: Headers,
+        response_body: Option<Body>,
+        mode: MockMode,
+    },
+    /// Body bytes relayed for a tunnel request since the last report
+    BytesTransferred {
+        request_id: String,
+        bytes_in: u64,
+        bytes_out: u64,
+    },
+    /// The local response is being relayed in chunks
+    ResponseStreamStarted {
+        request_id: String,
+        status: u16,
+        response_headers: Headers,
+    },
+    ResponseStreamEnded {
+        request_id: String,
+        duration_ms: u64,
+        response_body: Option<Body>,
+        error: Option<String>,
+    },
+    /// A WebSocket upgrade was accepted by the local target and is being proxied
+    WebSocketOpened {
+        request_id: String,
+        response_headers: Headers,
+    },
+    /// Frames relayed on a proxied WebSocket since the last report
+    WebSocketFrames {
+        request_id: String,
+        frames_in: u64,
+        frames_out: u64,
+    },
+    WebSocketClosed {
+        request_id: String,
+        code: Option<u16>,
+        reason: Option<String>,
+    },
+    /// A request matched an intercept rule and waits for a decision in the TUI
+    RequestIntercepted {
+        request_id: String,
+        request: HeldRequest,
+        /// Whether a hand-written response can be sent back (tunnel only)
+        can_respond: bool,
     },
     WebhookReceived(Box<crate::models::WebhookRequest>),
     ForwardSuccess,
-    ForwardError,
+    ForwardError {
+        /// Set when nothing accepted the connection to this local target
+        unreachable_target: Option<String>,
+    },
     Reconnecting {
         attempt: u32,
         max_attempts: u32,
@@ -83,7 +180,93 @@ pub enum TunnelEvent {
     },
     ReconnectFailed {
         reason: String,
+        /// The typed cause, when known, so `--no-tui` can exit with its code
+        error: Option<TunnelError>,
     },
+    /// The server rejected the access token and a refreshed one was obtained
+    TokenRefreshed,
+    InFlight {
+        active: usize,
+        queued: usize,
+        max: usize,
+    },
+}
+
+impl TunnelEvent {
+    /// The captured request this event updates, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            TunnelEvent::RequestReceived { request_id, .. }
+            | TunnelEvent::RequestForwarded { request_id, .. }
+            | TunnelEvent::RequestFailed { request_id, .. }
+            | TunnelEvent::FaultInjected { request_id, .. }
+            | TunnelEvent::RequestMocked { request_id, .. }
+            | TunnelEvent::BytesTransferred { request_id, .. }
+            | TunnelEvent::ResponseStreamStarted { request_id, .. }
+            | TunnelEvent::ResponseStreamEnded { request_id, .. }
+            | TunnelEvent::WebSocketOpened { request_id, .. }
+            | TunnelEvent::WebSocketFrames { request_id, .. }
+            | TunnelEvent::WebSocketClosed { request_id, .. }
+            | TunnelEvent::RequestIntercepted { request_id, .. } => Some(request_id),
+            TunnelEvent::WebhookReceived(request) => Some(&request.id),
+            _ => None,
+        }
+    }
+
+    /// Hide header values and body fields matched by the project's redaction rules.
+    pub fn redact(&mut self, rules: &RedactRules) {
+        if rules.is_empty() {
+            return;
+        }
+        match self {
+            TunnelEvent::RequestReceived { headers, body, .. } => {
+                rules.redact_headers(headers);
+                rules.redact_body(body);
+            }
+            TunnelEvent::RequestForwarded {
+                response_headers,
+                response_body,
+                ..
+            }
+            | TunnelEvent::RequestMocked {
+                response_headers,
+                response_body,
+                ..
+            } => {
+                rules.redact_headers(response_headers);
+                rules.redact_body(response_body);
+            }
+            TunnelEvent::ResponseStreamStarted {
+                response_headers, ..
+            }
+            | TunnelEvent::WebSocketOpened {
+                response_headers, ..
+            } => rules.redact_headers(response_headers),
+            TunnelEvent::ResponseStreamEnded { response_body, .. } => {
+                rules.redact_body(response_body)
+            }
+            TunnelEvent::WebhookReceived(request) => {
+                rules.redact_headers(&mut request.headers);
+                rules.redact_body(&mut request.body);
+                // The preview is a copy of the body, so rebuild it from the redacted one
+                match &request.body {
+                    Some(body) => {
+                        request.body_preview = body.as_text().map(str::to_string);
+                    }
+                    None => {
+                        if let Some(preview) = request.body_preview.as_mut() {
+                            rules.redact_text(preview);
+                        }
+                    }
+                }
+            }
+            TunnelEvent::RequestIntercepted { request, .. } => {
+                rules.redact_headers(&mut request.headers);
+                rules.redact_text(&mut request.body);
+            }
+            _ => {}
+        }
+    }
 }
 
 /// Configuration for reconnection behavior
@@ -105,6 +288,227 @@ impl Default for ReconnectConfig {
     }
 }
 
+/// Default number of requests forwarded to the local target at the same time
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 10;
+
+/// Interval between Phoenix heartbeats / tunnel pings
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Messages queued for the WebSocket before forwarding tasks wait for room.
+const OUTBOUND_BUFFER: usize = 64;
+
+/// Chunks or frames queued for one local stream before the read loop waits for room.
+const STREAM_BUFFER: usize = 16;
+
+/// How long the read loop waits for a local stream that stopped reading before
+/// dropping it.
+const STREAM_STALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Responses larger than this (or of unknown length) are streamed when streaming is enabled
+const STREAM_THRESHOLD: u64 = 1024 * 1024;
+
+/// Minimum interval between byte-count updates sent to the UI while streaming
+const STREAM_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Decode a `body` field according to its `body_encoding` (`raw` or `base64`).
+fn decode_body(payload: &serde_json::Map<String, serde_json::Value>) -> Vec<u8> {
+    let body_encoding = payload
+        .get("body_encoding")
+        .and_then(|v| v.as_str())
+        .unwrap_or("raw");
+    let raw_body = payload.get("body").and_then(|v| v.as_str()).unwrap_or("");
+    models::decode_body(raw_body, body_encoding).unwrap_or_else(|e| {
+        warn!("Failed to decode base64 body: {}", e);
+        raw_body.as_bytes().to_vec()
+    })
+}
+
+/// Whether a local response should be relayed in chunks rather than buffered:
+/// event streams, responses without a `Content-Length`, and large bodies.
+fn should_stream(headers: &reqwest::header::HeaderMap) -> bool {
+    let is_event_stream = headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("text/event-stream"));
+    let content_length = headers
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+    is_event_stream || content_length.is_none_or(|len| len > STREAM_THRESHOLD)
+}
+
+/// Whether a tunnel request asks to upgrade to a WebSocket.
+fn is_websocket_upgrade(headers: &Headers) -> bool {
+    headers.get_all("upgrade").any(|v| {
+        v.split(',')
+            .any(|token| token.trim().eq_ignore_ascii_case("websocket"))
+    })
+}
+
+/// Handshake headers that the local WebSocket client generates itself.
+const WEBSOCKET_HANDSHAKE_HEADERS: &[&str] = &[
+    "host",
+    "upgrade",
+    "connection",
+    "sec-websocket-key",
+    "sec-websocket-version",
+    "sec-websocket-extensions",
+    "content-length",
+    "transfer-encoding",
+];
+
+fn is_websocket_handshake_header(name: &str) -> bool {
+    WEBSOCKET_HANDSHAKE_HEADERS
+        .iter()
+        .any(|h| h.eq_ignore_ascii_case(name))
+}
+
+/// Frame and byte counts for a proxied WebSocket, flushed to the UI periodically.
+#[derive(Default)]
+struct FrameCounts {
+    frames_in: u64,
+    frames_out: u64,
+    bytes_in: u64,
+    bytes_out: u64,
+}
+
+/// Cloneable handle for writing channel messages from concurrent forwarding tasks.
+/// Messages are queued for the connection's writer task; once `OUTBOUND_BUFFER`
+/// messages are waiting, senders wait for room, so a slow WebSocket slows down the
+/// local reads that feed it instead of buffering whole bodies in memory.
+#[derive(Clone)]
+struct ChannelSink {
+    tx: mpsc::Sender<Message>,
+}
+
+impl ChannelSink {
+    fn new() -> (Self, mpsc::Receiver<Message>) {
+        let (tx, rx) = mpsc::channel(OUTBOUND_BUFFER);
+        (Self { tx }, rx)
+    }
+
+    async fn send(&self, message: &ChannelMessage) -> Result<()> {
+        let json = serde_json::to_string(message)?;
+        self.push(Message::Text(json.into())).await
+    }
+
+    async fn push(&self, message: Message) -> Result<()> {
+        self.tx
+            .send(message)
+            .await
+            .map_err(|_| anyhow!("WebSocket connection closed"))
+    }
+}
+
+/// Write queued messages, and a heartbeat every `HEARTBEAT_INTERVAL`, until a write
+/// fails. Runs apart from the read loop, so the read loop can wait on a slow local
+/// stream without stopping replies or heartbeats.
+fn spawn_writer<S>(
+    mut write: S,
+    mut outbound: mpsc::Receiver<Message>,
+    heartbeat: impl Fn(u64) -> ChannelMessage + Send + 'static,
+) -> tokio::task::JoinHandle<()>
+where
+    S: futures_util::Sink<Message> + Unpin + Send + 'static,
+    S::Error: std::fmt::Display,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval_at(
+            tokio::time::Instant::now() + HEARTBEAT_INTERVAL,
+            HEARTBEAT_INTERVAL,
+        );
+        let mut counter = 2;
+        loop {
+            let message = tokio::select! {
+                _ = ticker.tick() => {
+                    let heartbeat = heartbeat(counter);
+                    counter += 1;
+                    match serde_json::to_string(&heartbeat) {
+                        Ok(json) => Message::Text(json.into()),
+                        Err(_) => continue,
+                    }
+                }
+                message = outbound.recv() => match message {
+                    Some(message) => message,
+                    None => break,
+                },
+            };
+            if let Err(e) = write.send(message).await {
+                error!("Failed to send message: {}", e);
+                break;
+            }
+        }
+    })
+}
+
+/// Hand a body chunk or frame to its local stream, waiting while the stream's buffer
+/// is full. Returns false when the stream is gone or has not made room for
+/// `STREAM_STALL_TIMEOUT`, after which the caller drops it.
+async fn deliver<T>(tx: Option<mpsc::Sender<T>>, item: T) -> bool {
+    match tx {
+        Some(tx) => tx.send_timeout(item, STREAM_STALL_TIMEOUT).await.is_ok(),
+        None => false,
+    }
+}
+
+/// Bounded pool of local forwarding tasks. Requests beyond `max` wait for a free slot
+/// without blocking the WebSocket read loop.
+#[derive(Clone)]
+struct ForwardPool {
+    semaphore: Arc<Semaphore>,
+    max: usize,
+    active: Arc<AtomicUsize>,
+    queued: Arc<AtomicUsize>,
+    event_tx: mpsc::Sender<TunnelEvent>,
+}
+
+impl ForwardPool {
+    fn new(max: usize, event_tx: mpsc::Sender<TunnelEvent>) -> Self {
+        let max = max.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max)),
+            max,
+            active: Arc::new(AtomicUsize::new(0)),
+            queued: Arc::new(AtomicUsize::new(0)),
+            event_tx,
+        }
+    }
+
+    fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let pool = self.clone();
+        pool.queued.fetch_add(1, Ordering::SeqCst);
+
+        tokio::spawn(async move {
+            pool.report().await;
+            let Ok(_permit) = pool.semaphore.clone().acquire_owned().await else {
+                return;
+            };
+            pool.queued.fetch_sub(1, Ordering::SeqCst);
+            pool.active.fetch_add(1, Ordering::SeqCst);
+            pool.report().await;
+
+            task.await;
+
+            pool.active.fetch_sub(1, Ordering::SeqCst);
+            pool.report().await;
+        });
+    }
+
+    async fn report(&self) {
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::InFlight {
+                active: self.active.load(Ordering::SeqCst),
+                queued: self.queued.load(Ordering::SeqCst),
+                max: self.max,
+            })
+            .await;
+    }
+}
+
 /// Build a WebSocket URL from a base HTTP(S) URL
 #[cfg(test)]
 pub fn build_ws_url(base_url: &str, token: &str, path: &str) -> String {
@@ -145,6 +549,37 @@ pub fn build_forward_target(
     }
 }
 
+/// Error message for an injected connection failure, waiting out the timeout first
+/// when simulating a target that never answers.
+async fn injected_failure_error(failure: Fault) -> String {
+    match failure {
+        Fault::Timeout => {
+            tokio::time::sleep(LOCAL_REQUEST_TIMEOUT).await;
+            format!(
+                "Injected fault: no response within {}s",
+                LOCAL_REQUEST_TIMEOUT.as_secs()
+            )
+        }
+        Fault::Reset => "Injected fault: connection reset by local target".to_string(),
+        other => format!("Injected fault: {}", other.describe()),
+    }
+}
+
+/// Scheme, host and port of a local target URL, as named in unreachable-target errors.
+fn target_base(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .map(|url| url.origin().ascii_serialization())
+        .unwrap_or_else(|_| url.to_string())
+}
+
+/// Error text for a request the local target refused, naming the target base.
+fn unreachable_error(target: &str, cause: &str) -> String {
+    let error = TunnelError::TargetUnreachable {
+        target: target.to_string(),
+    };
+    format!("{}: {}", error, cause)
+}
+
 /// Determine if an error message represents a fatal (non-retryable) error
 pub fn is_fatal_error(error_msg: &str) -> bool {
     let lower = error_msg.to_lowercase();
@@ -158,6 +593,36 @@ pub fn is_fatal_error(error_msg: &str) -> bool {
         || lower.contains("tunnel join failed")
 }
 
+/// Whether a connection error means the server rejected the access token.
+pub fn is_auth_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<TunnelError>(),
+            Some(TunnelError::AuthenticationFailed)
+        ) || matches!(
+            cause.downcast_ref::<WsError>(),
+            Some(WsError::Http(response)) if response.status() == StatusCode::UNAUTHORIZED
+        )
+    })
+}
+
+/// Obtains a new access token after the server rejects the current one.
+pub type TokenRefresher =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Result<String>> + Send>> + Send + Sync>;
+
+/// Swap a refreshed token into `token` and report it.
+async fn refresh_token(
+    refresher: &TokenRefresher,
+    token: &std::sync::Mutex<String>,
+    event_tx: &mpsc::Sender<TunnelEvent>,
+) -> Result<()> {
+    let fresh = refresher().await?;
+    *token.lock().unwrap() = fresh;
+    info!("Access token refreshed");
+    let _ = event_tx.send(TunnelEvent::TokenRefreshed).await;
+    Ok(())
+}
+
 /// Calculate backoff duration with exponential backoff and jitter
 pub fn calculate_backoff(attempt: u32, config: &ReconnectConfig) -> Duration {
     let base_delay = config.initial_delay_ms as f64 * 2_f64.powi(attempt.saturating_sub(1) as i32);
@@ -177,10 +642,16 @@ pub fn calculate_backoff(attempt: u32, config: &ReconnectConfig) -> Duration {
 
 /// Tunnel client for WebSocket connection to Hooklistener server
 pub struct TunnelClient {
-    access_token: String,
+    access_token: std::sync::Mutex<String>,
+    token_refresher: Option<TokenRefresher>,
     endpoint_slug: String,
     target_url: String,
     base_url: String,
+    /// `None` uses the process-wide network settings
+    network: Option<NetworkSettings>,
+    pool: ForwardPool,
+    interceptor: Interceptor,
+    faults: FaultInjector,
     event_tx: mpsc::Sender<TunnelEvent>,
 }
 
@@ -191,23 +662,59 @@ impl TunnelClient {
         target_url: String,
         base_url: Option<String>,
         event_tx: mpsc::Sender<TunnelEvent>,
-    ) -> Self {
-        // Check environment variable for local development
-        let base_url = base_url
-            .or_else(|| std::env::var("HOOKLISTENER_WS_URL").ok())
-            .unwrap_or_else(|| "wss://api.hooklistener.com".to_string());
+    ) -> Result<Self> {
+        let base_url = match base_url {
+            Some(url) => url,
+            None => crate::config::ws_url()?,
+        };
 
-        Self {
-            access_token,
+        Ok(Self {
+            access_token: std::sync::Mutex::new(access_token),
+            token_refresher: None,
             endpoint_slug,
             target_url,
             base_url,
+            network: None,
+            pool: ForwardPool::new(DEFAULT_MAX_IN_FLIGHT, event_tx.clone()),
+            interceptor: Interceptor::default(),
+            faults: FaultInjector::default(),
             event_tx,
-        }
+        })
+    }
+
+    /// Limit how many webhooks are forwarded to the local target at once.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.pool = ForwardPool::new(max_in_flight, self.event_tx.clone());
+        self
+    }
+
+    /// Hold webhooks matching the interceptor's rules until they are released in the TUI.
+    pub fn with_interceptor(mut self, interceptor: Interceptor) -> Self {
+        self.interceptor = interceptor;
+        self
+    }
+
+    /// Inject latency and failures into forwarding to test how senders react.
+    pub fn with_faults(mut self, faults: FaultInjector) -> Self {
+        self.faults = faults;
+        self
+    }
+
+    /// Refresh the access token and reconnect when the server rejects it.
+    pub fn with_token_refresher(mut self, refresher: TokenRefresher) -> Self {
+        self.token_refresher = Some(refresher);
+        self
+    }
+
+    /// Use `network` instead of the process-wide network settings.
+    #[cfg(test)]
+    pub fn with_network(mut self, network: NetworkSettings) -> Self {
+        self.network = Some(network);
+        self
     }
 
     /// Connect to WebSocket and start listening for webhook events
-    pub async fn connect_and_listen(&self) -> Result<()> {
+    pub async fn connect_and_listen(self: &Arc<Self>) -> Result<()> {
         info!(
             endpoint = %self.endpoint_slug,
             target = %self.target_url,
@@ -220,13 +727,14 @@ impl TunnelClient {
             self.base_url
                 .replace("https://", "wss://")
                 .replace("http://", "ws://"),
-            self.access_token
+            self.access_token.lock().unwrap()
         );
 
         debug!("WebSocket URL: {}", ws_url);
 
         // Connect to WebSocket
-        let (ws_stream, _) = match connect_async(&ws_url).await {
+        let network = crate::http::settings_or(self.network.as_ref())?;
+        let (ws_stream, _) = match network.connect_websocket(&ws_url).await {
             Ok(stream) => stream,
             Err(e) => match e {
                 WsError::Http(response) => match response.status() {
@@ -236,15 +744,15 @@ impl TunnelClient {
                             .event_tx
                             .send(TunnelEvent::ConnectionError(msg.to_string()))
                             .await;
-                        return Err(anyhow!(msg));
+                        return Err(TunnelError::AuthenticationFailed.into());
                     }
                     StatusCode::NOT_FOUND => {
                         let msg = format!("Endpoint not found: '{}'.", self.endpoint_slug);
-                        let _ = self
-                            .event_tx
-                            .send(TunnelEvent::ConnectionError(msg.clone()))
-                            .await;
-                        return Err(anyhow!(msg));
+                        let _ = self.event_tx.send(TunnelEvent::ConnectionError(msg)).await;
+                        return Err(TunnelError::EndpointNotFound {
+                            slug: self.endpoint_slug.clone(),
+                        }
+                        .into());
                     }
                     status => {
                         let msg = format!("Connection failed with HTTP status: {}", status);
@@ -319,7 +827,10 @@ impl TunnelClient {
                                     .event_tx
                                     .send(TunnelEvent::ConnectionError(reason.to_string()))
                                     .await;
-                                return Err(anyhow!("Channel join failed: {}", reason));
+                                return Err(TunnelError::JoinFailed {
+                                    reason: reason.to_string(),
+                                }
+                                .into());
                             }
                         }
                     }
@@ -337,41 +848,25 @@ impl TunnelClient {
             }
         }
 
-        // Track last heartbeat time and counter
-        let mut last_heartbeat = tokio::time::Instant::now();
-        let mut heartbeat_counter = 2;
-        let heartbeat_interval = Duration::from_secs(30);
+        let (sink, outbound_rx) = ChannelSink::new();
+        let mut writer = spawn_writer(write, outbound_rx, |counter| ChannelMessage {
+            topic: "phoenix".to_string(),
+            event: "heartbeat".to_string(),
+            payload: serde_json::json!({}),
+            reference: Some(counter.to_string()),
+        });
 
-        // Listen for messages
+        // Listen for messages while forwarding tasks write replies through `sink`
         loop {
-            // Check if we need to send a heartbeat
-            if last_heartbeat.elapsed() >= heartbeat_interval {
-                let heartbeat = ChannelMessage {
-                    topic: "phoenix".to_string(),
-                    event: "heartbeat".to_string(),
-                    payload: serde_json::json!({}),
-                    reference: Some(heartbeat_counter.to_string()),
-                };
-                heartbeat_counter += 1;
-
-                if let Ok(json) = serde_json::to_string(&heartbeat)
-                    && let Err(e) = write.send(Message::Text(json.into())).await
-                {
-                    error!("Failed to send heartbeat: {}", e);
-                    break;
-                }
-                last_heartbeat = tokio::time::Instant::now();
-            }
-
-            // Use timeout to allow heartbeat checks
-            match tokio::time::timeout(Duration::from_millis(100), read.next()).await {
-                Ok(Some(msg)) => match msg {
-                    Ok(Message::Text(text)) => {
-                        if let Err(e) = self.handle_message(&text, &mut write).await {
+            tokio::select! {
+                _ = &mut writer => break,
+                msg = read.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        if let Err(e) = self.handle_message(&text, &sink).await {
                             error!("Error handling message: {}", e);
                         }
                     }
-                    Ok(Message::Close(frame)) => {
+                    Some(Ok(Message::Close(frame))) => {
                         info!("WebSocket closed: {:?}", frame);
                         let _ = self
                             .event_tx
@@ -381,17 +876,16 @@ impl TunnelClient {
                             .await;
                         break;
                     }
-                    Ok(Message::Ping(data)) => {
+                    Some(Ok(Message::Ping(data))) => {
                         debug!("Received ping, sending pong");
-                        if let Err(e) = write.send(Message::Pong(data)).await {
-                            error!("Failed to send pong: {}", e);
+                        if sink.push(Message::Pong(data)).await.is_err() {
                             break;
                         }
                     }
-                    Ok(_) => {
+                    Some(Ok(_)) => {
                         // Ignore other message types
                     }
-                    Err(e) => {
+                    Some(Err(e)) => {
                         error!("WebSocket error: {}", e);
                         let _ = self
                             .event_tx
@@ -402,37 +896,27 @@ impl TunnelClient {
                             .await;
                         break;
                     }
+                    None => {
+                        warn!("WebSocket stream ended");
+                        let _ = self
+                            .event_tx
+                            .send(TunnelEvent::ConnectionError(
+                                "WebSocket stream ended".to_string(),
+                            ))
+                            .await;
+                        break;
+                    }
                 },
-                Ok(None) => {
-                    warn!("WebSocket stream ended");
-                    let _ = self
-                        .event_tx
-                        .send(TunnelEvent::ConnectionError(
-                            "WebSocket stream ended".to_string(),
-                        ))
-                        .await;
-                    break;
-                }
-                Err(_) => {
-                    // Timeout - continue to check heartbeat
-                    continue;
-                }
             }
         }
 
+        // Forwarding tasks still holding `sink` see the connection as closed
+        writer.abort();
+
         Ok(())
     }
 
-    async fn handle_message(
-        &self,
-        text: &str,
-        write: &mut futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-            Message,
-        >,
-    ) -> Result<()> {
+    async fn handle_message(self: &Arc<Self>, text: &str, sink: &ChannelSink) -> Result<()> {
         let msg: ChannelMessage = serde_json::from_str(text)?;
 
         debug!(
@@ -451,20 +935,13 @@ impl TunnelClient {
                     match serde_json::from_value::<TunnelWebhookRequest>(request_data.clone()) {
                         Ok(request) => {
                             // Convert to model WebhookRequest for UI
+                            let body = request.body_bytes();
                             let model_request = crate::models::WebhookRequest {
                                 id: request.id.clone(),
                                 timestamp: chrono::Utc::now().timestamp(),
                                 remote_addr: "Tunnel".to_string(),
-                                headers: request
-                                    .headers
-                                    .iter()
-                                    .map(|(k, v)| (k.clone(), json_value_to_string(v)))
-                                    .collect(),
-                                content_length: request
-                                    .body
-                                    .as_ref()
-                                    .map(|b| b.len() as i64)
-                                    .unwrap_or(0),
+                                headers: request.headers.clone(),
+                                content_length: body.as_ref().map(|b| b.len() as i64).unwrap_or(0),
                                 method: request.method.clone(),
                                 url: request.path.clone(),
                                 path: Some(request.path.clone()),
@@ -474,17 +951,37 @@ impl TunnelClient {
                                     .map(|(k, v)| (k.clone(), json_value_to_string(v)))
                                     .collect(),
                                 created_at: chrono::Utc::now().to_rfc3339(),
-                                body_preview: request.body.clone(),
-                                body: request.body.clone(),
+                                body_preview: body
+                                    .as_ref()
+                                    .and_then(|b| b.as_text())
+                                    .map(str::to_string),
+                                body,
                             };
 
+                            let hold = self.interceptor.should_hold(
+                                &model_request.method,
+                                &request.path,
+                                &model_request.headers,
+                            );
+
                             // Notify UI
                             let _ = self
                                 .event_tx
                                 .send(TunnelEvent::WebhookReceived(Box::new(model_request)))
                                 .await;
 
-                            self.forward_webhook(request, write).await?;
+                            if hold {
+                                self.hold_webhook(request, sink).await;
+                                return Ok(());
+                            }
+
+                            let this = Arc::clone(self);
+                            let sink = sink.clone();
+                            self.pool.spawn(async move {
+                                if let Err(e) = this.forward_webhook(request, sink).await {
+                                    error!("Error forwarding webhook: {}", e);
+                                }
+                            });
                         }
                         Err(e) => {
                             let err_msg =
@@ -507,15 +1004,91 @@ impl TunnelClient {
         Ok(())
     }
 
+    /// Hold an intercepted webhook until the TUI forwards (possibly edited) or drops it.
+    async fn hold_webhook(self: &Arc<Self>, request: TunnelWebhookRequest, sink: &ChannelSink) {
+        let mut query_string: Vec<String> = request
+            .query_params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, json_value_to_string(v)))
+            .collect();
+        query_string.sort();
+        let held = HeldRequest {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            query_string: query_string.join("&"),
+            headers: request.headers.clone(),
+            body: request
+                .body_bytes()
+                .map(|b| String::from_utf8_lossy(b.as_bytes()).into_owned())
+                .unwrap_or_default(),
+        };
+        let original = held.clone();
+
+        info!(request_id = %request.id, "Holding intercepted webhook");
+        let decision = self.interceptor.hold(&request.id);
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::RequestIntercepted {
+                request_id: request.id.clone(),
+                request: held,
+                can_respond: false,
+            })
+            .await;
+
+        let this = Arc::clone(self);
+        let sink = sink.clone();
+        tokio::spawn(async move {
+            let Ok(decision) = decision.await else {
+                return;
+            };
+            match decision {
+                InterceptDecision::Forward(mut edited) => {
+                    edited.restore_redacted(&original);
+                    let mut request = request;
+                    request.method = edited.method;
+                    request.path = edited.path;
+                    request.query_params = crate::app::parse_query_string(&edited.query_string)
+                        .into_iter()
+                        .map(|(k, v)| (k, serde_json::Value::String(v)))
+                        .collect();
+                    request.headers = edited.headers;
+                    // Keep the original bytes unless the body was edited (it may be binary)
+                    if edited.body != original.body {
+                        request.body = (!edited.body.is_empty()).then_some(edited.body);
+                        request.body_encoding = None;
+                    }
+
+                    let forwarder = Arc::clone(&this);
+                    this.pool.spawn(async move {
+                        if let Err(e) = forwarder.forward_webhook(request, sink).await {
+                            error!("Error forwarding webhook: {}", e);
+                        }
+                    });
+                }
+                InterceptDecision::Drop | InterceptDecision::Respond(_) => {
+                    info!(request_id = %request.id, "Dropped intercepted webhook");
+                    let ack_message = ChannelMessage {
+                        topic: format!("cli:tunnel:{}", this.endpoint_slug),
+                        event: "request_ack".to_string(),
+                        payload: serde_json::json!({
+                            "request_id": request.id,
+                            "status": "error",
+                            "error": "Request dropped in intercept mode",
+                        }),
+                        reference: None,
+                    };
+                    if let Err(e) = sink.send(&ack_message).await {
+                        error!("Error acknowledging dropped webhook: {}", e);
+                    }
+                }
+            }
+        });
+    }
+
     async fn forward_webhook(
         &self,
         request: TunnelWebhookRequest,
-        write: &mut futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-            Message,
-        >,
+        sink: ChannelSink,
     ) -> Result<()> {
         info!(
             request_id = %request.id,
@@ -524,6 +1097,42 @@ impl TunnelClient {
             "Forwarding webhook to local server"
         );
 
+        let faults = self
+            .faults
+            .plan(&request.method, &request.path, &request.headers);
+        if !faults.is_empty() {
+            let _ = self
+                .event_tx
+                .send(TunnelEvent::FaultInjected {
+                    request_id: request.id.clone(),
+                    faults: faults.labels.join(", "),
+                })
+                .await;
+            tokio::time::sleep(faults.delay).await;
+        }
+        if let Some(failure) = faults.failure {
+            let error = injected_failure_error(failure).await;
+            warn!(request_id = %request.id, error = %error, "Injected fault");
+
+            let _ = self
+                .event_tx
+                .send(TunnelEvent::ForwardError {
+                    unreachable_target: None,
+                })
+                .await;
+            let ack_message = ChannelMessage {
+                topic: format!("cli:tunnel:{}", self.endpoint_slug),
+                event: "request_ack".to_string(),
+                payload: serde_json::json!({
+                    "request_id": request.id,
+                    "status": "error",
+                    "error": error,
+                }),
+                reference: None,
+            };
+            return sink.send(&ack_message).await;
+        }
+
         // Build target URL
         let target = format!("{}{}", self.target_url, request.path);
 
@@ -539,8 +1148,7 @@ impl TunnelClient {
             target
         };
 
-        // Create HTTP client
-        let client = reqwest::Client::new();
+        let client = crate::http::settings_or(self.network.as_ref())?.forwarding_client()?;
 
         // Build request with method
         let mut req_builder = match request.method.as_str() {
@@ -559,13 +1167,13 @@ impl TunnelClient {
         // Add headers (skip host — it will be set by reqwest)
         for (key, value) in &request.headers {
             if key.to_lowercase() != "host" {
-                req_builder = req_builder.header(key, json_value_to_string(value));
+                req_builder = req_builder.header(key, value);
             }
         }
 
         // Add body if present
-        if let Some(body) = &request.body {
-            req_builder = req_builder.body(body.clone());
+        if let Some(body) = request.body_bytes() {
+            req_builder = req_builder.body(body.into_bytes());
         }
 
         // Send request
@@ -592,8 +1200,7 @@ impl TunnelClient {
                     reference: None,
                 };
 
-                let ack_json = serde_json::to_string(&ack_message)?;
-                write.send(Message::Text(ack_json.into())).await?;
+                sink.send(&ack_message).await?;
             }
             Err(e) => {
                 error!(
@@ -602,7 +1209,15 @@ impl TunnelClient {
                     "Failed to forward request"
                 );
 
-                let _ = self.event_tx.send(TunnelEvent::ForwardError).await;
+                let unreachable_target = e.is_connect().then(|| target_base(&target_with_query));
+                let error = match &unreachable_target {
+                    Some(target) => unreachable_error(target, &e.to_string()),
+                    None => e.to_string(),
+                };
+                let _ = self
+                    .event_tx
+                    .send(TunnelEvent::ForwardError { unreachable_target })
+                    .await;
 
                 // Send error acknowledgment
                 let ack_message = ChannelMessage {
@@ -611,13 +1226,12 @@ impl TunnelClient {
                     payload: serde_json::json!({
                         "request_id": request.id,
                         "status": "error",
-                        "error": e.to_string(),
+                        "error": error,
                     }),
                     reference: None,
                 };
 
-                let ack_json = serde_json::to_string(&ack_message)?;
-                write.send(Message::Text(ack_json.into())).await?;
+                sink.send(&ack_message).await?;
             }
         }
 
@@ -625,12 +1239,17 @@ impl TunnelClient {
     }
 
     /// Connect with automatic reconnection on recoverable errors
-    pub async fn connect_with_reconnect(&self, config: ReconnectConfig) -> Result<()> {
+    pub async fn connect_with_reconnect(self: &Arc<Self>, config: ReconnectConfig) -> Result<()> {
         let mut attempt: u32 = 0;
+        // One refresh per rejection, so a token the server never accepts stays fatal
+        let mut refreshed = false;
 
         loop {
             let start = tokio::time::Instant::now();
             let result = self.connect_and_listen().await;
+            if start.elapsed() > Duration::from_secs(5) {
+                refreshed = false;
+            }
 
             match result {
                 Ok(()) => {
@@ -640,11 +1259,32 @@ impl TunnelClient {
                     }
                 }
                 Err(ref e) => {
-                    let err_msg = e.to_string();
+                    let mut err_msg = e.to_string();
+                    if is_auth_error(e)
+                        && !refreshed
+                        && let Some(refresher) = &self.token_refresher
+                    {
+                        refreshed = true;
+                        match refresh_token(refresher, &self.access_token, &self.event_tx).await {
+                            Ok(()) => continue,
+                            Err(refresh_err) => {
+                                warn!("Token refresh failed: {}", refresh_err);
+                                err_msg =
+                                    format!("{} (token refresh failed: {})", err_msg, refresh_err);
+                            }
+                        }
+                    }
                     if is_fatal_error(&err_msg) {
+                        let error = e
+                            .chain()
+                            .find_map(|cause| cause.downcast_ref::<TunnelError>())
+                            .cloned();
                         let _ = self
                             .event_tx
-                            .send(TunnelEvent::ReconnectFailed { reason: err_msg })
+                            .send(TunnelEvent::ReconnectFailed {
+                                reason: err_msg,
+                                error,
+                            })
                             .await;
                         return result;
                     }
@@ -662,6 +1302,7 @@ impl TunnelClient {
                     .event_tx
                     .send(TunnelEvent::ReconnectFailed {
                         reason: reason.clone(),
+                        error: None,
                     })
                     .await;
                 return Err(anyhow!(reason));
@@ -693,15 +1334,32 @@ impl TunnelClient {
 
 /// HTTP Tunnel forwarder - connects to /tunnel endpoint and forwards HTTP requests
 pub struct TunnelForwarder {
-    access_token: String,
+    access_token: std::sync::Mutex<String>,
+    token_refresher: Option<TokenRefresher>,
     local_host: String,
     local_port: u16,
     org_id: Option<String>,
     slug: Option<String>,
     base_url: String,
+    routing: RoutingTable,
+    local_tls: LocalTlsOptions,
+    http_client: reqwest::Client,
+    pool: ForwardPool,
+    streaming: bool,
+    /// Bodies of streamed tunnel requests still receiving `tunnel_request_chunk` frames
+    request_streams: std::sync::Mutex<HashMap<String, mpsc::Sender<Vec<u8>>>>,
+    /// Proxied WebSockets, keyed by the upgrade request ID, fed by `tunnel_ws_frame` frames
+    ws_sessions: std::sync::Mutex<HashMap<String, mpsc::Sender<Message>>>,
+    ws_connector: Option<tokio_tungstenite::Connector>,
+    interceptor: Interceptor,
+    faults: FaultInjector,
+    mocks: MockSet,
     event_tx: mpsc::Sender<TunnelEvent>,
 }
 
+/// Timeout for requests to the local target (time to response headers when streaming)
+const LOCAL_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl TunnelForwarder {
     pub fn new(
         access_token: String,
@@ -709,23 +1367,105 @@ impl TunnelForwarder {
         local_port: u16,
         org_id: Option<String>,
         slug: Option<String>,
+        base_url: Option<String>,
         event_tx: mpsc::Sender<TunnelEvent>,
-    ) -> Self {
-        let base_url = std::env::var("HOOKLISTENER_API_URL")
-            .unwrap_or_else(|_| "https://app.hooklistener.com".to_string());
-
-        Self {
-            access_token,
+    ) -> Result<Self> {
+        let base_url = match base_url {
+            Some(url) => url,
+            None => crate::config::api_url()?,
+        };
+        let routing =
+            RoutingTable::new(format!("http://{}:{}", local_host, local_port), Vec::new());
+        let http_client = reqwest::Client::builder()
+            .connect_timeout(LOCAL_REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+
+        Ok(Self {
+            access_token: std::sync::Mutex::new(access_token),
+            token_refresher: None,
             local_host,
             local_port,
             org_id,
             slug,
             base_url,
+            routing,
+            local_tls: LocalTlsOptions::default(),
+            http_client,
+            pool: ForwardPool::new(DEFAULT_MAX_IN_FLIGHT, event_tx.clone()),
+            streaming: false,
+            request_streams: std::sync::Mutex::new(HashMap::new()),
+            ws_sessions: std::sync::Mutex::new(HashMap::new()),
+            ws_connector: None,
+            interceptor: Interceptor::default(),
+            faults: FaultInjector::default(),
+            mocks: MockSet::default(),
             event_tx,
-        }
+        })
+    }
+
+    /// Hold requests matching the interceptor's rules until they are released in the TUI.
+    pub fn with_interceptor(mut self, interceptor: Interceptor) -> Self {
+        self.interceptor = interceptor;
+        self
+    }
+
+    /// Inject latency and failures into forwarding to test how senders react.
+    pub fn with_faults(mut self, faults: FaultInjector) -> Self {
+        self.faults = faults;
+        self
+    }
+
+    /// Refresh the access token and reconnect when the server rejects it.
+    pub fn with_token_refresher(mut self, refresher: TokenRefresher) -> Self {
+        self.token_refresher = Some(refresher);
+        self
+    }
+
+    /// Relay request and response bodies in chunks (`tunnel_response_start` /
+    /// `tunnel_response_chunk` / `tunnel_response_end`) instead of buffering them.
+    pub fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Answer matching requests with canned responses, always or when the target is down.
+    pub fn with_mocks(mut self, mocks: MockSet) -> Self {
+        self.mocks = mocks;
+        self
+    }
+
+    /// Limit how many requests are forwarded to the local target at once.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.pool = ForwardPool::new(max_in_flight, self.event_tx.clone());
+        self
+    }
+
+    /// Forward unmatched requests to `target` (e.g. `https://localhost:8443`)
+    /// instead of `http://local_host:local_port`.
+    pub fn with_target(mut self, target: String) -> Self {
+        self.routing = self.routing.with_default_target(target);
+        self
+    }
+
+    /// Route matching requests to other local targets; unmatched requests
+    /// still go to `local_host:local_port`.
+    pub fn with_routes(mut self, routes: Vec<Route>) -> Self {
+        self.routing = RoutingTable::new(self.routing.default_target().to_string(), routes);
+        self
+    }
+
+    /// Use custom TLS settings for HTTPS local targets. Call after
+    /// [`with_target`](Self::with_target) so an SNI override is pinned to the right address.
+    pub fn with_local_tls(mut self, local_tls: LocalTlsOptions) -> Result<Self> {
+        self.http_client =
+            local_tls.build_client(self.routing.default_target(), LOCAL_REQUEST_TIMEOUT)?;
+        self.ws_connector = local_tls.ws_connector()?;
+        self.local_tls = local_tls;
+        Ok(self)
     }
 
-    pub async fn connect_and_forward(&self) -> Result<()> {
+    pub async fn connect_and_forward(self: &Arc<Self>) -> Result<()> {
         info!(
             local_host = %self.local_host,
             local_port = %self.local_port,
@@ -740,13 +1480,13 @@ impl TunnelForwarder {
             self.base_url
                 .replace("https://", "wss://")
                 .replace("http://", "ws://"),
-            self.access_token
+            self.access_token.lock().unwrap()
         );
 
         debug!("Tunnel WebSocket URL: {}", ws_url);
 
         // Connect to WebSocket
-        let (ws_stream, _) = match connect_async(&ws_url).await {
+        let (ws_stream, _) = match crate::http::connect_websocket(&ws_url).await {
             Ok(stream) => stream,
             Err(e) => {
                 let msg = format!("Failed to connect to tunnel: {}", e);
@@ -754,6 +1494,11 @@ impl TunnelForwarder {
                     .event_tx
                     .send(TunnelEvent::ConnectionError(msg.clone()))
                     .await;
+                if let WsError::Http(response) = &e
+                    && response.status() == StatusCode::UNAUTHORIZED
+                {
+                    return Err(TunnelError::AuthenticationFailed.into());
+                }
                 return Err(anyhow!(msg));
             }
         };
@@ -776,6 +1521,10 @@ impl TunnelForwarder {
             info!(slug = %slug, "Requesting static tunnel");
         }
 
+        if self.streaming {
+            join_payload["streaming"] = serde_json::Value::Bool(true);
+        }
+
         let join_message = ChannelMessage {
             topic: "tunnel:connect".to_string(),
             event: "phx_join".to_string(),
@@ -870,57 +1619,46 @@ impl TunnelForwarder {
             }
         }
 
-        // Track last ping time
-        let mut last_ping = tokio::time::Instant::now();
-        let ping_interval = Duration::from_secs(30);
-        let mut ping_counter = 2;
-
-        // Listen for tunnel_request events
-        loop {
-            // Check if we need to send a ping
-            if last_ping.elapsed() >= ping_interval {
-                let ping_msg = ChannelMessage {
-                    topic: tunnel_topic.clone(),
-                    event: "ping".to_string(),
-                    payload: serde_json::json!({}),
-                    reference: Some(ping_counter.to_string()),
-                };
-                ping_counter += 1;
+        // Request bodies still streaming from a previous connection can never complete,
+        // and proxied WebSockets lose their public side; dropping the senders closes them
+        self.request_streams.lock().unwrap().clear();
+        self.ws_sessions.lock().unwrap().clear();
 
-                if let Ok(json) = serde_json::to_string(&ping_msg)
-                    && let Err(e) = write.send(Message::Text(json.into())).await
-                {
-                    error!("Failed to send ping: {}", e);
-                    break;
-                }
-                last_ping = tokio::time::Instant::now();
-            }
+        let (sink, outbound_rx) = ChannelSink::new();
+        let ping_topic = tunnel_topic.clone();
+        let mut writer = spawn_writer(write, outbound_rx, move |counter| ChannelMessage {
+            topic: ping_topic.clone(),
+            event: "ping".to_string(),
+            payload: serde_json::json!({}),
+            reference: Some(counter.to_string()),
+        });
 
-            // Use timeout to allow ping checks
-            match tokio::time::timeout(Duration::from_millis(100), read.next()).await {
-                Ok(Some(msg)) => match msg {
-                    Ok(Message::Text(text)) => {
+        // Listen for tunnel_request events while forwarding tasks write replies through `sink`
+        loop {
+            tokio::select! {
+                _ = &mut writer => break,
+                msg = read.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
                         if let Err(e) = self
-                            .handle_tunnel_message(&text, &mut write, &tunnel_topic)
+                            .handle_tunnel_message(&text, &sink, &tunnel_topic)
                             .await
                         {
                             error!("Error handling tunnel message: {}", e);
                         }
                     }
-                    Ok(Message::Close(frame)) => {
+                    Some(Ok(Message::Close(frame))) => {
                         info!("Tunnel WebSocket closed: {:?}", frame);
                         let _ = self.event_tx.send(TunnelEvent::Disconnected).await;
                         break;
                     }
-                    Ok(Message::Ping(data)) => {
+                    Some(Ok(Message::Ping(data))) => {
                         debug!("Received ping, sending pong");
-                        if let Err(e) = write.send(Message::Pong(data)).await {
-                            error!("Failed to send pong: {}", e);
+                        if sink.push(Message::Pong(data)).await.is_err() {
                             break;
                         }
                     }
-                    Ok(_) => {}
-                    Err(e) => {
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
                         error!("Tunnel WebSocket error: {}", e);
                         let _ = self
                             .event_tx
@@ -931,31 +1669,25 @@ impl TunnelForwarder {
                             .await;
                         break;
                     }
-                },
-                Ok(None) => {
-                    warn!("Tunnel WebSocket stream ended");
-                    let _ = self.event_tx.send(TunnelEvent::Disconnected).await;
-                    break;
-                }
-                Err(_) => {
-                    // Timeout - continue to check ping
-                    continue;
-                }
+                    None => {
+                        warn!("Tunnel WebSocket stream ended");
+                        let _ = self.event_tx.send(TunnelEvent::Disconnected).await;
+                        break;
+                    }
+                },
             }
         }
 
+        // Forwarding tasks still holding `sink` see the connection as closed
+        writer.abort();
+
         Ok(())
     }
 
     async fn handle_tunnel_message(
-        &self,
+        self: &Arc<Self>,
         text: &str,
-        write: &mut futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-            Message,
-        >,
+        sink: &ChannelSink,
         tunnel_topic: &str,
     ) -> Result<()> {
         let msg: ChannelMessage = serde_json::from_str(text)?;
@@ -990,42 +1722,50 @@ impl TunnelForwarder {
                         .and_then(|v| v.as_str())
                         .unwrap_or("")
                         .to_string();
+                    // Headers arrive as an object (repeated names as arrays) or as pairs
                     let headers = payload
                         .get("headers")
-                        .and_then(|v| v.as_object())
-                        .cloned()
+                        .and_then(|v| Headers::deserialize(v).ok())
                         .unwrap_or_default();
 
                     // Decode body based on body_encoding field
-                    let body_encoding = payload
-                        .get("body_encoding")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("raw");
-                    let raw_body = payload.get("body").and_then(|v| v.as_str()).unwrap_or("");
-                    let body: Vec<u8> = if body_encoding == "base64" {
-                        // Decode base64 body
-                        URL_SAFE_NO_PAD.decode(raw_body).unwrap_or_else(|e| {
-                            warn!("Failed to decode base64 body: {}", e);
-                            raw_body.as_bytes().to_vec()
-                        })
-                    } else {
-                        // Use body as-is (raw UTF-8)
-                        raw_body.as_bytes().to_vec()
-                    };
+                    let body = decode_body(payload);
 
-                    // Convert headers Map<String, Value> → HashMap<String, String>
-                    let headers_map: HashMap<String, String> = headers
-                        .iter()
-                        .map(|(k, v)| (k.clone(), json_value_to_string(v)))
-                        .collect();
-
-                    // Convert body bytes to Option<String>
-                    let body_string = if body.is_empty() {
-                        None
+                    // Streamed request bodies continue in tunnel_request_chunk frames
+                    let body_stream = if payload
+                        .get("streaming")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false)
+                    {
+                        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+                        self.request_streams
+                            .lock()
+                            .unwrap()
+                            .insert(request_id.clone(), tx);
+                        Some(rx)
                     } else {
-                        Some(String::from_utf8_lossy(&body).into_owned())
+                        None
                     };
 
+                    // Keep the exact bytes for the UI; binary bodies are shown as hex
+                    let ui_body = (!body.is_empty()).then(|| Body::from(body.clone()));
+
+                    let decision = self
+                        .routing
+                        .resolve(&method, &path, &query_string, &headers);
+                    let websocket_upgrade = is_websocket_upgrade(&headers);
+                    // Streamed bodies and WebSocket upgrades are never held
+                    let held = (body_stream.is_none()
+                        && !websocket_upgrade
+                        && self.interceptor.should_hold(&method, &path, &headers))
+                    .then(|| HeldRequest {
+                        method: method.clone(),
+                        path: path.clone(),
+                        query_string: query_string.clone(),
+                        headers: headers.clone(),
+                        body: String::from_utf8_lossy(&body).into_owned(),
+                    });
+
                     // Notify UI about request
                     let _ = self
                         .event_tx
@@ -1033,24 +1773,165 @@ impl TunnelForwarder {
                             request_id: request_id.clone(),
                             method: method.clone(),
                             path: path.clone(),
-                            headers: headers_map,
-                            body: body_string,
-                            query_string: query_string.clone(),
+                            headers: headers.clone(),
+                            body: ui_body,
+                            query_string,
+                            target: decision.url.clone(),
+                            route: decision.route,
                         })
                         .await;
+                    if !body.is_empty() {
+                        let _ = self
+                            .event_tx
+                            .send(TunnelEvent::BytesTransferred {
+                                request_id: request_id.clone(),
+                                bytes_in: body.len() as u64,
+                                bytes_out: 0,
+                            })
+                            .await;
+                    }
 
-                    // Forward the request
-                    self.forward_tunnel_request(
-                        request_id,
-                        method,
-                        path,
-                        query_string,
-                        headers,
-                        body,
-                        write,
-                        tunnel_topic,
-                    )
-                    .await?;
+                    if websocket_upgrade {
+                        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+                        self.ws_sessions
+                            .lock()
+                            .unwrap()
+                            .insert(request_id.clone(), tx);
+
+                        // WebSockets are long-lived, so they bypass the forwarding pool
+                        let this = Arc::clone(self);
+                        let sink = sink.clone();
+                        let tunnel_topic = tunnel_topic.to_string();
+                        tokio::spawn(async move {
+                            if let Err(e) = this
+                                .proxy_websocket(
+                                    request_id,
+                                    decision.url,
+                                    headers,
+                                    rx,
+                                    &sink,
+                                    &tunnel_topic,
+                                )
+                                .await
+                            {
+                                error!("Error proxying WebSocket: {}", e);
+                            }
+                        });
+                        return Ok(());
+                    }
+
+                    if let Some(held) = held {
+                        self.hold_tunnel_request(request_id, held, body, sink, tunnel_topic)
+                            .await;
+                        return Ok(());
+                    }
+
+                    // Forward the request once a slot in the pool is free. A streamed body
+                    // starts right away instead: its chunks are only read once forwarding
+                    // begins, and the read loop waits for them to be taken
+                    let streamed = body_stream.is_some();
+                    let this = Arc::clone(self);
+                    let sink = sink.clone();
+                    let tunnel_topic = tunnel_topic.to_string();
+                    let task = async move {
+                        if let Err(e) = this
+                            .forward_tunnel_request(
+                                request_id,
+                                method,
+                                path,
+                                decision.url,
+                                headers,
+                                body,
+                                body_stream,
+                                &sink,
+                                &tunnel_topic,
+                            )
+                            .await
+                        {
+                            error!("Error forwarding tunnel request: {}", e);
+                        }
+                    };
+                    if streamed {
+                        tokio::spawn(task);
+                    } else {
+                        self.pool.spawn(task);
+                    }
+                }
+            }
+            "tunnel_request_chunk" => {
+                if let Some(payload) = msg.payload.as_object()
+                    && let Some(request_id) = payload.get("request_id").and_then(|v| v.as_str())
+                {
+                    let chunk = decode_body(payload);
+                    let len = chunk.len() as u64;
+                    // Waiting here slows the server down to the local target's pace
+                    let tx = self
+                        .request_streams
+                        .lock()
+                        .unwrap()
+                        .get(request_id)
+                        .cloned();
+                    if deliver(tx, chunk).await {
+                        let _ = self
+                            .event_tx
+                            .send(TunnelEvent::BytesTransferred {
+                                request_id: request_id.to_string(),
+                                bytes_in: len,
+                                bytes_out: 0,
+                            })
+                            .await;
+                    } else {
+                        debug!(request_id = %request_id, "Dropping chunk for unknown or stalled request");
+                        self.request_streams.lock().unwrap().remove(request_id);
+                    }
+                }
+            }
+            "tunnel_ws_frame" => {
+                if let Some(payload) = msg.payload.as_object()
+                    && let Some(request_id) = payload.get("request_id").and_then(|v| v.as_str())
+                {
+                    let data = decode_body(payload);
+                    let message = match payload.get("opcode").and_then(|v| v.as_str()) {
+                        Some("binary") => Message::Binary(data.into()),
+                        _ => Message::Text(String::from_utf8_lossy(&data).into_owned().into()),
+                    };
+                    let tx = self.ws_sessions.lock().unwrap().get(request_id).cloned();
+                    if !deliver(tx, message).await {
+                        debug!(request_id = %request_id, "Dropping frame for unknown or stalled WebSocket");
+                        self.ws_sessions.lock().unwrap().remove(request_id);
+                    }
+                }
+            }
+            "tunnel_ws_close" => {
+                let tx = msg
+                    .payload
+                    .get("request_id")
+                    .and_then(|v| v.as_str())
+                    .and_then(|request_id| self.ws_sessions.lock().unwrap().remove(request_id));
+                if let Some(tx) = tx {
+                    let code = msg
+                        .payload
+                        .get("code")
+                        .and_then(|v| v.as_u64())
+                        .and_then(|c| u16::try_from(c).ok())
+                        .unwrap_or(1000);
+                    let reason = msg
+                        .payload
+                        .get("reason")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    let close = Message::Close(Some(CloseFrame {
+                        code: CloseCode::from(code),
+                        reason: reason.into(),
+                    }));
+                    deliver(Some(tx), close).await;
+                }
+            }
+            "tunnel_request_end" => {
+                if let Some(request_id) = msg.payload.get("request_id").and_then(|v| v.as_str()) {
+                    // Dropping the sender ends the body stream
+                    self.request_streams.lock().unwrap().remove(request_id);
                 }
             }
             "phx_reply" => {
@@ -1072,21 +1953,135 @@ impl TunnelForwarder {
         Ok(())
     }
 
+    /// Hold an intercepted request until the TUI forwards (possibly edited), drops or
+    /// answers it.
+    async fn hold_tunnel_request(
+        self: &Arc<Self>,
+        request_id: String,
+        held: HeldRequest,
+        body: Vec<u8>,
+        sink: &ChannelSink,
+        tunnel_topic: &str,
+    ) {
+        info!(request_id = %request_id, "Holding intercepted request");
+        let decision = self.interceptor.hold(&request_id);
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::RequestIntercepted {
+                request_id: request_id.clone(),
+                request: held.clone(),
+                can_respond: true,
+            })
+            .await;
+
+        let this = Arc::clone(self);
+        let sink = sink.clone();
+        let tunnel_topic = tunnel_topic.to_string();
+        tokio::spawn(async move {
+            let Ok(decision) = decision.await else {
+                return;
+            };
+            match decision {
+                InterceptDecision::Forward(mut edited) => {
+                    edited.restore_redacted(&held);
+                    let decision = this.routing.resolve(
+                        &edited.method,
+                        &edited.path,
+                        &edited.query_string,
+                        &edited.headers,
+                    );
+                    // Keep the original bytes unless the body was edited (it may be binary)
+                    let body = if edited.body == held.body {
+                        body
+                    } else {
+                        edited.body.into_bytes()
+                    };
+
+                    let forwarder = Arc::clone(&this);
+                    this.pool.spawn(async move {
+                        if let Err(e) = forwarder
+                            .forward_tunnel_request(
+                                request_id,
+                                edited.method,
+                                edited.path,
+                                decision.url,
+                                edited.headers,
+                                body,
+                                None,
+                                &sink,
+                                &tunnel_topic,
+                            )
+                            .await
+                        {
+                            error!("Error forwarding tunnel request: {}", e);
+                        }
+                    });
+                }
+                InterceptDecision::Drop => {
+                    info!(request_id = %request_id, "Dropped intercepted request");
+                    let error = "Request dropped in intercept mode".to_string();
+                    let _ = this
+                        .event_tx
+                        .send(TunnelEvent::RequestFailed {
+                            request_id: request_id.clone(),
+                            error: error.clone(),
+                            unreachable_target: None,
+                        })
+                        .await;
+                    if let Err(e) = this
+                        .send_tunnel_error(&request_id, &error, &sink, &tunnel_topic)
+                        .await
+                    {
+                        error!("Error dropping tunnel request: {}", e);
+                    }
+                }
+                InterceptDecision::Respond(response) => {
+                    info!(
+                        request_id = %request_id,
+                        status = %response.status,
+                        "Answered intercepted request"
+                    );
+                    let _ = this
+                        .event_tx
+                        .send(TunnelEvent::RequestForwarded {
+                            request_id: request_id.clone(),
+                            status: response.status,
+                            duration_ms: 0,
+                            response_headers: response.headers.clone(),
+                            response_body: Some(Body::from(response.body.clone())),
+                        })
+                        .await;
+                    let response_message = ChannelMessage {
+                        topic: tunnel_topic.clone(),
+                        event: "tunnel_response".to_string(),
+                        payload: serde_json::json!({
+                            "request_id": request_id,
+                            "status": response.status,
+                            "headers": response.headers.to_wire(),
+                            "body": response.body,
+                            "body_encoding": "raw",
+                        }),
+                        reference: None,
+                    };
+                    if let Err(e) = sink.send(&response_message).await {
+                        error!("Error answering tunnel request: {}", e);
+                    }
+                }
+            }
+        });
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn forward_tunnel_request(
         &self,
         request_id: String,
         method: String,
         path: String,
-        query_string: String,
-        headers: serde_json::Map<String, serde_json::Value>,
+        target: String,
+        headers: Headers,
         body: Vec<u8>,
-        write: &mut futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-            Message,
-        >,
+        body_stream: Option<mpsc::Receiver<Vec<u8>>>,
+        sink: &ChannelSink,
         tunnel_topic: &str,
     ) -> Result<()> {
         let start_time = tokio::time::Instant::now();
@@ -1095,20 +2090,64 @@ impl TunnelForwarder {
             request_id = %request_id,
             method = %method,
             path = %path,
+            target = %target,
             "Forwarding tunnel request to local server"
         );
 
-        // Build target URL
-        let mut target = format!("http://{}:{}{}", self.local_host, self.local_port, path);
-        if !query_string.is_empty() {
-            target.push('?');
-            target.push_str(&query_string);
+        let faults = self.faults.plan(&method, &path, &headers);
+        if !faults.is_empty() {
+            let _ = self
+                .event_tx
+                .send(TunnelEvent::FaultInjected {
+                    request_id: request_id.clone(),
+                    faults: faults.labels.join(", "),
+                })
+                .await;
+            tokio::time::sleep(faults.delay).await;
+        }
+        if let Some(failure) = faults.failure {
+            return self
+                .send_injected_failure(&request_id, failure, start_time, sink, tunnel_topic)
+                .await;
         }
 
-        // Create HTTP client with timeout
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()?;
+        // Only capture what templates need when mocks are configured
+        let mock_request = (!self.mocks.is_empty()).then(|| MockRequest {
+            request_id: request_id.clone(),
+            method: method.clone(),
+            path: path.clone(),
+            query_string: target
+                .split_once('?')
+                .map(|(_, query)| query.to_string())
+                .unwrap_or_default(),
+            headers: headers.clone(),
+            body: String::from_utf8_lossy(&body).into_owned(),
+        });
+        let find_mock = |mode| {
+            mock_request.as_ref().and_then(|request| {
+                self.mocks
+                    .find(mode, &request.method, &request.path, &request.headers)
+                    .map(|rule| (rule, request))
+            })
+        };
+
+        if let Some((rule, request)) = find_mock(MockMode::Always) {
+            return self
+                .send_mock_response(
+                    rule,
+                    request,
+                    MockMode::Always,
+                    start_time,
+                    sink,
+                    tunnel_topic,
+                )
+                .await;
+        }
+
+        let target = self
+            .local_tls
+            .apply_sni(&target, self.routing.default_target());
+        let client = &self.http_client;
 
         // Build request
         let mut req_builder = match method.as_str() {
@@ -1125,7 +2164,7 @@ impl TunnelForwarder {
                     .send_tunnel_error(
                         &request_id,
                         &format!("Unsupported method: {}", method),
-                        write,
+                        sink,
                         tunnel_topic,
                     )
                     .await;
@@ -1133,46 +2172,100 @@ impl TunnelForwarder {
             }
         };
 
-        // Add headers (skip host — it will be set by reqwest)
+        // Add headers (skip host — it will be set by reqwest); repeated names are appended
         for (key, value) in headers {
-            if key.to_lowercase() != "host" {
-                let value_str = json_value_to_string(&value);
-                if let Ok(header_name) = reqwest::header::HeaderName::from_bytes(key.as_bytes())
-                    && let Ok(header_value) = reqwest::header::HeaderValue::from_str(&value_str)
-                {
-                    req_builder = req_builder.header(header_name, header_value);
-                }
+            if key.to_lowercase() != "host"
+                && let Ok(header_name) = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+                && let Ok(header_value) = reqwest::header::HeaderValue::from_str(&value)
+            {
+                req_builder = req_builder.header(header_name, header_value);
             }
         }
 
-        // Add body if present
-        if !body.is_empty() {
-            req_builder = req_builder.body(body);
+        // Add body if present, continuing with streamed chunks as they arrive
+        match body_stream {
+            Some(rx) => {
+                let initial = futures_util::stream::iter((!body.is_empty()).then_some(body));
+                let rest = futures_util::stream::unfold(rx, |mut rx| async move {
+                    rx.recv().await.map(|chunk| (chunk, rx))
+                });
+                let stream = initial.chain(rest).map(Ok::<_, std::io::Error>);
+                req_builder = req_builder.body(reqwest::Body::wrap_stream(stream));
+            }
+            None if !body.is_empty() => {
+                req_builder = req_builder.body(body);
+            }
+            None => {}
         }
 
+        // Streamed responses may stay open indefinitely (SSE, long polling), so only
+        // the wait for response headers is bounded when streaming
+        let result = if self.streaming {
+            match tokio::time::timeout(LOCAL_REQUEST_TIMEOUT, req_builder.send()).await {
+                Ok(result) => result.map_err(|e| (e.is_connect(), e.to_string())),
+                Err(_) => Err((false, "timed out waiting for response headers".to_string())),
+            }
+        } else {
+            req_builder
+                .timeout(LOCAL_REQUEST_TIMEOUT)
+                .send()
+                .await
+                .map_err(|e| (e.is_connect(), e.to_string()))
+        };
+
         // Send request and handle response
-        match req_builder.send().await {
+        match result {
             Ok(response) => {
+                if self.streaming && should_stream(response.headers()) {
+                    return self
+                        .stream_tunnel_response(
+                            request_id,
+                            response,
+                            start_time,
+                            sink,
+                            tunnel_topic,
+                        )
+                        .await;
+                }
+
                 let status = response.status().as_u16();
-                let response_headers: HashMap<String, String> = response
-                    .headers()
-                    .iter()
-                    .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap_or("").to_string()))
-                    .collect();
-
-                // Get response as bytes to handle binary content properly
-                let response_bytes = response.bytes().await.unwrap_or_default();
-
-                // Check if the response is valid UTF-8 (text) or binary
-                let (response_body, body_encoding) =
-                    if let Ok(text) = std::str::from_utf8(&response_bytes) {
-                        // Valid UTF-8, send as raw
-                        (text.to_string(), "raw")
-                    } else {
-                        // Binary content, encode as base64
-                        (URL_SAFE_NO_PAD.encode(&response_bytes), "base64")
+                let response_headers = Headers::from_header_map(response.headers());
+
+                // Get response as bytes to handle binary content properly; a body that
+                // cannot be read fails the request rather than arriving empty
+                let response_bytes =
+                    match tokio::time::timeout(LOCAL_REQUEST_TIMEOUT, response.bytes()).await {
+                        Ok(Ok(bytes)) => bytes,
+                        Ok(Err(e)) => {
+                            let error_msg = format!("Failed to read response body: {}", e);
+                            return self
+                                .fail_tunnel_request(
+                                    &request_id,
+                                    &error_msg,
+                                    None,
+                                    start_time,
+                                    sink,
+                                    tunnel_topic,
+                                )
+                                .await;
+                        }
+                        Err(_) => {
+                            return self
+                                .fail_tunnel_request(
+                                    &request_id,
+                                    "Timed out reading response body",
+                                    None,
+                                    start_time,
+                                    sink,
+                                    tunnel_topic,
+                                )
+                                .await;
+                        }
                     };
 
+                // Send as raw when the response is valid UTF-8, base64 for binary content
+                let (response_body, body_encoding) = encode_body(&response_bytes);
+
                 let duration_ms = start_time.elapsed().as_millis() as u64;
 
                 info!(
@@ -1184,6 +2277,14 @@ impl TunnelForwarder {
                 );
 
                 // Notify UI
+                let _ = self
+                    .event_tx
+                    .send(TunnelEvent::BytesTransferred {
+                        request_id: request_id.clone(),
+                        bytes_in: 0,
+                        bytes_out: response_bytes.len() as u64,
+                    })
+                    .await;
                 let _ = self
                     .event_tx
                     .send(TunnelEvent::RequestForwarded {
@@ -1191,7 +2292,7 @@ impl TunnelForwarder {
                         status,
                         duration_ms,
                         response_headers: response_headers.clone(),
-                        response_body: Some(response_body.clone()),
+                        response_body: Some(Body::from(response_bytes.to_vec())),
                     })
                     .await;
 
@@ -1202,55 +2303,640 @@ impl TunnelForwarder {
                     payload: serde_json::json!({
                         "request_id": request_id,
                         "status": status,
-                        "headers": response_headers,
+                        "headers": response_headers.to_wire(),
                         "body": response_body,
                         "body_encoding": body_encoding,
                     }),
                     reference: None,
                 };
 
-                let response_json = serde_json::to_string(&response_message)?;
-                write.send(Message::Text(response_json.into())).await?;
+                sink.send(&response_message).await?;
+            }
+            Err((connect_failed, e)) => {
+                if connect_failed && let Some((rule, request)) = find_mock(MockMode::Fallback) {
+                    warn!(
+                        request_id = %request_id,
+                        error = %e,
+                        "Local target unreachable, answering with fallback mock"
+                    );
+                    return self
+                        .send_mock_response(
+                            rule,
+                            request,
+                            MockMode::Fallback,
+                            start_time,
+                            sink,
+                            tunnel_topic,
+                        )
+                        .await;
+                }
+
+                let unreachable_target = connect_failed.then(|| target_base(&target));
+                let error_msg = match &unreachable_target {
+                    Some(target) => unreachable_error(target, &e),
+                    None => format!("Failed to forward request: {}", e),
+                };
+                self.fail_tunnel_request(
+                    &request_id,
+                    &error_msg,
+                    unreachable_target,
+                    start_time,
+                    sink,
+                    tunnel_topic,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Report a request the local target did not answer to the UI and the server.
+    async fn fail_tunnel_request(
+        &self,
+        request_id: &str,
+        error_msg: &str,
+        unreachable_target: Option<String>,
+        start_time: tokio::time::Instant,
+        sink: &ChannelSink,
+        tunnel_topic: &str,
+    ) -> Result<()> {
+        let duration_ms = start_time.elapsed().as_millis() as u64;
+        error!(
+            request_id = %request_id,
+            error = %error_msg,
+            duration_ms = %duration_ms,
+            "Request forwarding failed"
+        );
+
+        // Notify UI
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::RequestFailed {
+                request_id: request_id.to_string(),
+                error: error_msg.to_string(),
+                unreachable_target,
+            })
+            .await;
+
+        // Send tunnel_error back to server
+        self.send_tunnel_error(request_id, error_msg, sink, tunnel_topic)
+            .await
+    }
+
+    /// Fail a tunnel request with an injected fault instead of forwarding it.
+    async fn send_injected_failure(
+        &self,
+        request_id: &str,
+        failure: Fault,
+        start_time: tokio::time::Instant,
+        sink: &ChannelSink,
+        tunnel_topic: &str,
+    ) -> Result<()> {
+        let Fault::Status(status) = failure else {
+            let error = injected_failure_error(failure).await;
+            warn!(request_id = %request_id, error = %error, "Injected fault");
+            let _ = self
+                .event_tx
+                .send(TunnelEvent::RequestFailed {
+                    request_id: request_id.to_string(),
+                    error: error.clone(),
+                    unreachable_target: None,
+                })
+                .await;
+            return self
+                .send_tunnel_error(request_id, &error, sink, tunnel_topic)
+                .await;
+        };
+
+        warn!(request_id = %request_id, status = %status, "Injected fault response");
+        let body = format!("Injected fault: HTTP {}", status);
+        let headers: Headers = [("content-type", "text/plain")].into_iter().collect();
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::RequestForwarded {
+                request_id: request_id.to_string(),
+                status,
+                duration_ms: start_time.elapsed().as_millis() as u64,
+                response_headers: headers.clone(),
+                response_body: Some(Body::from(body.clone())),
+            })
+            .await;
+
+        sink.send(&ChannelMessage {
+            topic: tunnel_topic.to_string(),
+            event: "tunnel_response".to_string(),
+            payload: serde_json::json!({
+                "request_id": request_id,
+                "status": status,
+                "headers": headers.to_wire(),
+                "body": body,
+                "body_encoding": "raw",
+            }),
+            reference: None,
+        })
+        .await
+    }
+
+    /// Answer a request with a rendered mock response after the rule's delay.
+    async fn send_mock_response(
+        &self,
+        rule: &MockRule,
+        request: &MockRequest,
+        mode: MockMode,
+        start_time: tokio::time::Instant,
+        sink: &ChannelSink,
+        tunnel_topic: &str,
+    ) -> Result<()> {
+        if rule.delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(rule.delay_ms)).await;
+        }
+
+        let response = rule.render(request);
+        let duration_ms = start_time.elapsed().as_millis() as u64;
+
+        info!(
+            request_id = %request.request_id,
+            status = %response.status,
+            mode = ?mode,
+            "Answered tunnel request with mock response"
+        );
+
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::BytesTransferred {
+                request_id: request.request_id.clone(),
+                bytes_in: 0,
+                bytes_out: response.body.len() as u64,
+            })
+            .await;
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::RequestMocked {
+                request_id: request.request_id.clone(),
+                status: response.status,
+                duration_ms,
+                response_headers: response.headers.clone(),
+                response_body: Some(Body::from(response.body.clone())),
+                mode,
+            })
+            .await;
+
+        sink.send(&ChannelMessage {
+            topic: tunnel_topic.to_string(),
+            event: "tunnel_response".to_string(),
+            payload: serde_json::json!({
+                "request_id": request.request_id,
+                "status": response.status,
+                "headers": response.headers.to_wire(),
+                "body": response.body,
+                "body_encoding": "raw",
+            }),
+            reference: None,
+        })
+        .await
+    }
+
+    /// Relay a local response incrementally: a `tunnel_response_start` frame with the
+    /// status and headers, one `tunnel_response_chunk` per body chunk, then
+    /// `tunnel_response_end` (with `error` set if the local stream broke off).
+    async fn stream_tunnel_response(
+        &self,
+        request_id: String,
+        mut response: reqwest::Response,
+        start_time: tokio::time::Instant,
+        sink: &ChannelSink,
+        tunnel_topic: &str,
+    ) -> Result<()> {
+        let status = response.status().as_u16();
+        let response_headers = Headers::from_header_map(response.headers());
+
+        info!(
+            request_id = %request_id,
+            status = %status,
+            "Streaming response from local server"
+        );
+
+        sink.send(&ChannelMessage {
+            topic: tunnel_topic.to_string(),
+            event: "tunnel_response_start".to_string(),
+            payload: serde_json::json!({
+                "request_id": request_id,
+                "status": status,
+                "headers": response_headers.to_wire(),
+            }),
+            reference: None,
+        })
+        .await?;
+
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::ResponseStreamStarted {
+                request_id: request_id.clone(),
+                status,
+                response_headers,
+            })
+            .await;
+
+        // Keep one byte past the preview limit so the UI marks it as truncated
+        let preview_limit = crate::app::MAX_BODY_SIZE + 1;
+        let mut preview = Vec::new();
+        let mut seq: u64 = 0;
+        let mut unreported: u64 = 0;
+        let mut last_report = tokio::time::Instant::now();
+
+        let error = loop {
+            match response.chunk().await {
+                Ok(Some(chunk)) => {
+                    let take = preview_limit.saturating_sub(preview.len()).min(chunk.len());
+                    preview.extend_from_slice(&chunk[..take]);
+
+                    let (body, body_encoding) = encode_body(&chunk);
+                    let chunk_message = ChannelMessage {
+                        topic: tunnel_topic.to_string(),
+                        event: "tunnel_response_chunk".to_string(),
+                        payload: serde_json::json!({
+                            "request_id": request_id,
+                            "seq": seq,
+                            "body": body,
+                            "body_encoding": body_encoding,
+                        }),
+                        reference: None,
+                    };
+                    if let Err(e) = sink.send(&chunk_message).await {
+                        break Some(e.to_string());
+                    }
+                    seq += 1;
+
+                    unreported += chunk.len() as u64;
+                    if last_report.elapsed() >= STREAM_PROGRESS_INTERVAL {
+                        self.report_bytes_out(&request_id, unreported).await;
+                        unreported = 0;
+                        last_report = tokio::time::Instant::now();
+                    }
+                }
+                Ok(None) => break None,
+                Err(e) => break Some(format!("Local response stream failed: {}", e)),
+            }
+        };
+
+        if unreported > 0 {
+            self.report_bytes_out(&request_id, unreported).await;
+        }
+
+        let mut end_payload = serde_json::json!({
+            "request_id": request_id,
+            "chunks": seq,
+        });
+        if let Some(error) = &error {
+            end_payload["error"] = serde_json::Value::String(error.clone());
+        }
+        let sent = sink
+            .send(&ChannelMessage {
+                topic: tunnel_topic.to_string(),
+                event: "tunnel_response_end".to_string(),
+                payload: end_payload,
+                reference: None,
+            })
+            .await;
+
+        let duration_ms = start_time.elapsed().as_millis() as u64;
+        match &error {
+            Some(error) => warn!(
+                request_id = %request_id,
+                error = %error,
+                duration_ms = %duration_ms,
+                "Streamed response ended early"
+            ),
+            None => info!(
+                request_id = %request_id,
+                chunks = %seq,
+                duration_ms = %duration_ms,
+                "Streamed response completed"
+            ),
+        }
+
+        let response_body = (!preview.is_empty()).then(|| Body::from(preview));
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::ResponseStreamEnded {
+                request_id,
+                duration_ms,
+                response_body,
+                error,
+            })
+            .await;
+
+        sent
+    }
+
+    /// Open a WebSocket to the local target, forwarding the client's headers
+    /// (subprotocols, cookies, auth) but letting tungstenite generate the handshake.
+    async fn connect_local_websocket(
+        &self,
+        target: &str,
+        headers: &Headers,
+    ) -> Result<(
+        tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+        >,
+        tokio_tungstenite::tungstenite::handshake::client::Response,
+    )> {
+        // Connect to the real address; with an SNI override the URL host only names the server
+        let address = reqwest::Url::parse(target)?;
+        let host = address
+            .host_str()
+            .ok_or_else(|| anyhow!("Target URL {} has no host", target))?
+            .to_string();
+        let port = address.port_or_known_default().unwrap_or(80);
+
+        let url = self
+            .local_tls
+            .apply_sni(target, self.routing.default_target());
+        let ws_url = if let Some(rest) = url.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = url.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            url
+        };
+
+        let mut request = ws_url.as_str().into_client_request()?;
+        for (key, value) in headers {
+            if is_websocket_handshake_header(key) {
+                continue;
+            }
+            if let Ok(header_name) = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+                && let Ok(header_value) = reqwest::header::HeaderValue::from_str(value)
+            {
+                request.headers_mut().append(header_name, header_value);
             }
+        }
+
+        let stream = tokio::net::TcpStream::connect((host.as_str(), port)).await?;
+        let (socket, response) = tokio_tungstenite::client_async_tls_with_config(
+            request,
+            stream,
+            None,
+            self.ws_connector.clone(),
+        )
+        .await?;
+        Ok((socket, response))
+    }
+
+    /// Proxy a WebSocket upgrade: open the local socket, confirm with `tunnel_ws_open`,
+    /// then relay frames both ways as `tunnel_ws_frame` until either side sends `tunnel_ws_close`.
+    async fn proxy_websocket(
+        &self,
+        request_id: String,
+        target: String,
+        headers: Headers,
+        mut from_tunnel: mpsc::Receiver<Message>,
+        sink: &ChannelSink,
+        tunnel_topic: &str,
+    ) -> Result<()> {
+        let start_time = tokio::time::Instant::now();
+
+        info!(
+            request_id = %request_id,
+            target = %target,
+            "Opening local WebSocket"
+        );
+
+        let connected = match tokio::time::timeout(
+            LOCAL_REQUEST_TIMEOUT,
+            self.connect_local_websocket(&target, &headers),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(anyhow!("timed out connecting to local WebSocket")),
+        };
+
+        let (socket, response) = match connected {
+            Ok(pair) => pair,
             Err(e) => {
-                let duration_ms = start_time.elapsed().as_millis() as u64;
-                let error_msg = format!("Failed to forward request: {}", e);
+                self.ws_sessions.lock().unwrap().remove(&request_id);
 
-                error!(
-                    request_id = %request_id,
-                    error = %error_msg,
-                    duration_ms = %duration_ms,
-                    "Request forwarding failed"
-                );
+                // The local server answered without upgrading; relay that response as-is
+                if let Some(WsError::Http(response)) = e.downcast_ref::<WsError>() {
+                    let status = response.status().as_u16();
+                    let response_headers = Headers::from_header_map(response.headers());
+                    let response_bytes = response.body().clone().unwrap_or_default();
+                    let (response_body, body_encoding) = encode_body(&response_bytes);
 
-                // Notify UI
+                    let _ = self
+                        .event_tx
+                        .send(TunnelEvent::RequestForwarded {
+                            request_id: request_id.clone(),
+                            status,
+                            duration_ms: start_time.elapsed().as_millis() as u64,
+                            response_headers: response_headers.clone(),
+                            response_body: Some(Body::from(response_bytes)),
+                        })
+                        .await;
+
+                    return sink
+                        .send(&ChannelMessage {
+                            topic: tunnel_topic.to_string(),
+                            event: "tunnel_response".to_string(),
+                            payload: serde_json::json!({
+                                "request_id": request_id,
+                                "status": status,
+                                "headers": response_headers.to_wire(),
+                                "body": response_body,
+                                "body_encoding": body_encoding,
+                            }),
+                            reference: None,
+                        })
+                        .await;
+                }
+
+                let error_msg = format!("Failed to open local WebSocket: {}", e);
+                error!(request_id = %request_id, error = %error_msg, "WebSocket proxy failed");
                 let _ = self
                     .event_tx
                     .send(TunnelEvent::RequestFailed {
                         request_id: request_id.clone(),
                         error: error_msg.clone(),
+                        unreachable_target: None,
                     })
                     .await;
+                return self
+                    .send_tunnel_error(&request_id, &error_msg, sink, tunnel_topic)
+                    .await;
+            }
+        };
+
+        let response_headers = Headers::from_header_map(response.headers());
+
+        sink.send(&ChannelMessage {
+            topic: tunnel_topic.to_string(),
+            event: "tunnel_ws_open".to_string(),
+            payload: serde_json::json!({
+                "request_id": request_id,
+                "status": 101,
+                "headers": response_headers.to_wire(),
+            }),
+            reference: None,
+        })
+        .await?;
+
+        info!(request_id = %request_id, "WebSocket proxied");
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::WebSocketOpened {
+                request_id: request_id.clone(),
+                response_headers,
+            })
+            .await;
+
+        let (mut local_write, mut local_read) = socket.split();
+        let mut counts = FrameCounts::default();
+        let mut report = tokio::time::interval(STREAM_PROGRESS_INTERVAL);
+
+        // `notify_tunnel` is false when the public side closed first
+        let (code, reason, notify_tunnel) = loop {
+            tokio::select! {
+                _ = report.tick() => self.report_frames(&request_id, &mut counts).await,
+                message = from_tunnel.recv() => match message {
+                    Some(Message::Close(frame)) => {
+                        let code = frame.as_ref().map(|f| u16::from(f.code));
+                        let reason = frame.as_ref().map(|f| f.reason.to_string());
+                        let _ = local_write.send(Message::Close(frame)).await;
+                        break (code, reason, false);
+                    }
+                    Some(message) => {
+                        counts.frames_in += 1;
+                        counts.bytes_in += message.len() as u64;
+                        if let Err(e) = local_write.send(message).await {
+                            break (Some(1011), Some(e.to_string()), true);
+                        }
+                    }
+                    None => {
+                        let _ = local_write.send(Message::Close(None)).await;
+                        break (None, Some("Tunnel disconnected".to_string()), false);
+                    }
+                },
+                message = local_read.next() => match message {
+                    Some(Ok(message @ (Message::Text(_) | Message::Binary(_)))) => {
+                        let (body, body_encoding, opcode) = match &message {
+                            Message::Text(text) => (text.to_string(), "raw", "text"),
+                            _ => {
+                                let data = message.clone().into_data();
+                                (URL_SAFE_NO_PAD.encode(data), "base64", "binary")
+                            }
+                        };
+                        counts.frames_out += 1;
+                        counts.bytes_out += message.len() as u64;
+                        let frame_message = ChannelMessage {
+                            topic: tunnel_topic.to_string(),
+                            event: "tunnel_ws_frame".to_string(),
+                            payload: serde_json::json!({
+                                "request_id": request_id,
+                                "opcode": opcode,
+                                "body": body,
+                                "body_encoding": body_encoding,
+                            }),
+                            reference: None,
+                        };
+                        if sink.send(&frame_message).await.is_err() {
+                            let _ = local_write.send(Message::Close(None)).await;
+                            break (None, Some("Tunnel disconnected".to_string()), false);
+                        }
+                    }
+                    Some(Ok(Message::Close(frame))) => {
+                        let code = frame.as_ref().map(|f| u16::from(f.code));
+                        let reason = frame.as_ref().map(|f| f.reason.to_string());
+                        break (code, reason, true);
+                    }
+                    // Ping/pong is answered by tungstenite on the local side
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => break (Some(1011), Some(e.to_string()), true),
+                    None => break (None, None, true),
+                },
+            }
+        };
+
+        self.ws_sessions.lock().unwrap().remove(&request_id);
+        self.report_frames(&request_id, &mut counts).await;
 
-                // Send tunnel_error back to server
-                self.send_tunnel_error(&request_id, &error_msg, write, tunnel_topic)
-                    .await?;
+        let sent = if notify_tunnel {
+            let mut payload = serde_json::json!({ "request_id": request_id });
+            if let Some(code) = code {
+                payload["code"] = code.into();
             }
+            if let Some(reason) = &reason {
+                payload["reason"] = reason.as_str().into();
+            }
+            sink.send(&ChannelMessage {
+                topic: tunnel_topic.to_string(),
+                event: "tunnel_ws_close".to_string(),
+                payload,
+                reference: None,
+            })
+            .await
+        } else {
+            Ok(())
+        };
+
+        info!(
+            request_id = %request_id,
+            code = ?code,
+            duration_ms = %start_time.elapsed().as_millis(),
+            "WebSocket closed"
+        );
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::WebSocketClosed {
+                request_id,
+                code,
+                reason,
+            })
+            .await;
+
+        sent
+    }
+
+    async fn report_frames(&self, request_id: &str, counts: &mut FrameCounts) {
+        let counts = std::mem::take(counts);
+        if counts.frames_in == 0 && counts.frames_out == 0 {
+            return;
         }
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::WebSocketFrames {
+                request_id: request_id.to_string(),
+                frames_in: counts.frames_in,
+                frames_out: counts.frames_out,
+            })
+            .await;
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::BytesTransferred {
+                request_id: request_id.to_string(),
+                bytes_in: counts.bytes_in,
+                bytes_out: counts.bytes_out,
+            })
+            .await;
+    }
 
-        Ok(())
+    async fn report_bytes_out(&self, request_id: &str, bytes: u64) {
+        let _ = self
+            .event_tx
+            .send(TunnelEvent::BytesTransferred {
+                request_id: request_id.to_string(),
+                bytes_in: 0,
+                bytes_out: bytes,
+            })
+            .await;
     }
 
     async fn send_tunnel_error(
         &self,
         request_id: &str,
         error: &str,
-        write: &mut futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<
-                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-            >,
-            Message,
-        >,
+        sink: &ChannelSink,
         tunnel_topic: &str,
     ) -> Result<()> {
         let error_message = ChannelMessage {
@@ -1263,18 +2949,21 @@ impl TunnelForwarder {
             reference: None,
         };
 
-        let error_json = serde_json::to_string(&error_message)?;
-        write.send(Message::Text(error_json.into())).await?;
-        Ok(())
+        sink.send(&error_message).await
     }
 
     /// Connect with automatic reconnection on recoverable errors
-    pub async fn connect_with_reconnect(&self, config: ReconnectConfig) -> Result<()> {
+    pub async fn connect_with_reconnect(self: &Arc<Self>, config: ReconnectConfig) -> Result<()> {
         let mut attempt: u32 = 0;
+        // One refresh per rejection, so a token the server never accepts stays fatal
+        let mut refreshed = false;
 
         loop {
             let start = tokio::time::Instant::now();
             let result = self.connect_and_forward().await;
+            if start.elapsed() > Duration::from_secs(5) {
+                refreshed = false;
+            }
 
             match result {
                 Ok(()) => {
@@ -1283,11 +2972,32 @@ impl TunnelForwarder {
                     }
                 }
                 Err(ref e) => {
-                    let err_msg = e.to_string();
+                    let mut err_msg = e.to_string();
+                    if is_auth_error(e)
+                        && !refreshed
+                        && let Some(refresher) = &self.token_refresher
+                    {
+                        refreshed = true;
+                        match refresh_token(refresher, &self.access_token, &self.event_tx).await {
+                            Ok(()) => continue,
+                            Err(refresh_err) => {
+                                warn!("Token refresh failed: {}", refresh_err);
+                                err_msg =
+                                    format!("{} (token refresh failed: {})", err_msg, refresh_err);
+                            }
+                        }
+                    }
                     if is_fatal_error(&err_msg) {
+                        let error = e
+                            .chain()
+                            .find_map(|cause| cause.downcast_ref::<TunnelError>())
+                            .cloned();
                         let _ = self
                             .event_tx
-                            .send(TunnelEvent::ReconnectFailed { reason: err_msg })
+                            .send(TunnelEvent::ReconnectFailed {
+                                reason: err_msg,
+                                error,
+                            })
                             .await;
                         return result;
                     }
@@ -1305,6 +3015,7 @@ impl TunnelForwarder {
                     .event_tx
                     .send(TunnelEvent::ReconnectFailed {
                         reason: reason.clone(),
+                        error: None,
                     })
                     .await;
                 return Err(anyhow!(reason));
@@ -1376,11 +3087,16 @@ mod tests {
             "method": "POST",
             "path": "/webhook",
             "query_params": {"foo": "bar"},
-            "headers": {"content-type": "application/json"},
+            "headers": {"content-type": "application/json", "via": ["1.1 a", "1.1 b"]},
             "body": "{\"data\":1}"
         }"#;
         let req: TunnelWebhookRequest = serde_json::from_str(json).unwrap();
         assert_eq!(req.id, "req-1");
+        assert_eq!(req.headers.len(), 3);
+        assert_eq!(
+            req.headers.get_all("via").collect::<Vec<_>>(),
+            ["1.1 a", "1.1 b"]
+        );
         assert_eq!(req.method, "POST");
         assert_eq!(req.path, "/webhook");
         assert!(req.body.is_some());
@@ -1461,6 +3177,82 @@ mod tests {
         assert!(!is_fatal_error("WebSocket stream ended"));
     }
 
+    #[test]
+    fn test_is_auth_error() {
+        assert!(is_auth_error(&TunnelError::AuthenticationFailed.into()));
+        let rejected = tokio_tungstenite::tungstenite::http::Response::builder()
+            .status(401)
+            .body(None)
+            .unwrap();
+        assert!(is_auth_error(&WsError::Http(Box::new(rejected)).into()));
+        // Status codes in free text, such as an endpoint slug, are not auth errors
+        assert!(!is_auth_error(&anyhow!("Endpoint not found: 'team-401'.")));
+        assert!(!is_auth_error(&anyhow!(
+            "Failed to connect to tunnel: HTTP error: 401 Unauthorized"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_token_is_refreshed_once() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Rejects every handshake, recording the token each one carried
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (token_tx, mut token_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 2048];
+                let n = socket.read(&mut buf).await.unwrap();
+                let head = String::from_utf8_lossy(&buf[..n]).to_string();
+                let token = head
+                    .split("token=")
+                    .nth(1)
+                    .and_then(|rest| rest.split(' ').next())
+                    .unwrap_or_default()
+                    .to_string();
+                let _ = token_tx.send(token);
+                let _ = socket
+                    .write_all(b"HTTP/1.1 401 Unauthorized\r\ncontent-length: 0\r\n\r\n")
+                    .await;
+            }
+        });
+
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let refresher: TokenRefresher = Arc::new(|| Box::pin(async { Ok("fresh".to_string()) }));
+        let client = Arc::new(
+            TunnelClient::new(
+                "stale".to_string(),
+                "my-endpoint".to_string(),
+                "http://localhost:3000".to_string(),
+                Some(format!("ws://127.0.0.1:{}", port)),
+                event_tx,
+            )
+            .unwrap()
+            .with_network(NetworkSettings::default())
+            .with_token_refresher(refresher),
+        );
+
+        let result = client
+            .connect_with_reconnect(ReconnectConfig::default())
+            .await;
+        assert!(result.is_err());
+        assert_eq!(token_rx.recv().await.unwrap(), "stale");
+        assert_eq!(token_rx.recv().await.unwrap(), "fresh");
+
+        let mut refreshed = 0;
+        let mut failed = false;
+        while let Ok(event) = event_rx.try_recv() {
+            match event {
+                TunnelEvent::TokenRefreshed => refreshed += 1,
+                TunnelEvent::ReconnectFailed { .. } => failed = true,
+                _ => {}
+            }
+        }
+        assert_eq!(refreshed, 1);
+        assert!(failed);
+    }
+
     // calculate_backoff tests
     #[test]
     fn test_calculate_backoff_first_attempt() {
@@ -1504,4 +3296,530 @@ mod tests {
         let decoded = URL_SAFE_NO_PAD.decode(&encoded).unwrap();
         assert_eq!(decoded, original);
     }
+
+    // Forwarding pool
+    #[tokio::test]
+    async fn test_forward_pool_limits_concurrency() {
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let pool = ForwardPool::new(2, event_tx);
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (done_tx, mut done_rx) = mpsc::unbounded_channel();
+
+        for _ in 0..5 {
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            let done_tx = done_tx.clone();
+            pool.spawn(async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                let _ = done_tx.send(());
+            });
+        }
+
+        for _ in 0..5 {
+            done_rx.recv().await.unwrap();
+        }
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+
+        // Drain reports until the pool is idle again.
+        let mut last = None;
+        while let Ok(Some(event)) =
+            tokio::time::timeout(Duration::from_millis(100), event_rx.recv()).await
+        {
+            last = Some(event);
+        }
+        assert!(matches!(
+            last,
+            Some(TunnelEvent::InFlight {
+                active: 0,
+                queued: 0,
+                max: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_forward_pool_minimum_one_slot() {
+        let (event_tx, _event_rx) = mpsc::channel(1);
+        let pool = ForwardPool::new(0, event_tx);
+        assert_eq!(pool.max, 1);
+    }
+
+    #[tokio::test]
+    async fn test_channel_sink_serializes_messages() {
+        let (sink, mut rx) = ChannelSink::new();
+        let msg = ChannelMessage {
+            topic: "tunnel:abc".to_string(),
+            event: "tunnel_response".to_string(),
+            payload: serde_json::json!({"request_id": "r1"}),
+            reference: None,
+        };
+        sink.send(&msg).await.unwrap();
+        let Some(Message::Text(text)) = rx.try_recv().ok() else {
+            panic!("expected a text frame");
+        };
+        assert!(text.contains("\"event\":\"tunnel_response\""));
+
+        drop(rx);
+        assert!(sink.send(&msg).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_channel_sink_waits_for_room() {
+        let (sink, mut rx) = ChannelSink::new();
+        let msg = ChannelMessage {
+            topic: "tunnel:abc".to_string(),
+            event: "tunnel_response_chunk".to_string(),
+            payload: serde_json::json!({}),
+            reference: None,
+        };
+        for _ in 0..OUTBOUND_BUFFER {
+            sink.send(&msg).await.unwrap();
+        }
+        let blocked = tokio::time::timeout(Duration::from_millis(50), sink.send(&msg)).await;
+        assert!(blocked.is_err(), "a full sink must make senders wait");
+
+        rx.recv().await.unwrap();
+        sink.send(&msg).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_response_body_fails_the_request() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).await.unwrap();
+            // Promise more body than is sent, then hang up
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 100\r\n\r\npartial")
+                .await
+                .unwrap();
+        });
+
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let forwarder = TunnelForwarder::new(
+            "token".to_string(),
+            "127.0.0.1".to_string(),
+            port,
+            None,
+            None,
+            Some("http://localhost:4000".to_string()),
+            event_tx,
+        )
+        .unwrap();
+        let (sink, mut rx) = ChannelSink::new();
+
+        forwarder
+            .forward_tunnel_request(
+                "r1".to_string(),
+                "GET".to_string(),
+                "/".to_string(),
+                format!("http://127.0.0.1:{}/", port),
+                Headers::new(),
+                Vec::new(),
+                None,
+                &sink,
+                "tunnel:abc",
+            )
+            .await
+            .unwrap();
+
+        let Ok(Message::Text(text)) = rx.try_recv() else {
+            panic!("expected a reply to the server");
+        };
+        let msg: ChannelMessage = serde_json::from_str(&text).unwrap();
+        assert_eq!(msg.event, "tunnel_error");
+
+        let mut failed = false;
+        while let Ok(event) = event_rx.try_recv() {
+            assert!(!matches!(event, TunnelEvent::RequestForwarded { .. }));
+            failed |= matches!(event, TunnelEvent::RequestFailed { .. });
+        }
+        assert!(failed);
+    }
+
+    #[tokio::test]
+    async fn test_refused_forward_names_the_unreachable_target() {
+        // Bind and release a port so the connection is refused
+        let port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let target = format!("http://127.0.0.1:{}/hook?a=1", port);
+
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let forwarder = TunnelForwarder::new(
+            "token".to_string(),
+            "127.0.0.1".to_string(),
+            port,
+            None,
+            None,
+            Some("http://localhost:4000".to_string()),
+            event_tx,
+        )
+        .unwrap();
+        let (sink, _rx) = ChannelSink::new();
+
+        forwarder
+            .forward_tunnel_request(
+                "r1".to_string(),
+                "GET".to_string(),
+                "/hook".to_string(),
+                target.clone(),
+                Headers::new(),
+                Vec::new(),
+                None,
+                &sink,
+                "tunnel:abc",
+            )
+            .await
+            .unwrap();
+
+        let mut unreachable = None;
+        while let Ok(event) = event_rx.try_recv() {
+            if let TunnelEvent::RequestFailed {
+                error,
+                unreachable_target,
+                ..
+            } = event
+            {
+                assert!(error.contains("is not reachable"), "{error}");
+                unreachable = unreachable_target;
+            }
+        }
+        assert_eq!(unreachable, Some(format!("http://127.0.0.1:{}", port)));
+    }
+
+    // Streaming
+    fn header_map(pairs: &[(&'static str, &'static str)]) -> reqwest::header::HeaderMap {
+        pairs
+            .iter()
+            .map(|(k, v)| {
+                (
+                    reqwest::header::HeaderName::from_static(k),
+                    reqwest::header::HeaderValue::from_static(v),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_should_stream() {
+        assert!(should_stream(&header_map(&[(
+            "content-type",
+            "text/event-stream; charset=utf-8"
+        )])));
+        assert!(should_stream(&header_map(&[])));
+        assert!(should_stream(&header_map(&[("content-length", "5000000")])));
+        assert!(!should_stream(&header_map(&[
+            ("content-type", "application/json"),
+            ("content-length", "42"),
+        ])));
+    }
+
+    #[test]
+    fn test_encode_decode_body_roundtrip() {
+        for bytes in [b"plain text".to_vec(), vec![0xff, 0x00, 0xfe]] {
+            let (body, body_encoding) = encode_body(&bytes);
+            let payload = serde_json::json!({"body": body, "body_encoding": body_encoding});
+            assert_eq!(decode_body(payload.as_object().unwrap()), bytes);
+        }
+        assert_eq!(encode_body(b"abc").1, "raw");
+        assert_eq!(encode_body(&[0xff]).1, "base64");
+    }
+
+    #[tokio::test]
+    async fn test_streamed_response_is_relayed_in_frames() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\
+                      transfer-encoding: chunked\r\n\r\n\
+                      7\r\ndata:1\n\r\n7\r\ndata:2\n\r\n0\r\n\r\n",
+                )
+                .await
+                .unwrap();
+        });
+
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let forwarder = TunnelForwarder::new(
+            "token".to_string(),
+            "127.0.0.1".to_string(),
+            port,
+            None,
+            None,
+            Some("http://localhost:4000".to_string()),
+            event_tx,
+        )
+        .unwrap()
+        .with_streaming(true);
+        let (sink, mut rx) = ChannelSink::new();
+
+        forwarder
+            .forward_tunnel_request(
+                "r1".to_string(),
+                "GET".to_string(),
+                "/events".to_string(),
+                format!("http://127.0.0.1:{}/events", port),
+                Headers::new(),
+                Vec::new(),
+                None,
+                &sink,
+                "tunnel:abc",
+            )
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        let mut body = Vec::new();
+        while let Ok(Message::Text(text)) = rx.try_recv() {
+            let msg: ChannelMessage = serde_json::from_str(&text).unwrap();
+            if msg.event == "tunnel_response_chunk" {
+                body.extend(decode_body(msg.payload.as_object().unwrap()));
+            }
+            events.push(msg.event);
+        }
+        assert_eq!(
+            events.first().map(String::as_str),
+            Some("tunnel_response_start")
+        );
+        assert_eq!(
+            events.last().map(String::as_str),
+            Some("tunnel_response_end")
+        );
+        assert_eq!(body, b"data:1\ndata:2\n");
+
+        let mut bytes_out = 0;
+        let mut ended = false;
+        while let Ok(event) = event_rx.try_recv() {
+            match event {
+                TunnelEvent::BytesTransferred { bytes_out: n, .. } => bytes_out += n,
+                TunnelEvent::ResponseStreamEnded { error, .. } => {
+                    assert!(error.is_none());
+                    ended = true;
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(bytes_out, 14);
+        assert!(ended);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_mock_answers_when_target_is_down() {
+        // Bind and release a port so the connection is refused
+        let port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let dir = tempfile::TempDir::new().unwrap();
+        let mocks_path = dir.path().join("mocks.json");
+        std::fs::write(
+            &mocks_path,
+            r#"[{"path": "/orders/*", "mode": "fallback", "status": 202,
+                 "body": "queued {{body.id}} via {{method}}"}]"#,
+        )
+        .unwrap();
+
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let forwarder = TunnelForwarder::new(
+            "token".to_string(),
+            "127.0.0.1".to_string(),
+            port,
+            None,
+            None,
+            Some("http://localhost:4000".to_string()),
+            event_tx,
+        )
+        .unwrap()
+        .with_mocks(MockSet::load(&mocks_path).unwrap());
+        let (sink, mut rx) = ChannelSink::new();
+
+        forwarder
+            .forward_tunnel_request(
+                "r1".to_string(),
+                "POST".to_string(),
+                "/orders/7".to_string(),
+                format!("http://127.0.0.1:{}/orders/7", port),
+                Headers::new(),
+                br#"{"id": 7}"#.to_vec(),
+                None,
+                &sink,
+                "tunnel:abc",
+            )
+            .await
+            .unwrap();
+
+        let Ok(Message::Text(text)) = rx.try_recv() else {
+            panic!("expected a tunnel_response");
+        };
+        let msg: ChannelMessage = serde_json::from_str(&text).unwrap();
+        assert_eq!(msg.event, "tunnel_response");
+        assert_eq!(msg.payload["status"], 202);
+        assert_eq!(msg.payload["body"], "queued 7 via POST");
+
+        let mut mocked = None;
+        while let Ok(event) = event_rx.try_recv() {
+            if let TunnelEvent::RequestMocked { mode, .. } = event {
+                mocked = Some(mode);
+            }
+        }
+        assert_eq!(mocked, Some(MockMode::Fallback));
+    }
+
+    #[tokio::test]
+    async fn test_injected_error_replaces_forwarding() {
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let forwarder = TunnelForwarder::new(
+            "token".to_string(),
+            "127.0.0.1".to_string(),
+            1,
+            None,
+            None,
+            Some("http://localhost:4000".to_string()),
+            event_tx,
+        )
+        .unwrap()
+        .with_faults(FaultInjector::new(vec![
+            crate::fault::FaultRule::parse("error=503,path=/flaky/*").unwrap(),
+        ]));
+        let (sink, mut rx) = ChannelSink::new();
+
+        forwarder
+            .forward_tunnel_request(
+                "r1".to_string(),
+                "POST".to_string(),
+                "/flaky/1".to_string(),
+                "http://127.0.0.1:1/flaky/1".to_string(),
+                Headers::new(),
+                Vec::new(),
+                None,
+                &sink,
+                "tunnel:abc",
+            )
+            .await
+            .unwrap();
+
+        let Ok(Message::Text(text)) = rx.try_recv() else {
+            panic!("expected a tunnel_response");
+        };
+        let msg: ChannelMessage = serde_json::from_str(&text).unwrap();
+        assert_eq!(msg.event, "tunnel_response");
+        assert_eq!(msg.payload["status"], 503);
+
+        let Ok(TunnelEvent::FaultInjected { faults, .. }) = event_rx.try_recv() else {
+            panic!("expected a FaultInjected event");
+        };
+        assert_eq!(faults, "HTTP 503");
+    }
+
+    // WebSocket passthrough
+    #[test]
+    fn test_is_websocket_upgrade() {
+        let mut headers = Headers::new();
+        assert!(!is_websocket_upgrade(&headers));
+        headers.push("Upgrade", "h2c");
+        assert!(!is_websocket_upgrade(&headers));
+        headers.push("Upgrade", "WebSocket");
+        assert!(is_websocket_upgrade(&headers));
+    }
+
+    #[test]
+    fn test_websocket_handshake_headers_are_not_forwarded() {
+        assert!(is_websocket_handshake_header("Sec-WebSocket-Key"));
+        assert!(is_websocket_handshake_header("host"));
+        assert!(!is_websocket_handshake_header("sec-websocket-protocol"));
+        assert!(!is_websocket_handshake_header("cookie"));
+    }
+
+    #[tokio::test]
+    async fn test_websocket_frames_are_relayed_both_ways() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Local echo server
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(message)) = socket.next().await {
+                if message.is_close() {
+                    break;
+                }
+                socket.send(message).await.unwrap();
+            }
+        });
+
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let forwarder = TunnelForwarder::new(
+            "token".to_string(),
+            "127.0.0.1".to_string(),
+            port,
+            None,
+            None,
+            Some("http://localhost:4000".to_string()),
+            event_tx,
+        )
+        .unwrap();
+        let (sink, mut outbound) = ChannelSink::new();
+        let (to_local, from_tunnel) = mpsc::channel(STREAM_BUFFER);
+
+        let proxy = forwarder.proxy_websocket(
+            "ws1".to_string(),
+            format!("http://127.0.0.1:{}/live", port),
+            Headers::new(),
+            from_tunnel,
+            &sink,
+            "tunnel:abc",
+        );
+        let client = async {
+            let mut events = Vec::new();
+            to_local.send(Message::Text("hello".into())).await.unwrap();
+            while let Some(Message::Text(text)) = outbound.recv().await {
+                let msg: ChannelMessage = serde_json::from_str(&text).unwrap();
+                events.push(msg.event.clone());
+                if msg.event == "tunnel_ws_frame" {
+                    assert_eq!(msg.payload["body"], "hello");
+                    to_local.send(Message::Close(None)).await.unwrap();
+                    break;
+                }
+            }
+            events
+        };
+
+        let (result, events) = tokio::join!(proxy, client);
+        result.unwrap();
+        assert_eq!(events, vec!["tunnel_ws_open", "tunnel_ws_frame"]);
+
+        let mut frames = (0, 0);
+        let mut closed = false;
+        while let Ok(event) = event_rx.try_recv() {
+            match event {
+                TunnelEvent::WebSocketFrames {
+                    frames_in,
+                    frames_out,
+                    ..
+                } => {
+                    frames.0 += frames_in;
+                    frames.1 += frames_out;
+                }
+                TunnelEvent::WebSocketClosed { .. } => closed = true,
+                _ => {}
+            }
+        }
+        assert_eq!(frames, (1, 1));
+        assert!(closed);
+    }
 }
@@ -1,4 +1,8 @@
-use crate::app::{App, AppState};
+use crate::app::{
+    App, AppState, INTERCEPT_FIELDS, InterceptEditor, InterceptOutcome, StreamStatus,
+    TunnelRequest, WebSocketInfo,
+};
+use crate::mock::MockMode;
 use crate::syntax::JsonHighlighter;
 use ratatui::{
     prelude::*,
@@ -6,6 +10,7 @@ use ratatui::{
         Block, Borders, Cell, List, ListItem, Paragraph, Row, Table, TableState, Tabs, Wrap,
     },
 };
+use std::borrow::Cow;
 
 // Pastel color palette — soft, pleasant, modern
 mod colors {
@@ -37,7 +42,41 @@ mod colors {
     }
 }
 
-pub fn draw(frame: &mut Frame, app: &App) {
+/// Draw the active session, with a tab bar above it when `titles` lists several sessions.
+pub fn draw_sessions(frame: &mut Frame, app: &App, titles: &[String], active: usize) {
+    if titles.len() < 2 {
+        draw(frame, app, frame.area());
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Session tabs
+            Constraint::Min(0),    // Active session
+        ])
+        .split(frame.area());
+
+    let tabs = Tabs::new(titles.iter().map(|t| Line::from(t.as_str())))
+        .block(
+            Block::default()
+                .title(" Sessions (Tab/Shift+Tab to switch) ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors::MUTED)),
+        )
+        .style(Style::default().fg(colors::TEXT))
+        .highlight_style(
+            Style::default()
+                .fg(colors::SECONDARY)
+                .add_modifier(Modifier::BOLD),
+        )
+        .select(active);
+    frame.render_widget(tabs, chunks[0]);
+
+    draw(frame, app, chunks[1]);
+}
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -45,7 +84,7 @@ pub fn draw(frame: &mut Frame, app: &App) {
             Constraint::Min(0),    // Main content
             Constraint::Length(1), // Status bar
         ])
-        .split(frame.area());
+        .split(area);
 
     // Draw main content
     match &app.state {
@@ -56,6 +95,7 @@ pub fn draw(frame: &mut Frame, app: &App) {
         AppState::Listening => draw_listening(frame, app, chunks[0]),
         AppState::Tunneling => draw_tunneling(frame, app, chunks[0]),
         AppState::ExportMenu => draw_export_menu(frame, app, chunks[0]),
+        AppState::Intercepting => draw_intercept_editor(frame, app, chunks[0]),
         AppState::Error { message, hint } => draw_error(frame, message, hint.as_deref(), chunks[0]),
     }
 
@@ -130,6 +170,14 @@ fn draw_listening(frame: &mut Frame, app: &App, area: Rect) {
                         .fg(colors::SUCCESS)
                         .add_modifier(Modifier::BOLD),
                 ),
+                match &app.inspector_url {
+                    Some(url) => Span::styled(
+                        format!("   Inspector: {}", url),
+                        Style::default().fg(colors::INFO),
+                    ),
+                    None => Span::raw(""),
+                },
+                token_refreshed_span(app, "   "),
             ]),
         ]
     } else if let Some(err) = &app.listening_error {
@@ -207,6 +255,13 @@ fn draw_listening(frame: &mut Frame, app: &App, area: Rect) {
                     .fg(colors::INFO)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled("   In-flight: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                in_flight_text(app),
+                Style::default()
+                    .fg(colors::WARNING)
+                    .add_modifier(Modifier::BOLD),
+            ),
         ]),
         Line::from(vec![
             Span::styled("Success: ", Style::default().fg(colors::TEXT)),
@@ -225,6 +280,13 @@ fn draw_listening(frame: &mut Frame, app: &App, area: Rect) {
                     .fg(colors::ERROR)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled("   Faults: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                app.listening_stats.faults.to_string(),
+                Style::default()
+                    .fg(colors::WARNING)
+                    .add_modifier(Modifier::BOLD),
+            ),
         ]),
     ];
 
@@ -345,12 +407,26 @@ fn draw_listening(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
+    // Proxied WebSockets, newest first
+    let websockets: Vec<&TunnelRequest> = app
+        .tunnel_requests
+        .iter()
+        .rev()
+        .filter(|r| r.websocket.is_some())
+        .collect();
+    let websockets_height = if websockets.is_empty() {
+        0
+    } else {
+        websockets.len().min(4) as u16 + 2
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(7), // Header with URL and status
-            Constraint::Length(5), // Statistics
-            Constraint::Min(0),    // Requests table
+            Constraint::Length(7),                 // Header with URL and status
+            Constraint::Length(5),                 // Statistics
+            Constraint::Length(websockets_height), // Proxied WebSockets
+            Constraint::Min(0),                    // Requests table
         ])
         .split(area);
 
@@ -361,7 +437,17 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
         "Connecting...".to_string()
     };
 
-    let target_url = format!("{}:{}", app.tunnel_local_host, app.tunnel_local_port);
+    let mut target_url = if app.tunnel_local_https {
+        format!(
+            "https://{}:{}",
+            app.tunnel_local_host, app.tunnel_local_port
+        )
+    } else {
+        format!("{}:{}", app.tunnel_local_host, app.tunnel_local_port)
+    };
+    if !app.tunnel_routes.is_empty() {
+        target_url.push_str(&format!(" (+{} routes)", app.tunnel_routes.len()));
+    }
 
     let is_reconnecting = app
         .tunnel_error
@@ -449,6 +535,14 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
             } else {
                 Span::raw("")
             },
+            match &app.inspector_url {
+                Some(url) => Span::styled(
+                    format!("     Inspector: {}", url),
+                    Style::default().fg(colors::INFO),
+                ),
+                None => Span::raw(""),
+            },
+            token_refreshed_span(app, "     "),
         ]),
     ];
 
@@ -524,6 +618,51 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
                     .fg(colors::WARNING)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled("     In-flight: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                in_flight_text(app),
+                Style::default()
+                    .fg(colors::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("     Faults: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                app.tunnel_stats.faults.to_string(),
+                Style::default()
+                    .fg(colors::WARNING)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Transferred: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                format!(
+                    "↓ {}  ↑ {}",
+                    format_bytes(app.tunnel_stats.bytes_in),
+                    format_bytes(app.tunnel_stats.bytes_out)
+                ),
+                Style::default()
+                    .fg(colors::INFO)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("     Streaming: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                app.tunnel_requests
+                    .iter()
+                    .filter(|r| r.stream_status == StreamStatus::Streaming)
+                    .count()
+                    .to_string(),
+                Style::default()
+                    .fg(colors::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("     Mocked: ", Style::default().fg(colors::TEXT)),
+            Span::styled(
+                app.tunnel_stats.mocked.to_string(),
+                Style::default()
+                    .fg(colors::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
         ]),
     ];
 
@@ -536,6 +675,10 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
 
     frame.render_widget(stats, chunks[1]);
 
+    if !websockets.is_empty() {
+        draw_tunnel_websockets(frame, &websockets, chunks[2]);
+    }
+
     // Live Requests table
     if app.tunnel_requests.is_empty() {
         let no_requests = Paragraph::new("Waiting for requests...")
@@ -548,9 +691,9 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
                     .border_style(Style::default().fg(colors::MUTED)),
             );
 
-        frame.render_widget(no_requests, chunks[2]);
+        frame.render_widget(no_requests, chunks[3]);
     } else {
-        let available_rows = chunks[2].height.saturating_sub(4) as usize; // borders + header + header margin
+        let available_rows = chunks[3].height.saturating_sub(4) as usize; // borders + header + header margin
 
         if available_rows == 0 {
             let compact = Paragraph::new("Expand terminal height to view requests")
@@ -562,7 +705,7 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(colors::MUTED)),
                 );
-            frame.render_widget(compact, chunks[2]);
+            frame.render_widget(compact, chunks[3]);
             return;
         }
 
@@ -601,42 +744,83 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
                     _ => ("❓", colors::TEXT),
                 };
 
-                // Status display
+                // Status display (⇣ marks a response that is still streaming,
+                // ✎ an edited intercepted request and ↩ a hand-written response)
                 let (status_display, status_color) = if let Some(status) = request.status {
-                    (status.to_string(), colors::for_http_status(status))
+                    let display = if request.stream_status == StreamStatus::Streaming {
+                        format!("{} ⇣", status)
+                    } else if request.websocket.as_ref().is_some_and(|ws| ws.open) {
+                        format!("{} ⇄", status)
+                    } else if request.intercept == Some(InterceptOutcome::Edited) {
+                        format!("{} ✎", status)
+                    } else if request.intercept == Some(InterceptOutcome::Answered) {
+                        format!("{} ↩", status)
+                    } else if request.mocked.is_some() {
+                        format!("{} ◆", status)
+                    } else {
+                        status.to_string()
+                    };
+                    // Mocked responses never reached the local target
+                    let color = if request.mocked.is_some() {
+                        colors::ACCENT
+                    } else {
+                        colors::for_http_status(status)
+                    };
+                    (display, color)
+                } else if request.intercept == Some(InterceptOutcome::Held) {
+                    ("⏸ held".to_string(), colors::ACCENT)
                 } else if request.error.is_some() {
                     ("Error".to_string(), colors::ERROR)
                 } else {
                     let spinner_chars = ["⏳", "⏳", "⏳", "⏳"];
                     (spinner_chars[0].to_string(), colors::WARNING)
                 };
+                // ⚡ marks requests with injected faults
+                let status_display = if request.fault.is_some() {
+                    format!("{} ⚡", status_display)
+                } else {
+                    status_display
+                };
 
-                // Duration display
+                // Duration display; streaming responses show bytes relayed so far
                 let duration_display = if let Some(completed_at) = request.completed_at {
                     let duration = completed_at.duration_since(request.received_at);
                     format!("{}ms", duration.as_millis())
+                } else if request.stream_status == StreamStatus::Streaming {
+                    format_bytes(request.bytes_out)
                 } else {
                     "-".to_string()
                 };
 
+                // Routed target (host:port), highlighted when a routing rule matched
+                let route_color = if request.route.is_some() {
+                    colors::ACCENT
+                } else {
+                    colors::MUTED
+                };
+
                 Row::new(vec![
                     Cell::from(time_display).style(Style::default().fg(colors::MUTED)),
                     Cell::from(format!("{} {}", method_symbol, request.method))
                         .style(Style::default().fg(method_color)),
                     Cell::from(request.path.clone()).style(Style::default().fg(colors::TEXT)),
+                    Cell::from(target_authority(&request.target_url))
+                        .style(Style::default().fg(route_color)),
                     Cell::from(status_display).style(Style::default().fg(status_color)),
                     Cell::from(duration_display).style(Style::default().fg(colors::MUTED)),
                 ])
             })
             .collect();
 
-        let headers = Row::new(vec!["Time", "Method", "Path", "Status", "Duration"])
-            .style(
-                Style::default()
-                    .fg(colors::PRIMARY)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .bottom_margin(1);
+        let headers = Row::new(vec![
+            "Time", "Method", "Path", "Target", "Status", "Duration",
+        ])
+        .style(
+            Style::default()
+                .fg(colors::PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
 
         let title = if app.tunnel_requests.len() > available_rows {
             format!(
@@ -653,10 +837,11 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
             rows,
             [
                 Constraint::Percentage(10), // Time
-                Constraint::Percentage(15), // Method
-                Constraint::Percentage(50), // Path
-                Constraint::Percentage(12), // Status
-                Constraint::Percentage(13), // Duration
+                Constraint::Percentage(14), // Method
+                Constraint::Percentage(36), // Path
+                Constraint::Percentage(17), // Target
+                Constraint::Percentage(11), // Status
+                Constraint::Percentage(12), // Duration
             ],
         )
         .header(headers)
@@ -675,8 +860,119 @@ fn draw_tunneling(frame: &mut Frame, app: &App, area: Rect) {
             table_state.select(Some(app.tunnel_selected_index - start_idx));
         }
 
-        frame.render_stateful_widget(requests_table, chunks[2], &mut table_state);
+        frame.render_stateful_widget(requests_table, chunks[3], &mut table_state);
+    }
+}
+
+fn draw_tunnel_websockets(frame: &mut Frame, websockets: &[&TunnelRequest], area: Rect) {
+    let open = websockets
+        .iter()
+        .filter(|r| r.websocket.as_ref().is_some_and(|ws| ws.open))
+        .count();
+
+    let items: Vec<ListItem> = websockets
+        .iter()
+        .filter_map(|request| {
+            let ws = request.websocket.as_ref()?;
+            let (marker, marker_color) = if ws.open {
+                ("●", colors::SUCCESS)
+            } else {
+                ("○", colors::MUTED)
+            };
+            Some(ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", marker), Style::default().fg(marker_color)),
+                Span::styled(request.path.clone(), Style::default().fg(colors::TEXT)),
+                Span::styled(
+                    format!(" → {}", target_authority(&request.target_url)),
+                    Style::default().fg(colors::MUTED),
+                ),
+                Span::styled(
+                    format!("   {}", websocket_text(ws)),
+                    Style::default().fg(colors::ACCENT),
+                ),
+            ])))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(format!(" WebSockets ({} open) ", open))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::SECONDARY)),
+    );
+    frame.render_widget(list, area);
+}
+
+/// Summarize a proxied WebSocket, e.g. `open · 12 in / 30 out frames`.
+fn websocket_text(ws: &WebSocketInfo) -> String {
+    let state = match (ws.open, ws.close_code) {
+        (true, _) => "open".to_string(),
+        (false, Some(code)) => format!("closed ({})", code),
+        (false, None) => "closed".to_string(),
+    };
+    format!(
+        "{} · {} in / {} out frames",
+        state, ws.frames_in, ws.frames_out
+    )
+}
+
+/// Format in-flight forwarding counts, e.g. `3/10 (2 queued)`.
+/// Status bar suffix describing intercept mode, empty when no rules are configured.
+fn intercept_status(app: &App) -> String {
+    match &app.interceptor {
+        Some(interceptor) if interceptor.is_enabled() => {
+            format!(" ⏸ {} held", app.held_requests.len())
+        }
+        Some(_) => format!(" ⏸ paused, {} held", app.held_requests.len()),
+        None => String::new(),
+    }
+}
+
+/// Status line note showing when the access token was last refreshed.
+fn token_refreshed_span(app: &App, indent: &str) -> Span<'static> {
+    match app.token_refreshed_at {
+        Some(at) => Span::styled(
+            format!("{}Token refreshed {}", indent, at.format("%H:%M:%S")),
+            Style::default().fg(colors::MUTED),
+        ),
+        None => Span::raw(""),
+    }
+}
+
+fn in_flight_text(app: &App) -> String {
+    let stats = &app.in_flight;
+    if stats.max == 0 {
+        return "0".to_string();
+    }
+    if stats.queued > 0 {
+        format!("{}/{} ({} queued)", stats.active, stats.max, stats.queued)
+    } else {
+        format!("{}/{}", stats.active, stats.max)
+    }
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5 KB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
     }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Reduce a forwarding URL to its `host:port` for compact table display.
+fn target_authority(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    without_scheme
+        .split(['/', '?'])
+        .next()
+        .unwrap_or(without_scheme)
 }
 
 fn draw_request_detail(frame: &mut Frame, app: &App, area: Rect) {
@@ -800,6 +1096,34 @@ fn draw_info_tab(
         ]),
     ];
 
+    // Show where the tunnel routed this request
+    if let Some(resp) = &app.selected_tunnel_response
+        && let Some(target_url) = &resp.target_url
+    {
+        info_text.push(Line::from(""));
+        info_text.push(Line::from(vec![
+            Span::styled(
+                "Routed To: ",
+                Style::default()
+                    .fg(colors::PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(target_url.as_str(), Style::default().fg(colors::ACCENT)),
+        ]));
+        info_text.push(Line::from(vec![
+            Span::styled(
+                "Route: ",
+                Style::default()
+                    .fg(colors::PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                resp.route.as_deref().unwrap_or("default target"),
+                Style::default().fg(colors::TEXT),
+            ),
+        ]));
+    }
+
     // Show last forward URL with replay hint
     if !app.forward_url_input.is_empty() {
         info_text.push(Line::from(""));
@@ -844,12 +1168,8 @@ fn draw_headers_tab(
     request: &crate::models::WebhookRequest,
     area: Rect,
 ) {
-    let mut headers: Vec<(&String, &String)> = request.headers.iter().collect();
-    headers.sort_by(|(k1, _), (k2, _)| {
-        k1.to_ascii_lowercase()
-            .cmp(&k2.to_ascii_lowercase())
-            .then_with(|| k1.cmp(k2))
-    });
+    // Shown in arrival order, repeated headers on separate lines
+    let headers: Vec<&(String, String)> = request.headers.iter().collect();
     let available_lines = area.height.saturating_sub(2) as usize;
 
     let start_line = app.headers_scroll_offset;
@@ -961,7 +1281,10 @@ fn draw_body_tab(
     request: &crate::models::WebhookRequest,
     area: Rect,
 ) {
-    let body_text = request.body.as_ref().or(request.body_preview.as_ref());
+    let body_text = match &request.body {
+        Some(body) => Some(body.display()),
+        None => request.body_preview.as_deref().map(Cow::Borrowed),
+    };
 
     let Some(body_content) = body_text else {
         render_empty_body(frame, " Body ", "(no body)", area);
@@ -979,18 +1302,19 @@ fn draw_body_tab(
         " (Preview)"
     };
 
-    let content_type =
-        if body_content.trim().starts_with('{') || body_content.trim().starts_with('[') {
-            " JSON"
-        } else {
-            ""
-        };
+    let content_type = match &request.body {
+        Some(body) if body.is_binary() => format!(" Hex, {} bytes", body.len()),
+        _ if body_content.trim().starts_with('{') || body_content.trim().starts_with('[') => {
+            " JSON".to_string()
+        }
+        _ => String::new(),
+    };
 
     let title_extra = format!("{}{}", content_type, source_suffix);
 
     render_highlighted_body(
         frame,
-        body_content,
+        &body_content,
         app.body_scroll_offset,
         "Body",
         &title_extra,
@@ -1046,6 +1370,33 @@ fn draw_response_tab(frame: &mut Frame, app: &App, area: Rect) {
                     .fg(status_color)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled(
+                if resp.websocket.is_some() {
+                    "     WebSocket: "
+                } else {
+                    ""
+                },
+                Style::default()
+                    .fg(colors::PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                resp.websocket
+                    .as_ref()
+                    .map(websocket_text)
+                    .unwrap_or_default(),
+                Style::default().fg(colors::ACCENT),
+            ),
+            Span::styled(
+                match resp.mocked {
+                    Some(MockMode::Always) => "     ◆ Mocked",
+                    Some(MockMode::Fallback) => "     ◆ Mocked (target unreachable)",
+                    None => "",
+                },
+                Style::default()
+                    .fg(colors::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
         ]),
         Line::from(vec![
             Span::styled(
@@ -1060,6 +1411,33 @@ fn draw_response_tab(frame: &mut Frame, app: &App, area: Rect) {
                     .unwrap_or_else(|| "-".to_string()),
                 Style::default().fg(colors::TEXT),
             ),
+            Span::styled(
+                "     Transferred: ",
+                Style::default()
+                    .fg(colors::PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                match resp.stream_status {
+                    StreamStatus::Buffered => format_bytes(resp.bytes_out),
+                    StreamStatus::Streaming => {
+                        format!("{} (streaming)", format_bytes(resp.bytes_out))
+                    }
+                    StreamStatus::Streamed => {
+                        format!("{} (streamed)", format_bytes(resp.bytes_out))
+                    }
+                },
+                Style::default().fg(colors::TEXT),
+            ),
+            Span::styled(
+                resp.fault
+                    .as_ref()
+                    .map(|fault| format!("     ⚡ Injected: {}", fault))
+                    .unwrap_or_default(),
+                Style::default()
+                    .fg(colors::WARNING)
+                    .add_modifier(Modifier::BOLD),
+            ),
         ]),
     ];
 
@@ -1084,12 +1462,7 @@ fn draw_response_tab(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(status_info, chunks[0]);
 
     // Section 2: Response headers
-    let mut headers: Vec<(&String, &String)> = resp.headers.iter().collect();
-    headers.sort_by(|(k1, _), (k2, _)| {
-        k1.to_ascii_lowercase()
-            .cmp(&k2.to_ascii_lowercase())
-            .then_with(|| k1.cmp(k2))
-    });
+    let headers: Vec<&(String, String)> = resp.headers.iter().collect();
     let available_header_lines = chunks[1].height.saturating_sub(2) as usize;
     let max_header_scroll = headers.len().saturating_sub(available_header_lines.max(1));
     let start_line = app.response_headers_scroll_offset.min(max_header_scroll);
@@ -1135,14 +1508,19 @@ fn draw_response_tab(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(headers_list, chunks[1]);
 
     // Section 3: Response body
-    match resp.body.as_deref() {
-        Some(body_content) if !body_content.is_empty() => {
+    match &resp.body {
+        Some(body) if !body.is_empty() => {
+            let title_extra = if body.is_binary() {
+                format!(" (Hex, {} bytes)", body.len())
+            } else {
+                String::new()
+            };
             render_highlighted_body(
                 frame,
-                body_content,
+                &body.display(),
                 app.response_scroll_offset,
                 "Response Body",
-                "",
+                &title_extra,
                 colors::SUCCESS,
                 chunks[2],
             );
@@ -1439,20 +1817,25 @@ fn draw_forward_result(frame: &mut Frame, app: &App, area: Rect) {
             frame.render_widget(error, chunks[1]);
         }
 
-        // Response body
+        // Response body (hex dump for binary content)
+        let display = result.body.display();
         let body_text = if result.success {
-            if result.body.is_empty() {
-                "(empty response)"
-            } else if result.body.len() > 500 {
-                &format!(
+            if display.is_empty() {
+                "(empty response)".to_string()
+            } else if display.len() > 500 {
+                let mut cutoff = 500;
+                while !display.is_char_boundary(cutoff) {
+                    cutoff -= 1;
+                }
+                format!(
                     "{}...\n\n[Truncated - showing first 500 characters]",
-                    &result.body[..500]
+                    &display[..cutoff]
                 )
             } else {
-                &result.body
+                display.into_owned()
             }
         } else {
-            "(no response body)"
+            "(no response body)".to_string()
         };
 
         let body = Paragraph::new(body_text)
@@ -1545,6 +1928,112 @@ fn draw_export_menu(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(popup, popup_area);
 }
 
+fn draw_intercept_editor(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(editor) = &app.intercept_editor else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)])
+        .split(area);
+
+    let mut summary = vec![Line::from(vec![
+        Span::styled(
+            format!("{} ", editor.original.method),
+            Style::default()
+                .fg(colors::SUCCESS)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(&editor.original.path, Style::default().fg(colors::TEXT)),
+        Span::styled(
+            format!("  ({} held)", app.held_requests.len()),
+            Style::default().fg(colors::MUTED),
+        ),
+    ])];
+    summary.push(match &editor.error {
+        Some(error) => Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(colors::ERROR),
+        )),
+        None => Line::from(Span::styled(
+            "Edit the request, then forward, drop or answer it",
+            Style::default().fg(colors::MUTED),
+        )),
+    });
+
+    let header = Paragraph::new(summary).block(
+        Block::default()
+            .title(" ⏸ Intercepted Request ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::PRIMARY)),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(if editor.can_respond {
+            vec![Constraint::Percentage(60), Constraint::Percentage(40)]
+        } else {
+            vec![Constraint::Percentage(100)]
+        })
+        .split(chunks[1]);
+
+    let request_fields = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Percentage(40),
+            Constraint::Min(3),
+        ])
+        .split(columns[0]);
+    for (index, field_area) in request_fields.iter().enumerate() {
+        draw_intercept_field(frame, editor, index, *field_area);
+    }
+
+    if editor.can_respond {
+        let response_fields = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Percentage(30),
+                Constraint::Min(3),
+            ])
+            .split(columns[1]);
+        for (offset, field_area) in response_fields.iter().enumerate() {
+            draw_intercept_field(frame, editor, 4 + offset, *field_area);
+        }
+    }
+}
+
+/// Render one editor field, keeping the end of the text (where typing happens) in view.
+fn draw_intercept_field(frame: &mut Frame, editor: &InterceptEditor, index: usize, area: Rect) {
+    let focused = editor.focus == index;
+    let mut text = editor.fields[index].clone();
+    if focused {
+        text.push('▏');
+    }
+
+    let visible_rows = area.height.saturating_sub(2) as usize;
+    let scroll = text.lines().count().saturating_sub(visible_rows) as u16;
+
+    let field = Paragraph::new(text)
+        .style(Style::default().fg(colors::TEXT))
+        .scroll((scroll, 0))
+        .block(
+            Block::default()
+                .title(format!(" {} ", INTERCEPT_FIELDS[index]))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if focused {
+                    colors::SECONDARY
+                } else {
+                    colors::PRIMARY
+                })),
+        );
+    frame.render_widget(field, area);
+}
+
 fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -1577,17 +2066,37 @@ fn draw_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         AppState::Listening => {
             let total_requests = app.listening_requests.len();
             (
-                format!("🎧 Listening ({})", total_requests),
-                "↑/↓: Navigate | Enter: Details | /: Search | Q: Quit",
+                format!("🎧 Listening ({}){}", total_requests, intercept_status(app)),
+                if app.interceptor.is_some() {
+                    "↑/↓: Navigate | Enter: Details | /: Search | I: Edit held | P: Pause | R: Reconnect | Q: Quit"
+                } else {
+                    "↑/↓: Navigate | Enter: Details | /: Search | R: Reconnect | Q: Quit"
+                },
             )
         }
         AppState::Tunneling => {
             let total_requests = app.tunnel_requests.len();
             (
-                format!("🌐 Tunnel ({})", total_requests),
-                "↑/↓: Navigate | Enter: Details | C: Copy URL | R: Reconnect | Q: Quit",
+                format!("🌐 Tunnel ({}){}", total_requests, intercept_status(app)),
+                if app.interceptor.is_some() {
+                    "↑/↓: Navigate | Enter: Details | I: Edit held | P: Pause | R: Reconnect | Q: Quit"
+                } else {
+                    "↑/↓: Navigate | Enter: Details | C: Copy URL | R: Reconnect | Q: Quit"
+                },
             )
         }
+        AppState::Intercepting => (
+            "⏸ Intercept".to_string(),
+            if app
+                .intercept_editor
+                .as_ref()
+                .is_some_and(|editor| editor.can_respond)
+            {
+                "Tab: Field | Ctrl+S: Forward | Ctrl+D: Drop | Ctrl+R: Respond | Esc: Back"
+            } else {
+                "Tab: Field | Ctrl+S: Forward | Ctrl+D: Drop | Esc: Back"
+            },
+        ),
         AppState::Error { .. } => ("❌ Error".to_string(), "Q/Esc: Quit"),
     };
 
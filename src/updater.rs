@@ -115,7 +115,8 @@ async fn check_latest_version() -> Result<Option<String>, UpdateError> {
         GITHUB_REPO_OWNER, GITHUB_REPO_NAME
     );
 
-    let client = reqwest::Client::builder()
+    let client = crate::http::client_builder()
+        .map_err(|e| UpdateError::CheckFailed(e.to_string()))?
         .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
         .user_agent(format!("hooklistener-cli/{}", CURRENT_VERSION))
         .build()